@@ -0,0 +1,223 @@
+//! u-blox receiver access over [`embedded-hal`] I2C (DDC).
+//!
+//! [`embedded-hal`]: https://docs.rs/embedded-hal
+
+use crate::framing::{Deframer, Frame};
+use crate::messages::{framed_vec, Message};
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+
+/// Default u-blox I2C (DDC) slave address.
+pub const DEFAULT_ADDR: u8 = 0x42;
+
+/// `Number of Bytes available` register (high byte, low byte follows).
+const BYTES_AVAIL_REG: u8 = 0xFD;
+
+/// Data stream register.
+const DATA_REG: u8 = 0xFF;
+
+/// Size of the read buffer, and therefore the most bytes read in a
+/// single transfer.
+const READ_BUF_LEN: usize = 128;
+
+/// The error type returned by [`UbloxDevice::send()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SendError<E> {
+    /// The message could not be serialized.
+    Serialize,
+    /// The I2C write failed.
+    I2c(E),
+}
+
+/// A u-blox receiver attached to an I2C bus.
+///
+/// Bytes are read from the receiver's data stream register and fed to
+/// an internal [`Deframer`].
+#[derive(Debug)]
+pub struct UbloxDevice<T> {
+    i2c: T,
+    addr: u8,
+    deframer: Deframer,
+    buf: [u8; READ_BUF_LEN],
+    /// Bytes in `buf[pos..len]` have been read but not yet deframed.
+    pos: usize,
+    len: usize,
+}
+
+impl<T, E> UbloxDevice<T>
+where
+    T: Write<Error = E> + WriteRead<Error = E>,
+{
+    /// Returns a new device using `i2c` at [`DEFAULT_ADDR`].
+    pub fn new(i2c: T) -> Self {
+        Self::with_addr(i2c, DEFAULT_ADDR)
+    }
+
+    /// Returns a new device using `i2c` at slave address `addr`.
+    pub fn with_addr(i2c: T, addr: u8) -> Self {
+        UbloxDevice {
+            i2c,
+            addr,
+            deframer: Deframer::new(),
+            buf: [0; READ_BUF_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Consumes `self`, returning the underlying I2C bus.
+    pub fn release(self) -> T {
+        self.i2c
+    }
+
+    /// Frames and writes `msg` to the receiver.
+    pub fn send<M: Message>(&mut self, msg: &M) -> Result<(), SendError<E>> {
+        let framed = framed_vec(msg).map_err(|()| SendError::Serialize)?;
+        self.i2c.write(self.addr, &framed).map_err(SendError::I2c)
+    }
+
+    /// Returns the number of bytes the receiver has pending for
+    /// output.
+    pub fn available(&mut self) -> Result<usize, E> {
+        let mut avail = [0; 2];
+        self.i2c
+            .write_read(self.addr, &[BYTES_AVAIL_REG], &mut avail)?;
+        Ok(usize::from(u16::from_be_bytes(avail)))
+    }
+
+    /// Reads pending bytes from the receiver and returns the next
+    /// complete frame, if any.
+    ///
+    /// Performs at most one data register read per call. Any bytes
+    /// read beyond the returned frame are retained for the next call.
+    pub fn poll_frame(&mut self) -> Result<Option<Frame>, E> {
+        // Frames left queued for rescanning by the previous call are
+        // complete, so return them without reading more input.
+        if let Some(frame) = self.deframer.poll() {
+            return Ok(Some(frame));
+        }
+        if let Some(frame) = self.drain() {
+            return Ok(Some(frame));
+        }
+
+        let avail = self.available()?;
+        // The high byte of the available count sometimes reads back
+        // as 0x80, so treat these values as nothing available.
+        if avail == 0 || avail == 0x8000 || avail == 0x0080 {
+            return Ok(None);
+        }
+
+        let len = usize::min(avail, self.buf.len());
+        self.i2c
+            .write_read(self.addr, &[DATA_REG], &mut self.buf[..len])?;
+        self.pos = 0;
+        self.len = len;
+        Ok(self.drain())
+    }
+
+    /// Pushes buffered bytes into the deframer until a frame is
+    /// complete or the buffer is exhausted.
+    fn drain(&mut self) -> Option<Frame> {
+        while self.pos < self.len {
+            let b = self.buf[self.pos];
+            self.pos += 1;
+            if let Some(frame) = self.deframer.push(b) {
+                return Some(frame);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ack::Ack;
+    use alloc::vec::Vec;
+
+    /// A receiver with a fixed output stream.
+    struct MockI2c {
+        output: Vec<u8>,
+        written: Vec<u8>,
+    }
+
+    impl Write for MockI2c {
+        type Error = ();
+
+        fn write(&mut self, addr: u8, bytes: &[u8]) -> Result<(), ()> {
+            assert_eq!(addr, DEFAULT_ADDR);
+            self.written.extend_from_slice(bytes);
+            Ok(())
+        }
+    }
+
+    impl WriteRead for MockI2c {
+        type Error = ();
+
+        fn write_read(&mut self, addr: u8, bytes: &[u8], buffer: &mut [u8]) -> Result<(), ()> {
+            assert_eq!(addr, DEFAULT_ADDR);
+            match bytes {
+                [BYTES_AVAIL_REG] => {
+                    buffer.copy_from_slice(&(self.output.len() as u16).to_be_bytes())
+                }
+                [DATA_REG] => {
+                    let rest = self.output.split_off(buffer.len());
+                    buffer.copy_from_slice(&self.output);
+                    self.output = rest;
+                }
+                _ => return Err(()),
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_poll_frame() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut output = Vec::new();
+        output.extend_from_slice(&[0xff; 3]);
+        output.extend_from_slice(&ack);
+        output.extend_from_slice(&ack);
+        let mut dev = UbloxDevice::new(MockI2c {
+            output,
+            written: Vec::new(),
+        });
+
+        let mut frames = 0;
+        while let Some(frame) = dev.poll_frame().unwrap() {
+            assert_eq!(frame.message, [0x06, 0x01]);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+
+        dev.send(&Ack {
+            class: 0x06,
+            id: 0x01,
+        })
+        .unwrap();
+        assert_eq!(dev.release().written, ack);
+    }
+
+    #[test]
+    fn test_poll_frame_drains_backlog() {
+        // A false syncword whose declared length spans both acks, so
+        // they are only found once its checksum fails on the last
+        // byte read.
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut output = Vec::new();
+        output.extend_from_slice(&[0xb5, 0x62, 0x42, 0x42, 0x18, 0x00]);
+        output.extend_from_slice(&ack);
+        output.extend_from_slice(&ack);
+        output.extend_from_slice(&[0x00; 5]);
+        let mut dev = UbloxDevice::new(MockI2c {
+            output,
+            written: Vec::new(),
+        });
+
+        let mut frames = 0;
+        while let Some(frame) = dev.poll_frame().unwrap() {
+            assert_eq!(frame.message, [0x06, 0x01]);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+    }
+}
@@ -6,7 +6,7 @@
 /// From UBX-13003221-R18:
 ///
 /// > The checksum algorithm used is the 8-Bit Fletcher Algorithm,
-/// which is used in the TCP standard (RFC 1145)
+/// > which is used in the TCP standard (RFC 1145)
 ///
 /// # Example
 ///
@@ -55,9 +55,64 @@ impl Checksum {
         input
     }
 
+    /// Returns the running checksum, `(ck_a, ck_b)`, without resetting
+    /// `self`, e.g. to log progress through a frame.
+    pub fn peek(&self) -> (u8, u8) {
+        self.0
+    }
+
     /// Returns the running checksum, `(ck_a, ck_b)`, and resets
     /// `self` to default state.
     pub fn take(&mut self) -> (u8, u8) {
         ::core::mem::take(&mut self.0)
     }
+
+    /// Returns the checksum, `(ck_a, ck_b)`, of `bytes`.
+    ///
+    /// `bytes` should span from the class byte to the end of the
+    /// payload, i.e. exclude the syncword and trailing checksum.
+    pub fn compute(bytes: &[u8]) -> (u8, u8) {
+        let mut cksum = Self::new();
+        for &b in bytes {
+            cksum.push(b);
+        }
+        cksum.take()
+    }
+
+    /// Returns `true` if the checksum of `bytes` equals `expected`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ublox::framing::Checksum;
+    /// let frame = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+    /// // Checksum covers class through payload.
+    /// assert!(Checksum::verify(&frame[2..7], (0x0d, 0x26)));
+    /// assert!(!Checksum::verify(&frame[2..7], (0x26, 0x0d)));
+    /// ```
+    pub fn verify(bytes: &[u8], expected: (u8, u8)) -> bool {
+        Self::compute(bytes) == expected
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_peek() {
+        let mut cksum = Checksum::new();
+        assert_eq!(cksum.peek(), (0, 0));
+        for &b in &[0x05, 0x01, 0x02, 0x00] {
+            cksum.push(b);
+        }
+        assert_eq!(cksum.peek(), (0x08, 0x1b));
+        assert_eq!(cksum.peek(), (0x08, 0x1b));
+        cksum.push(0x06);
+        cksum.push(0x01);
+        let peeked = cksum.peek();
+        assert_eq!(cksum.take(), peeked);
+        assert_eq!(peeked, (0x0f, 0x38));
+        assert_eq!(cksum.peek(), (0, 0));
+    }
 }
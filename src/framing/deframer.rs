@@ -1,7 +1,18 @@
 //! u-blox protocol framing and deframing state machines.
 
-use crate::framing::{Checksum, Frame, FrameVec};
-use log::{trace, warn};
+use crate::framing::{Checksum, Frame, FrameError, FrameVec};
+use alloc::{collections::VecDeque, string::String, vec::Vec};
+
+/// Maximum length of an NMEA sentence, including the leading `$` and
+/// trailing `<CR><LF>`.
+const NMEA_MAX_LEN: usize = 82;
+
+/// Standard UBX syncword, `0xB5 0x62`.
+const SYNCWORD: u16 = 0xB5_62;
+
+/// Unreasonable (and arbitrarily chosen) upper limit on declared
+/// payload length.
+const MAX_LEN: usize = 999;
 
 /// One-shot defamer utility function.
 pub fn deframe<T>(bytes: T) -> Option<Frame>
@@ -17,27 +28,266 @@ where
     None
 }
 
+/// Deframes every frame in `bytes`, returning each frame and each
+/// framing error in the order they occur.
+///
+/// Unlike [`deframe()`], which stops at the first frame and silently
+/// skips bad ones, this reports every dropped frame, e.g. to count
+/// corrupt frames in a capture file. Garbage between frames and a
+/// trailing incomplete frame are not reported.
+pub fn deframe_all(bytes: &[u8]) -> Vec<Result<Frame, FrameError>> {
+    fn errors(deframer: &mut Deframer) -> impl Iterator<Item = Result<Frame, FrameError>> + '_ {
+        deframer.events().filter_map(|event| match event {
+            DeframerEvent::Oversize { declared } => Some(Err(FrameError::Size {
+                declared,
+                capacity: MAX_LEN,
+            })),
+            DeframerEvent::Checksum => Some(Err(FrameError::Checksum)),
+            DeframerEvent::Resync { .. } => None,
+        })
+    }
+
+    let mut deframer = Deframer::with_events();
+    let mut results = Vec::new();
+    for &b in bytes {
+        let frame = deframer.push(b);
+        results.extend(errors(&mut deframer));
+        results.extend(frame.map(Ok));
+    }
+    loop {
+        let frame = deframer.poll();
+        results.extend(errors(&mut deframer));
+        match frame {
+            Some(frame) => results.push(Ok(frame)),
+            None => break,
+        }
+    }
+    results
+}
+
+/// Deframes every complete frame in `buf`, returning the frames and
+/// the number of bytes consumed.
+///
+/// Garbage between frames is skipped. Bytes past the consumed count
+/// may hold the start of a frame which is not yet complete, and
+/// should be kept and prepended to the next chunk of input.
+pub fn split_frames(buf: &[u8]) -> (Vec<Frame>, usize) {
+    let mut deframer = Deframer::new();
+    let mut frames = Vec::new();
+    let mut consumed = 0;
+    // The backlog always holds the most recently pushed bytes, so a
+    // frame ends just before the first byte still in the backlog.
+    for (i, &b) in buf.iter().enumerate() {
+        if let Some(frame) = deframer.push(b) {
+            frames.push(frame);
+            consumed = i + 1 - deframer.backlog.len();
+        }
+    }
+    while let Some(frame) = deframer.poll() {
+        frames.push(frame);
+        consumed = buf.len() - deframer.backlog.len();
+    }
+
+    let [sync_msb, sync_lsb] = SYNCWORD.to_be_bytes();
+    let tail = &buf[consumed..];
+    let partial = if deframer.is_syncing() {
+        // At most the first syncword byte may be pending.
+        match tail.last() {
+            Some(&b) if b == sync_msb => 1,
+            _ => 0,
+        }
+    } else {
+        // Keep everything from the first syncword on, as the frame in
+        // progress may follow a false sync.
+        tail.windows(2)
+            .position(|w| w == [sync_msb, sync_lsb])
+            .map_or(0, |pos| tail.len() - pos)
+    };
+    (frames, buf.len() - partial)
+}
+
+/// An item parsed from a byte stream containing interleaved UBX and
+/// NMEA data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamItem {
+    /// A UBX frame.
+    Ubx(Frame),
+    /// An NMEA sentence, including the leading `$` but without the
+    /// trailing `<CR><LF>`.
+    ///
+    /// The sentence's checksum is not validated.
+    Nmea(String),
+}
+
+/// Diagnostic events queued by a [`Deframer`] created with
+/// [`Deframer::with_events()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeframerEvent {
+    /// The syncword was found after discarding `discarded` bytes.
+    Resync {
+        /// Number of bytes discarded while searching for the syncword.
+        discarded: usize,
+    },
+    /// A frame was dropped because its declared payload length is
+    /// unreasonably large.
+    Oversize {
+        /// Declared message length parsed from byte stream.
+        declared: usize,
+    },
+    /// A frame was dropped because of a checksum mismatch.
+    Checksum,
+}
+
+/// A type for 'deframing' u-blox message frames.
+///
+/// The deframer collects payloads in a single buffer which is reused
+/// from frame to frame, so after warming up it only allocates the
+/// payloads of the frames it returns. Rejected frames and resyncs do
+/// not allocate.
+#[derive(Debug, Clone)]
+pub struct Deframer {
+    state: State,
+    /// Payload of the frame currently being received.
+    buf: FrameVec,
+    /// Bytes waiting to be pushed through the state machine, i.e. new
+    /// input and bytes being rescanned after a false sync.
+    backlog: VecDeque<u8>,
+    /// Syncword preceding every frame, most significant byte first.
+    syncword: u16,
+    bytes_discarded: usize,
+    checksum_errors: usize,
+    /// Events not yet drained with [`Deframer::events()`], if
+    /// queueing.
+    events: Option<VecDeque<DeframerEvent>>,
+}
+
 impl Deframer {
     /// Incrementally parses a u-blox message frame with the given
     /// `input`, returning a an error or optional [`Frame`].
+    ///
+    /// Non-UBX data, such as NMEA sentences, is discarded.
     #[inline]
     pub fn push(&mut self, input: u8) -> Option<Frame> {
-        use self::Deframer::*;
-        match self {
+        match self.push_inner(input, false) {
+            Some(StreamItem::Ubx(frame)) => Some(frame),
+            _ => None,
+        }
+    }
+
+    /// Incrementally parses a u-blox message frame or NMEA sentence
+    /// with the given `input`.
+    ///
+    /// Use this instead of [`push()`](#method.push) for byte streams
+    /// with interleaved UBX and NMEA data, e.g. from a receiver in its
+    /// default configuration.
+    #[inline]
+    pub fn push_item(&mut self, input: u8) -> Option<StreamItem> {
+        self.push_inner(input, true)
+    }
+
+    /// Continues parsing bytes queued for rescanning, without new
+    /// input, returning the next frame found.
+    ///
+    /// [`push()`](#method.push) returns at most one frame per input
+    /// byte, so rejecting a false frame may leave complete frames
+    /// queued. Call this until it returns `None` once input ends, e.g.
+    /// at EOF, so they are not lost.
+    pub fn poll(&mut self) -> Option<Frame> {
+        match self.step_backlog(false) {
+            Some(StreamItem::Ubx(frame)) => Some(frame),
+            _ => None,
+        }
+    }
+
+    /// Like [`poll()`](#method.poll), but for streams parsed with
+    /// [`push_item()`](#method.push_item).
+    pub fn poll_item(&mut self) -> Option<StreamItem> {
+        self.step_backlog(true)
+    }
+
+    fn push_inner(&mut self, input: u8, nmea: bool) -> Option<StreamItem> {
+        self.backlog.push_back(input);
+        self.step_backlog(nmea)
+    }
+
+    fn step_backlog(&mut self, nmea: bool) -> Option<StreamItem> {
+        while let Some(b) = self.backlog.pop_front() {
+            if let item @ Some(_) = self.step(b, nmea) {
+                return item;
+            }
+        }
+        None
+    }
+
+    /// Queues the bytes following a false syncword to be scanned
+    /// again, ahead of any bytes already queued.
+    ///
+    /// A syncword may appear in noise or in the payload of a
+    /// truncated frame. When that false frame is rejected, the real
+    /// frame may be among the bytes it consumed.
+    fn rescan(&mut self, header: [u8; 4], trailer: &[u8]) {
+        let queued = self.backlog.len();
+        self.backlog.extend(&header);
+        self.backlog.extend(&self.buf);
+        self.backlog.extend(trailer);
+        self.backlog.rotate_left(queued);
+        // The false syncword itself will not be seen again.
+        self.bytes_discarded += 2;
+    }
+
+    fn step(&mut self, input: u8, nmea: bool) -> Option<StreamItem> {
+        use self::State::*;
+        match &mut self.state {
+            Sync { .. } if nmea && input == b'$' => {
+                trace!("nmea ← sync");
+                let mut sentence = String::with_capacity(NMEA_MAX_LEN);
+                sentence.push('$');
+                self.state = Nmea { sentence };
+            }
+
             Sync { accum, processed } => {
-                const SYNCWORD: u16 = 0xB5_62;
                 *accum = (*accum << 8) | u16::from(input);
                 *processed += 1;
-                if *accum == SYNCWORD {
-                    *self = Deframer::Class;
-                } else if *processed % 7 == 0 {
-                    trace!("still searching for syncword after {} bytes", *processed);
+                if *processed >= 2 && *accum == self.syncword {
+                    // The first syncword byte was counted as discarded
+                    // when it arrived.
+                    let discarded = *processed - 2;
+                    self.bytes_discarded -= 1;
+                    if discarded > 0 {
+                        self.notify(DeframerEvent::Resync { discarded });
+                    }
+                    self.state = State::Class;
+                } else {
+                    self.bytes_discarded += 1;
+                    if *processed % 7 == 0 {
+                        trace!("still searching for syncword after {} bytes", *processed);
+                    }
+                }
+            }
+
+            Nmea { sentence } => {
+                if input == b'\n' && sentence.ends_with('\r') {
+                    sentence.pop();
+                    let sentence = ::core::mem::take(sentence);
+                    self.state = State::default();
+                    return Some(StreamItem::Nmea(sentence));
+                } else if (input.is_ascii_graphic() || input == b' ' || input == b'\r')
+                    && sentence.len() < NMEA_MAX_LEN - 1
+                {
+                    sentence.push(char::from(input));
+                } else {
+                    // Not an NMEA sentence after all, so look for the
+                    // next sync starting with this byte.
+                    trace!("abandoning nmea sentence {:?}", sentence);
+                    self.bytes_discarded += sentence.len();
+                    self.state = State::default();
+                    return self.step(input, nmea);
                 }
             }
 
             Class => {
                 trace!("class {:#04x} ← sync", input);
-                *self = Id {
+                self.state = Id {
                     cksum: Checksum::with(input),
                     class: input,
                 }
@@ -45,7 +295,7 @@ impl Deframer {
 
             Id { class, cksum } => {
                 trace!("id {:#04x} ← class", input);
-                *self = LengthLsb {
+                self.state = LengthLsb {
                     class: *class,
                     id: cksum.push(input),
                     cksum: *cksum,
@@ -54,7 +304,7 @@ impl Deframer {
 
             LengthLsb { class, id, cksum } => {
                 trace!("len_l {:#04x} ← id", input);
-                *self = LengthMsb {
+                self.state = LengthMsb {
                     class: *class,
                     id: *id,
                     len_b0: cksum.push(input),
@@ -70,20 +320,34 @@ impl Deframer {
             } => {
                 let len = (usize::from(cksum.push(input)) << 8) | usize::from(*len_b0);
                 // Revert to start state is len is larger than
-                // unreasonable (and arbitrarily chosen) upper limit.
-                if len > 999 {
+                // `MAX_LEN`.
+                if len > MAX_LEN {
                     warn!("declared message length {:#06x} is unreasonably large", len);
-                    *self = Self::default();
+                    let header = [*class, *id, *len_b0, input];
+                    self.state = State::default();
+                    self.notify(DeframerEvent::Oversize { declared: len });
+                    // The buffer still holds the previous frame's
+                    // payload, which must not be rescanned.
+                    self.buf.clear();
+                    self.rescan(header, &[]);
                     return None;
                 }
                 trace!("len_h {:#04x} ← len_lsb", input);
-                let message = FrameVec::with_capacity(len);
-                *self = Message {
-                    class: *class,
-                    id: *id,
-                    len,
-                    message,
-                    cksum: *cksum,
+                self.buf.clear();
+                self.buf.reserve(len);
+                self.state = if len == 0 {
+                    CkA {
+                        class: *class,
+                        id: *id,
+                        cksum_calc: cksum.take(),
+                    }
+                } else {
+                    Message {
+                        class: *class,
+                        id: *id,
+                        len,
+                        cksum: *cksum,
+                    }
                 }
             }
 
@@ -91,15 +355,13 @@ impl Deframer {
                 class,
                 id,
                 len,
-                message,
                 cksum,
             } => {
-                message.push(cksum.push(input));
-                if message.len() == *len {
-                    *self = CkA {
+                self.buf.push(cksum.push(input));
+                if self.buf.len() == *len {
+                    self.state = CkA {
                         class: *class,
                         id: *id,
-                        message: message.clone(),
                         cksum_calc: cksum.take(),
                     };
                 }
@@ -108,51 +370,53 @@ impl Deframer {
             CkA {
                 class,
                 id,
-                message,
                 cksum_calc,
             } => {
                 trace!("ck_a {:#04x} ← mesg", input);
                 if input == cksum_calc.0 {
-                    let mut msg = ::alloc::vec::Vec::new();
-                    ::core::mem::swap(message, &mut msg);
-                    *self = CkB {
+                    self.state = CkB {
                         class: *class,
                         id: *id,
-                        message: msg,
                         cksum_calc: *cksum_calc,
                     };
                 } else {
                     warn!(
                         "ck_a mismatch, expected {:#04x}, got {:#04x}, msg {:02x?}",
-                        cksum_calc.0, input, message
+                        cksum_calc.0, input, self.buf
                     );
-                    *self = Self::default();
+                    let header = header(*class, *id, self.buf.len());
+                    self.state = State::default();
+                    self.checksum_errors += 1;
+                    self.notify(DeframerEvent::Checksum);
+                    self.rescan(header, &[input]);
                 }
             }
 
             CkB {
                 class,
                 id,
-                message,
                 cksum_calc,
             } => {
                 trace!("ck_b {:#04x} ← ck_a", input);
-                let mut msg = ::alloc::vec::Vec::new();
-                ::core::mem::swap(message, &mut msg);
                 let ret = if input == cksum_calc.1 {
-                    Some(Frame {
+                    Some(StreamItem::Ubx(Frame {
                         class: *class,
                         id: *id,
-                        message: msg,
-                    })
+                        message: self.buf.as_slice().to_vec(),
+                    }))
                 } else {
                     warn!(
                         "ck_b mismatch, expected {:#04x}, got {:#04x}, msg {:02x?}",
-                        cksum_calc.1, input, msg
+                        cksum_calc.1, input, self.buf
                     );
+                    let header = header(*class, *id, self.buf.len());
+                    let ck_a = cksum_calc.0;
+                    self.checksum_errors += 1;
+                    self.notify(DeframerEvent::Checksum);
+                    self.rescan(header, &[ck_a, input]);
                     None
                 };
-                *self = Self::default();
+                self.state = State::default();
                 return ret;
             }
         };
@@ -162,9 +426,107 @@ impl Deframer {
 
     /// Returns a new deframer.
     pub fn new() -> Self {
-        Deframer::Sync {
-            accum: 0,
-            processed: 0,
+        Deframer {
+            state: State::default(),
+            buf: FrameVec::new(),
+            backlog: VecDeque::new(),
+            syncword: SYNCWORD,
+            bytes_discarded: 0,
+            checksum_errors: 0,
+            events: None,
+        }
+    }
+
+    /// Returns a new deframer which queues a [`DeframerEvent`] upon
+    /// every resync or error.
+    ///
+    /// Events accumulate until drained with
+    /// [`events()`](#method.events), so drain them regularly, e.g.
+    /// after every frame.
+    pub fn with_events() -> Self {
+        Deframer {
+            events: Some(VecDeque::new()),
+            ..Self::new()
+        }
+    }
+
+    /// Drains and returns the queued events, oldest first.
+    ///
+    /// Always empty unless `self` was created with
+    /// [`with_events()`](#method.with_events).
+    pub fn events(&mut self) -> impl Iterator<Item = DeframerEvent> + '_ {
+        self.events.iter_mut().flat_map(|events| events.drain(..))
+    }
+
+    /// Returns a new deframer which searches for `syncword` instead
+    /// of the standard UBX syncword `0xB562`.
+    ///
+    /// This is useful for streams where UBX frames have been
+    /// re-framed by a bridge or proprietary transport. The syncword is
+    /// matched most significant byte first.
+    pub fn with_syncword(syncword: u16) -> Self {
+        Deframer {
+            syncword,
+            ..Self::new()
+        }
+    }
+
+    /// Returns the total number of bytes discarded while searching
+    /// for the syncword.
+    pub fn bytes_discarded(&self) -> usize {
+        self.bytes_discarded
+    }
+
+    /// Returns the total number of frames dropped due to checksum
+    /// mismatch.
+    pub fn checksum_errors(&self) -> usize {
+        self.checksum_errors
+    }
+
+    /// Returns the name of the current state, e.g. `"Sync"` while
+    /// searching for the syncword or `"Payload"` while collecting a
+    /// frame's payload.
+    ///
+    /// Useful to diagnose a stream which never produces frames.
+    pub fn state_name(&self) -> &'static str {
+        match self.state {
+            State::Sync { .. } => "Sync",
+            State::Nmea { .. } => "Nmea",
+            State::Class => "Class",
+            State::Id { .. } => "Id",
+            State::LengthLsb { .. } => "LengthLsb",
+            State::LengthMsb { .. } => "LengthMsb",
+            State::Message { .. } => "Payload",
+            State::CkA { .. } => "CkA",
+            State::CkB { .. } => "CkB",
+        }
+    }
+
+    /// Returns the number of bytes received of the frame (or NMEA
+    /// sentence) in progress, including the syncword, or 0 while
+    /// searching for the syncword.
+    pub fn bytes_in_current_frame(&self) -> usize {
+        match &self.state {
+            State::Sync { .. } => 0,
+            State::Nmea { sentence } => sentence.len(),
+            State::Class => 2,
+            State::Id { .. } => 3,
+            State::LengthLsb { .. } => 4,
+            State::LengthMsb { .. } => 5,
+            State::Message { .. } | State::CkA { .. } => 6 + self.buf.len(),
+            State::CkB { .. } => 7 + self.buf.len(),
+        }
+    }
+
+    /// Returns `true` if `self` is searching for the syncword, i.e. is
+    /// not in the middle of a frame.
+    pub(crate) fn is_syncing(&self) -> bool {
+        matches!(self.state, State::Sync { .. }) && self.backlog.is_empty()
+    }
+
+    fn notify(&mut self, event: DeframerEvent) {
+        if let Some(events) = self.events.as_mut() {
+            events.push_back(event);
         }
     }
 }
@@ -175,28 +537,32 @@ impl Default for Deframer {
     }
 }
 
-/// A type for 'deframing' u-blox message frames.
+/// Returns the frame header bytes following the syncword.
+fn header(class: u8, id: u8, len: usize) -> [u8; 4] {
+    let [len_lsb, len_msb] = (len as u16).to_le_bytes();
+    [class, id, len_lsb, len_msb]
+}
+
+/// Deframer states.
 #[derive(Debug, Clone)]
-pub enum Deframer {
+enum State {
     /// Shift in every byte until matches value equals the syncword.
-    #[doc(hidden)]
     Sync { accum: u16, processed: usize },
 
+    /// Collect an NMEA sentence until `<CR><LF>`.
+    Nmea { sentence: String },
+
     /// No data, as the byte received durning this state is passed to
     /// next state.
-    #[doc(hidden)]
     Class,
 
     /// Byte received during this state is passed to next state.
-    #[doc(hidden)]
     Id { class: u8, cksum: Checksum },
 
     /// Length LSB received during this state is passed to next state.
-    #[doc(hidden)]
     LengthLsb { class: u8, id: u8, cksum: Checksum },
 
     /// Collect length's MSB.
-    #[doc(hidden)]
     LengthMsb {
         class: u8,
         id: u8,
@@ -204,40 +570,77 @@ pub enum Deframer {
         cksum: Checksum,
     },
 
-    /// Push rx bytes into message until `message.len() == len`.
-    #[doc(hidden)]
+    /// Push rx bytes into the payload buffer until it holds `len`
+    /// bytes.
     Message {
         class: u8,
         id: u8,
         len: usize,
-        message: FrameVec,
         cksum: Checksum,
     },
 
     /// Go to initial state if received byte doesnt match first byte
     /// of running checksum.
-    #[doc(hidden)]
     CkA {
         class: u8,
         id: u8,
-        message: FrameVec,
         cksum_calc: (u8, u8),
     },
 
     /// Go to initial state if received byte doesn't match second byte
     /// of running checksum.
-    #[doc(hidden)]
     CkB {
         class: u8,
         id: u8,
-        message: FrameVec,
         cksum_calc: (u8, u8),
     },
 }
 
+impl Default for State {
+    fn default() -> Self {
+        State::Sync {
+            accum: 0,
+            processed: 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Deframer;
+    extern crate std;
+
+    use super::{deframe_all, split_frames, Deframer, DeframerEvent, StreamItem};
+    use crate::framing::FrameError;
+    use crate::framing::{Checksum, Frame, MultiDeframer, StaticDeframer128};
+    use alloc::vec::Vec;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    /// Counts allocations per thread, so tests running concurrently
+    /// don't affect each other's counts.
+    struct CountingAlloc;
+
+    std::thread_local! {
+        static ALLOCS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    unsafe impl GlobalAlloc for CountingAlloc {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let _ = ALLOCS.try_with(|n| n.set(n.get() + 1));
+            System.alloc(layout)
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout)
+        }
+    }
+
+    #[global_allocator]
+    static GLOBAL: CountingAlloc = CountingAlloc;
+
+    fn allocs() -> usize {
+        ALLOCS.with(Cell::get)
+    }
 
     #[test]
     fn test_deframe() {
@@ -249,4 +652,410 @@ mod test {
         }
         assert!(res.is_some());
     }
+
+    /// Returns a pseudo-random byte stream of up to 4 KiB, weighted
+    /// towards syncword bytes and small lengths so that streams
+    /// regularly get past the sync state.
+    fn random_stream(seed: &mut u64) -> Vec<u8> {
+        let mut next = || {
+            // xorshift64
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            *seed
+        };
+        let len = (next() % 4096) as usize;
+        (0..len)
+            .map(|_| match next() % 8 {
+                0 => 0xb5,
+                1 => 0x62,
+                2 => 0x00,
+                3 => 0xd3,
+                _ => next() as u8,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_random_streams() {
+        let mut seed = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..500 {
+            let stream = random_stream(&mut seed);
+
+            let mut deframer = Deframer::new();
+            for frame in stream.iter().filter_map(|&b| deframer.push(b)) {
+                // Every returned frame must carry a valid checksum.
+                let framed = frame.into_framed_vec();
+                let mut cksum = Checksum::default();
+                for &b in &framed[2..framed.len() - 2] {
+                    cksum.push(b);
+                }
+                assert_eq!(
+                    cksum.take(),
+                    (framed[framed.len() - 2], framed[framed.len() - 1])
+                );
+            }
+            assert!(deframer.bytes_discarded() <= stream.len());
+
+            let mut deframer = Deframer::new();
+            stream.iter().for_each(|&b| drop(deframer.push_item(b)));
+            let mut deframer = StaticDeframer128::new();
+            stream.iter().for_each(|&b| drop(deframer.push(b)));
+            let mut deframer = MultiDeframer::new();
+            stream.iter().for_each(|&b| drop(deframer.push(b)));
+
+            let (_, consumed) = split_frames(&stream);
+            assert!(consumed <= stream.len());
+        }
+    }
+
+    #[test]
+    fn test_state_name() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let expected = [
+            ("Class", 2),
+            ("Id", 3),
+            ("LengthLsb", 4),
+            ("LengthMsb", 5),
+            ("Payload", 6),
+            ("Payload", 7),
+            ("CkA", 8),
+            ("CkB", 9),
+            ("Sync", 0),
+        ];
+        let mut deframer = Deframer::new();
+        assert_eq!(deframer.state_name(), "Sync");
+        assert_eq!(deframer.bytes_in_current_frame(), 0);
+        assert_eq!(deframer.push(0xb5), None);
+        assert_eq!(deframer.state_name(), "Sync");
+        for (&b, &(name, len)) in ack[1..].iter().zip(expected.iter()) {
+            deframer.push(b);
+            assert_eq!(deframer.state_name(), name);
+            assert_eq!(deframer.bytes_in_current_frame(), len);
+        }
+
+        for &b in b"$GPTXT" {
+            assert_eq!(deframer.push_item(b), None);
+        }
+        assert_eq!(deframer.state_name(), "Nmea");
+        assert_eq!(deframer.bytes_in_current_frame(), 6);
+    }
+
+    #[test]
+    fn test_deframe_all() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut bad = ack;
+        bad[7] = 0x02;
+        let oversize = [0xb5, 0x62, 0x05, 0x01, 0xe8, 0x03];
+        let buf: Vec<u8> = [0x00, 0x24]
+            .iter()
+            .chain(ack.iter())
+            .chain(bad.iter())
+            .chain(ack.iter())
+            .chain(oversize.iter())
+            .chain(ack[..4].iter())
+            .copied()
+            .collect();
+        let results = deframe_all(&buf);
+        assert_eq!(results.len(), 4);
+        let frame = results[0].as_ref().unwrap();
+        assert_eq!((frame.class, frame.id), (0x05, 0x01));
+        assert_eq!(frame.message, [0x06, 0x01]);
+        assert_eq!(results[1], Err(FrameError::Checksum));
+        assert_eq!(results[2], results[0]);
+        assert_eq!(
+            results[3],
+            Err(FrameError::Size {
+                declared: 1000,
+                capacity: 999
+            })
+        );
+        assert!(deframe_all(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_deframe_all_drains_backlog() {
+        let (ack, buf) = false_sync_before_acks();
+        let results = deframe_all(&buf);
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], Err(FrameError::Checksum));
+        assert_eq!(results[1].clone().unwrap().into_framed_vec(), ack);
+        assert_eq!(results[2], results[1]);
+    }
+
+    #[test]
+    fn test_split_frames() {
+        let pvt = Frame {
+            class: 0x01,
+            id: 0x07,
+            message: alloc::vec![0x55; 92],
+        }
+        .into_framed_vec();
+        let buf: Vec<u8> = [0x00, 0x24]
+            .iter()
+            .chain(pvt.iter())
+            .chain([0xb5, 0x00].iter())
+            .chain(pvt.iter())
+            .chain(pvt[..3].iter())
+            .copied()
+            .collect();
+        let (frames, consumed) = split_frames(&buf);
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].class, frames[0].id), (0x01, 0x07));
+        assert_eq!(frames[0].message.len(), 92);
+        assert_eq!(frames[0], frames[1]);
+        assert_eq!(&buf[consumed..], &pvt[..3]);
+
+        // A lone first syncword byte may start the next frame.
+        let (frames, consumed) = split_frames(&buf[..buf.len() - 2]);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(&buf[consumed..buf.len() - 2], &pvt[..1]);
+        assert_eq!(split_frames(&[0x00, 0x62, 0x01]), (Vec::new(), 3));
+    }
+
+    /// A false syncword declaring a 24 byte payload which swallows two
+    /// ACKs, followed by a bad checksum as the final byte.
+    fn false_sync_before_acks() -> ([u8; 10], Vec<u8>) {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let buf = [0xb5, 0x62, 0x42, 0x42, 0x18, 0x00]
+            .iter()
+            .chain(ack.iter())
+            .chain(ack.iter())
+            .chain([0x00; 4].iter())
+            .chain([0x00].iter())
+            .copied()
+            .collect();
+        (ack, buf)
+    }
+
+    #[test]
+    fn test_split_frames_drains_backlog() {
+        let (ack, buf) = false_sync_before_acks();
+        assert_eq!(buf.len(), 31);
+        let (frames, consumed) = split_frames(&buf);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].clone().into_framed_vec(), ack);
+        assert_eq!(frames[0], frames[1]);
+        assert_eq!(consumed, 31);
+
+        // A frame still in progress after the drained frames is kept.
+        let mut buf = buf;
+        buf.extend_from_slice(&ack[..5]);
+        let (frames, consumed) = split_frames(&buf);
+        assert_eq!(frames.len(), 2);
+        assert_eq!(&buf[consumed..], &ack[..5]);
+    }
+
+    #[test]
+    fn test_poll() {
+        let (ack, buf) = false_sync_before_acks();
+        let mut deframer = Deframer::new();
+        assert_eq!(buf.iter().filter_map(|&b| deframer.push(b)).count(), 1);
+        let frame = deframer.poll().unwrap();
+        assert_eq!(frame.into_framed_vec(), ack);
+        assert!(deframer.poll().is_none());
+        assert!(Deframer::new().poll().is_none());
+    }
+
+    #[test]
+    fn test_with_syncword() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut bridged = alloc::vec![0x00, 0xaa, 0x55];
+        bridged.extend_from_slice(&ack[2..]);
+
+        let mut deframer = Deframer::with_syncword(0xaa55);
+        let frames: Vec<Frame> = ack
+            .iter()
+            .chain(bridged.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert_eq!(frames.len(), 1);
+        assert_eq!((frames[0].class, frames[0].id), (0x05, 0x01));
+        assert_eq!(frames[0].message, [0x06, 0x01]);
+        assert_eq!(deframer.bytes_discarded(), ack.len() + 1);
+
+        let mut deframer = Deframer::new();
+        assert_eq!(bridged.iter().filter_map(|&b| deframer.push(b)).count(), 0);
+    }
+
+    #[test]
+    fn test_empty_payload() {
+        let poll = Frame {
+            class: 0x0a,
+            id: 0x04,
+            message: Vec::new(),
+        };
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut deframer = Deframer::new();
+        let frames: Vec<Frame> = poll
+            .clone()
+            .into_framed_vec()
+            .iter()
+            .chain(ack.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], poll);
+        assert_eq!(frames[1].message, [0x06, 0x01]);
+        assert_eq!(deframer.bytes_discarded(), 0);
+    }
+
+    #[test]
+    fn test_consecutive_frames() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let eoe = Frame {
+            class: 0x01,
+            id: 0x61,
+            message: alloc::vec![0x10, 0x20, 0x30, 0x40],
+        };
+        let mut deframer = Deframer::new();
+        let frames: Vec<_> = ack
+            .iter()
+            .chain(eoe.clone().into_framed_vec().iter())
+            .chain(ack.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].message, [0x06, 0x01]);
+        assert_eq!(frames[1], eoe);
+        assert_eq!(frames[2], frames[0]);
+    }
+
+    #[test]
+    fn test_reuses_payload_buffer() {
+        let good = Frame {
+            class: 0x01,
+            id: 0x61,
+            message: alloc::vec![0x10, 0x20, 0x30, 0x40],
+        }
+        .into_framed_vec();
+        let mut bad = good.clone();
+        *bad.last_mut().unwrap() ^= 0xff;
+        let stream: Vec<u8> = [0x00, 0x24]
+            .iter()
+            .chain(bad.iter())
+            .chain(good.iter())
+            .copied()
+            .collect();
+
+        let mut deframer = Deframer::new();
+        // Warm up the payload buffer and backlog.
+        assert_eq!(stream.iter().filter_map(|&b| deframer.push(b)).count(), 1);
+
+        let before = allocs();
+        let mut frames = 0;
+        for _ in 0..10 {
+            frames += stream.iter().filter_map(|&b| deframer.push(b)).count();
+        }
+        assert_eq!(frames, 10);
+        // One allocation per returned payload, none for the rejected
+        // frames.
+        assert_eq!(allocs() - before, frames);
+        assert_eq!(deframer.checksum_errors(), 11);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let good = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+        let bad = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x27];
+        let mut deframer = Deframer::with_events();
+        let frames = [0x24, 0x47, 0xb5]
+            .iter()
+            .chain(good.iter())
+            .chain(bad.iter())
+            .filter_map(|&b| deframer.push(b))
+            .count();
+        assert_eq!(frames, 1);
+        // The bad frame is rescanned and discarded after its checksum
+        // fails.
+        assert_eq!(deframer.bytes_discarded(), 3 + bad.len());
+        assert_eq!(deframer.checksum_errors(), 1);
+        assert_eq!(
+            deframer.events().collect::<Vec<_>>(),
+            [
+                DeframerEvent::Resync { discarded: 3 },
+                DeframerEvent::Checksum
+            ]
+        );
+        assert_eq!(deframer.events().count(), 0);
+        assert_eq!(Deframer::new().events().count(), 0);
+    }
+
+    #[test]
+    fn test_resync_after_false_sync() {
+        // A false syncword declaring a 16 byte payload, followed by a
+        // real frame which would be swallowed as that payload.
+        let garbage = [0x00, 0xb5, 0x62, 0x42, 0x42, 0x10, 0x00, 0x33];
+        let good = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+        let mut deframer = Deframer::new();
+        let frames: Vec<_> = garbage
+            .iter()
+            .chain(good.iter())
+            .chain(good.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].class, frames[0].id), (0x05, 0x01));
+        assert_eq!(frames[0], frames[1]);
+        assert_eq!(deframer.checksum_errors(), 1);
+    }
+
+    #[test]
+    fn test_resync_after_oversize() {
+        let garbage = [0xb5, 0x62, 0xb5, 0x62, 0xff, 0xff];
+        let good = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+        let mut deframer = Deframer::new();
+        let frames = garbage
+            .iter()
+            .chain(good.iter())
+            .filter_map(|&b| deframer.push(b))
+            .count();
+        assert_eq!(frames, 1);
+
+        // The previous frame's payload is not rescanned.
+        let oversize = [0xb5, 0x62, 0x05, 0x01, 0xff, 0xff];
+        let mut deframer = Deframer::new();
+        let frames = good
+            .iter()
+            .chain(oversize.iter())
+            .chain(good.iter())
+            .filter_map(|&b| deframer.push(b))
+            .count();
+        assert_eq!(frames, 2);
+        assert_eq!(deframer.bytes_discarded(), oversize.len());
+    }
+
+    #[test]
+    fn test_nmea_passthrough() {
+        let nmea = b"$GPTXT,01,01,02,u-blox ag - www.u-blox.com*50\r\n";
+        let ubx = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+        let mut deframer = Deframer::new();
+        let items: Vec<StreamItem> = nmea
+            .iter()
+            .chain(ubx.iter())
+            .chain(nmea.iter())
+            .filter_map(|&b| deframer.push_item(b))
+            .collect();
+        assert_eq!(items.len(), 3);
+        assert_eq!(
+            items[0],
+            StreamItem::Nmea("$GPTXT,01,01,02,u-blox ag - www.u-blox.com*50".into())
+        );
+        assert!(matches!(items[1], StreamItem::Ubx(_)));
+        assert_eq!(items[0], items[2]);
+    }
+
+    #[test]
+    fn test_abandoned_nmea() {
+        // A `$` followed by binary data must not swallow the next frame.
+        let bytes = [
+            0x24, 0x01, 0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26,
+        ];
+        let mut deframer = Deframer::new();
+        let items: Vec<StreamItem> = bytes
+            .iter()
+            .filter_map(|&b| deframer.push_item(b))
+            .collect();
+        assert!(matches!(items.as_slice(), [StreamItem::Ubx(_)]));
+    }
 }
@@ -1,11 +1,12 @@
-/// The error type returned by [`Deframer::push()`].
+use core::fmt;
+
+/// The error type returned by [`StaticDeframer::push()`].
 ///
-/// [`Deframer::push()`]: enum.Deframer.html#method.push
+/// [`StaticDeframer::push()`]: struct.StaticDeframer.html#method.push
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum FrameError {
     /// The payload length parsed out of message is larger than we can
     /// store.
-    #[cfg(not(feature = "std"))]
     Size {
         /// Declared message length parsed from byte stream.
         declared: usize,
@@ -20,3 +21,38 @@ pub enum FrameError {
     /// after receiving only the first declared checksum byte.
     Checksum,
 }
+
+impl fmt::Display for FrameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FrameError::Size { declared, capacity } => write!(
+                f,
+                "declared length {} exceeds capacity {}",
+                declared, capacity
+            ),
+            FrameError::Checksum => f.write_str("checksum mismatch"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FrameError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(FrameError::Checksum.to_string(), "checksum mismatch");
+        assert_eq!(
+            FrameError::Size {
+                declared: 200,
+                capacity: 128
+            }
+            .to_string(),
+            "declared length 200 exceeds capacity 128"
+        );
+    }
+}
@@ -1,5 +1,7 @@
 use crate::framing::{Checksum, FrameVec};
-use crate::messages::Message;
+use crate::messages::{Message, VarMessage};
+use bytes::Bytes;
+use core::fmt;
 
 /// The type returned by [`Deframer::push()`] upon successfully parsing
 /// a u-blox message.
@@ -23,7 +25,35 @@ pub struct Frame {
 }
 
 impl Frame {
+    /// Returns a frame holding `msg`, with `class` and `id` taken from
+    /// `M`.
+    ///
+    /// Prefer this to building a `Frame` from its fields, which makes
+    /// it possible to pair a payload with the wrong class or ID.
+    ///
+    /// Returns an error if `msg` cannot be serialized.
+    pub fn for_message<M: Message>(msg: &M) -> Result<Frame, ()> {
+        let mut message = alloc::vec![0; M::LEN];
+        msg.serialize(&mut message.as_mut_slice())?;
+        Ok(Frame {
+            class: M::CLASS,
+            id: M::ID,
+            message,
+        })
+    }
+
+    /// Returns a mutable reference to the payload, e.g. to rewrite a
+    /// frame before forwarding it.
+    ///
+    /// The checksum is computed when the frame is serialized, so it
+    /// always matches the edited payload.
+    pub fn message_mut(&mut self) -> &mut FrameVec {
+        &mut self.message
+    }
+
     /// Converts `Frame` into to framed vector of bytes.
+    ///
+    /// The length and checksum are computed from the current payload.
     pub fn into_framed_vec(self) -> FrameVec {
         let Frame {
             class,
@@ -54,20 +84,93 @@ impl Frame {
     }
 }
 
+/// Formats a one-line summary of the frame without its payload, e.g.
+/// `UBX[class=0x05 id=0x01 len=2]`.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "UBX[class={:#04x} id={:#04x} len={}]",
+            self.class,
+            self.id,
+            self.message.len()
+        )
+    }
+}
+
+/// A [`Frame`] whose payload is a reference-counted [`Bytes`] buffer.
+///
+/// Converting a [`Frame`] into a `BytesFrame` takes ownership of its
+/// payload without copying it, and the payload can then be shared
+/// cheaply, e.g. between tasks of a high-throughput pipeline.
+///
+/// [`Bytes`]: bytes::Bytes
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BytesFrame {
+    /// Message class.
+    pub class: u8,
+    /// Message ID.
+    pub id: u8,
+    /// The message's payload.
+    pub message: Bytes,
+}
+
+impl From<Frame> for BytesFrame {
+    fn from(frame: Frame) -> Self {
+        BytesFrame {
+            class: frame.class,
+            id: frame.id,
+            message: Bytes::from(frame.message),
+        }
+    }
+}
+
+const FRAME_OVERHEAD: usize = 8;
+
 /// Frame a u-blox message to a buffer.
 pub fn frame<M: Message>(msg: &M, dst: &mut [u8]) -> Result<usize, ()> {
-    const FRAME_OVERHEAD: usize = 8;
-    if dst.len() < (FRAME_OVERHEAD + M::LEN) {
+    frame_with(M::CLASS, M::ID, M::LEN, dst, |body| msg.serialize(body))
+}
+
+/// Frame a variable-length u-blox message to a buffer.
+pub fn frame_var<M: VarMessage>(msg: &M, dst: &mut [u8]) -> Result<usize, ()> {
+    frame_with(M::CLASS, M::ID, msg.payload_len(), dst, |body| {
+        msg.serialize(body)
+    })
+}
+
+/// Frame an arbitrary payload to a buffer, e.g. to forward a frame of
+/// an unknown message.
+///
+/// Returns the frame's length, or an error if `dst` is too small or
+/// `payload` is longer than a frame can hold.
+pub fn frame_raw(class: u8, id: u8, payload: &[u8], dst: &mut [u8]) -> Result<usize, ()> {
+    if payload.len() > usize::from(u16::MAX) {
         return Err(());
     }
-    let dst = &mut dst[..M::LEN + FRAME_OVERHEAD];
+    frame_with(class, id, payload.len(), dst, |body| {
+        body.copy_from_slice(payload);
+        Ok(())
+    })
+}
+
+/// Writes frame prelude and checksum around the `len` byte message
+/// body written by `serialize`.
+fn frame_with<F>(class: u8, id: u8, len: usize, dst: &mut [u8], serialize: F) -> Result<usize, ()>
+where
+    F: FnOnce(&mut &mut [u8]) -> Result<(), ()>,
+{
+    if dst.len() < (FRAME_OVERHEAD + len) {
+        return Err(());
+    }
+    let dst = &mut dst[..len + FRAME_OVERHEAD];
     // Prelude
     {
-        let [len_lsb, len_msb] = (M::LEN as u16).to_le_bytes();
-        dst[..6].clone_from_slice(&[0xB5, 0x62, M::CLASS, M::ID, len_lsb, len_msb]);
+        let [len_lsb, len_msb] = (len as u16).to_le_bytes();
+        dst[..6].clone_from_slice(&[0xB5, 0x62, class, id, len_lsb, len_msb]);
     }
     // Mesage body.
-    msg.serialize(&mut dst[6..(M::LEN + 6)].as_mut())?;
+    serialize(&mut dst[6..(len + 6)].as_mut())?;
     // Append checksum.
     {
         let mut cksm = Checksum::default();
@@ -77,7 +180,86 @@ pub fn frame<M: Message>(msg: &M, dst: &mut [u8]) -> Result<usize, ()> {
             cksm.push(*b);
         }
         let (ck_a, ck_b) = cksm.take();
-        dst[M::LEN + 6..].clone_from_slice(&[ck_a, ck_b]);
+        dst[len + 6..].clone_from_slice(&[ck_a, ck_b]);
+    }
+    Ok(len + FRAME_OVERHEAD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{cfg::SetMsgRates, nav};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_for_message() {
+        let msg = SetMsgRates {
+            class: nav::Pvt::CLASS,
+            id: nav::Pvt::ID,
+            ddc: 1,
+            uart1: 0,
+            uart2: 0,
+            usb: 1,
+            spi: 0,
+        };
+        let frame = Frame::for_message(&msg).unwrap();
+        assert_eq!(
+            (frame.class, frame.id),
+            (SetMsgRates::CLASS, SetMsgRates::ID)
+        );
+        assert_eq!(frame.message.len(), SetMsgRates::LEN);
+
+        let mut expected = [0_u8; SetMsgRates::LEN + FRAME_OVERHEAD];
+        super::frame(&msg, &mut expected).unwrap();
+        assert_eq!(frame.into_framed_vec(), expected);
+    }
+
+    #[test]
+    fn test_message_mut() {
+        let mut frame = Frame {
+            class: 0x05,
+            id: 0x01,
+            message: alloc::vec![0x06, 0x01],
+        };
+        let original = frame.clone().into_framed_vec();
+        assert_eq!(&original[8..], [0x0f, 0x38]);
+
+        frame.message_mut()[1] = 0x24;
+        let edited = frame.clone().into_framed_vec();
+        assert_eq!(&edited[..7], &original[..7]);
+        assert_eq!(edited[7], 0x24);
+        assert_eq!(&edited[8..], [0x32, 0x5b]);
+        assert_eq!(crate::framing::deframe(edited), Some(frame.clone()));
+
+        frame.message_mut().push(0x00);
+        let grown = frame.into_framed_vec();
+        assert_eq!(grown[4], 3);
+        assert_eq!(grown.len(), original.len() + 1);
+    }
+
+    #[test]
+    fn test_frame_raw() {
+        for payload in [&[][..], &[0x06, 0x01][..], &[0xa5; 300][..]].iter() {
+            let frame = Frame {
+                class: 0x13,
+                id: 0x40,
+                message: payload.to_vec(),
+            };
+            let expected = frame.into_framed_vec();
+            let mut buf = [0xff_u8; 320];
+            let len = frame_raw(0x13, 0x40, payload, &mut buf).unwrap();
+            assert_eq!(&buf[..len], expected.as_slice());
+            assert!(frame_raw(0x13, 0x40, payload, &mut buf[..len - 1]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let frame = Frame {
+            class: 0x05,
+            id: 0x01,
+            message: alloc::vec![0x06, 0x01],
+        };
+        assert_eq!(frame.to_string(), "UBX[class=0x05 id=0x01 len=2]");
     }
-    Ok(M::LEN + FRAME_OVERHEAD)
 }
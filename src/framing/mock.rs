@@ -0,0 +1,213 @@
+//! In-memory transport for testing message exchanges.
+
+use crate::messages::{framed_vec, framed_vec_var, Message, VarMessage};
+use std::{
+    collections::VecDeque,
+    io::{self, ErrorKind, Read, Write},
+    vec::Vec,
+};
+
+/// An in-memory `Read + Write` transport standing in for a receiver.
+///
+/// Bytes written to the transport are captured for inspection, and
+/// reads return a scripted sequence of bytes. Once the script is
+/// exhausted, reads fail with [`ErrorKind::WouldBlock`], which
+/// [`Transport`] treats as "no data yet", so timeouts behave as they
+/// would with a silent receiver.
+///
+/// # Example
+///
+/// ```
+/// # use std::time::Duration;
+/// # use ublox::{framing::{MockTransport, Transport}, messages::{ack::Ack, cfg::SetMsgRates}};
+/// let msg = SetMsgRates { class: 0x01, id: 0x07, ddc: 0, uart1: 1, uart2: 0, usb: 0, spi: 0 };
+/// let mut mock = MockTransport::new();
+/// mock.push_message(&Ack { class: 0x06, id: 0x01 }).unwrap();
+/// let mut transport = Transport::new(mock);
+/// assert!(transport.send_and_wait_ack(&msg, Duration::from_millis(100)).unwrap());
+/// ```
+///
+/// [`Transport`]: crate::framing::Transport
+#[derive(Clone, Debug, Default)]
+pub struct MockTransport {
+    rx: VecDeque<u8>,
+    tx: Vec<u8>,
+}
+
+impl MockTransport {
+    /// Returns a new transport with nothing to read.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `bytes` to the bytes returned by future reads.
+    pub fn push_bytes(&mut self, bytes: &[u8]) {
+        self.rx.extend(bytes);
+    }
+
+    /// Appends `msg`, framed, to the bytes returned by future reads.
+    ///
+    /// Returns an error if `msg` cannot be serialized.
+    pub fn push_message<M: Message>(&mut self, msg: &M) -> Result<(), ()> {
+        self.push_bytes(&framed_vec(msg)?);
+        Ok(())
+    }
+
+    /// Appends variable-length `msg`, framed, to the bytes returned
+    /// by future reads.
+    ///
+    /// Returns an error if `msg` cannot be serialized.
+    pub fn push_var_message<M: VarMessage>(&mut self, msg: &M) -> Result<(), ()> {
+        self.push_bytes(&framed_vec_var(msg)?);
+        Ok(())
+    }
+
+    /// Returns all bytes written to the transport so far.
+    pub fn written(&self) -> &[u8] {
+        &self.tx
+    }
+
+    /// Returns and clears all bytes written to the transport so far.
+    pub fn take_written(&mut self) -> Vec<u8> {
+        ::core::mem::take(&mut self.tx)
+    }
+
+    /// Returns the number of scripted bytes not yet read.
+    pub fn unread(&self) -> usize {
+        self.rx.len()
+    }
+}
+
+impl Read for MockTransport {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.rx.is_empty() && !buf.is_empty() {
+            return Err(ErrorKind::WouldBlock.into());
+        }
+        self.rx.read(buf)
+    }
+}
+
+impl Write for MockTransport {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.tx.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::Frame;
+    use crate::framing::Transport;
+    use crate::messages::{ack::Nak, cfg::SetMsgRates, mon::MonVer, Message};
+    use std::time::Duration;
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn test_send_and_wait_ack() {
+        let msg = SetMsgRates {
+            class: 0x01,
+            id: 0x07,
+            ddc: 0,
+            uart1: 1,
+            uart2: 0,
+            usb: 0,
+            spi: 0,
+        };
+        let mut mock = MockTransport::new();
+        // Unrelated traffic followed by UBX-ACK-ACK for CFG-MSG.
+        mock.push_bytes(b"$GPTXT*00\r\n");
+        mock.push_bytes(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+        mock.push_message(&Nak {
+            class: SetMsgRates::CLASS,
+            id: SetMsgRates::ID,
+        })
+        .unwrap();
+
+        let mut transport = Transport::new(mock);
+        assert!(transport.send_and_wait_ack(&msg, TIMEOUT).unwrap());
+        assert_eq!(
+            transport.get_ref().written(),
+            framed_vec(&msg).unwrap().as_slice()
+        );
+        assert!(!transport.send_and_wait_ack(&msg, TIMEOUT).unwrap());
+        assert_eq!(
+            transport
+                .send_and_wait_ack(&msg, TIMEOUT)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::TimedOut
+        );
+        assert_eq!(transport.get_ref().unread(), 0);
+    }
+
+    #[test]
+    fn test_recv_frame_drains_backlog() {
+        // A false syncword declaring a 24 byte payload which swallows
+        // two ACKs, followed by a bad checksum as the final byte.
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let mut mock = MockTransport::new();
+        mock.push_bytes(&[0xb5, 0x62, 0x42, 0x42, 0x18, 0x00]);
+        mock.push_bytes(&ack);
+        mock.push_bytes(&ack);
+        mock.push_bytes(&[0x00; 5]);
+
+        let mut transport = Transport::new(mock);
+        let deadline = std::time::Instant::now() + TIMEOUT;
+        let first = transport.recv_frame(deadline).unwrap().unwrap();
+        let second = transport.recv_frame(deadline).unwrap().unwrap();
+        assert_eq!(first, second);
+        assert!(std::time::Instant::now() < deadline);
+        assert_eq!(transport.recv_frame(deadline).unwrap(), None);
+    }
+
+    #[test]
+    fn test_poll_response() {
+        let ver = MonVer::new("ROM CORE 3.01 (107888)", "00080000", &["PROTVER=18.00"]);
+        let mut mock = MockTransport::new();
+        // An unrelated frame followed by the response.
+        mock.push_bytes(&[0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38]);
+        mock.push_var_message(&ver).unwrap();
+
+        let mut transport = Transport::new(mock);
+        assert_eq!(transport.poll_response::<MonVer>(TIMEOUT).unwrap(), ver);
+        let poll = Frame {
+            class: MonVer::CLASS,
+            id: MonVer::ID,
+            message: Vec::new(),
+        };
+        assert_eq!(
+            transport.get_ref().written(),
+            poll.into_framed_vec().as_slice()
+        );
+        assert_eq!(
+            transport
+                .poll_response::<MonVer>(TIMEOUT)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::TimedOut
+        );
+
+        transport
+            .get_mut()
+            .push_message(&Nak {
+                class: MonVer::CLASS,
+                id: MonVer::ID,
+            })
+            .unwrap();
+        let start = std::time::Instant::now();
+        assert_eq!(
+            transport
+                .poll_response::<MonVer>(TIMEOUT)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::PermissionDenied
+        );
+        assert!(start.elapsed() < TIMEOUT);
+    }
+}
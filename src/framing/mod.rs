@@ -4,11 +4,35 @@ mod checksum;
 mod deframer;
 mod error;
 mod frame;
+#[cfg(feature = "std")]
+mod mock;
+#[cfg(feature = "std")]
+mod receiver;
+mod rtcm;
+mod static_deframer;
+#[cfg(feature = "tokio")]
+mod stream;
+#[cfg(feature = "std")]
+mod transport;
+#[cfg(feature = "std")]
+mod writer;
 
 pub use checksum::Checksum;
-pub use deframer::{deframe, Deframer};
+pub use deframer::{deframe, deframe_all, split_frames, Deframer, DeframerEvent, StreamItem};
 pub use error::FrameError;
-pub use frame::{frame, Frame};
+pub use frame::{frame, frame_raw, frame_var, BytesFrame, Frame};
+#[cfg(feature = "std")]
+pub use mock::MockTransport;
+#[cfg(feature = "std")]
+pub use receiver::Receiver;
+pub use rtcm::{MultiDeframer, StreamFrame};
+pub use static_deframer::{FrameRef, StaticDeframer, StaticDeframer128};
+#[cfg(feature = "tokio")]
+pub use stream::FrameStream;
+#[cfg(feature = "std")]
+pub use transport::Transport;
+#[cfg(feature = "std")]
+pub use writer::FramedWriter;
 
 /// TODO: add `std` feature and use `heapless::Vec<u8,
 /// heapless::consts::U128>` when not `std` feature is not enabled.
@@ -0,0 +1,224 @@
+//! High-level message exchange with a receiver.
+
+use crate::framing::Transport;
+use crate::messages::{
+    cfg::{PortId, SetMsgRates},
+    Message, Msg, VarMessage,
+};
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    time::{Duration, Instant},
+};
+
+/// Owns a `Read + Write` transport connected to a receiver and
+/// exchanges parsed messages with it.
+///
+/// Every operation waits at most [`timeout()`](Self::timeout) for
+/// the receiver, failing with an error of kind
+/// [`ErrorKind::TimedOut`] otherwise.
+///
+/// # Example
+///
+/// ```
+/// # use ublox::{framing::{MockTransport, Receiver}, messages::{ack::Ack, cfg::PortId, nav::{Eoe, Pvt}, Msg}};
+/// let mut mock = MockTransport::new();
+/// mock.push_message(&Ack { class: 0x06, id: 0x01 }).unwrap();
+/// mock.push_message(&Eoe { iTOW: 1000 }).unwrap();
+/// let mut receiver = Receiver::new(mock);
+/// assert!(receiver.set_rate::<Pvt>(PortId::Uart1, 1).unwrap());
+/// assert!(matches!(receiver.next_message().unwrap(), Msg::Nav(_)));
+/// ```
+#[derive(Debug)]
+pub struct Receiver<T> {
+    transport: Transport<T>,
+    timeout: Duration,
+}
+
+impl<T: Read + Write> Receiver<T> {
+    /// Default for [`timeout()`](Self::timeout).
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Returns a new `Receiver` communicating over `inner`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            transport: Transport::new(inner),
+            timeout: Self::DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Returns how long operations wait for the receiver.
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Sets how long operations wait for the receiver.
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Returns a reference to the underlying transport.
+    pub fn get_ref(&self) -> &T {
+        self.transport.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying transport.
+    pub fn get_mut(&mut self) -> &mut T {
+        self.transport.get_mut()
+    }
+
+    /// Consumes `self`, returning the underlying transport.
+    pub fn into_inner(self) -> T {
+        self.transport.into_inner()
+    }
+
+    /// Returns the next message received.
+    ///
+    /// Returns an error of kind [`ErrorKind::InvalidData`] if the next
+    /// frame cannot be parsed. The receiver remains usable afterward,
+    /// so callers may skip such frames by calling this again.
+    pub fn next_message(&mut self) -> io::Result<Msg> {
+        let deadline = Instant::now() + self.timeout;
+        match self.transport.recv_frame(deadline)? {
+            Some(frame) => {
+                Msg::from_frame(&frame).map_err(|e| io::Error::new(ErrorKind::InvalidData, e))
+            }
+            None => Err(io::Error::new(
+                ErrorKind::TimedOut,
+                "timed out waiting for message",
+            )),
+        }
+    }
+
+    /// Configures the receiver to send message `M` every `rate`
+    /// navigation solutions on `port` only, disabling it on all other
+    /// ports.
+    ///
+    /// Returns the result of [`configure()`](Self::configure).
+    pub fn set_rate<M: Message>(&mut self, port: PortId, rate: u8) -> io::Result<bool> {
+        self.configure(&SetMsgRates::on_port(port, M::CLASS, M::ID, rate))
+    }
+
+    /// Sends `msg`, then waits for the receiver to acknowledge or
+    /// reject it.
+    ///
+    /// Returns `Ok(true)` upon UBX-ACK-ACK and `Ok(false)` upon
+    /// UBX-ACK-NAK. Any other messages received while waiting are
+    /// discarded.
+    pub fn configure<M: Message>(&mut self, msg: &M) -> io::Result<bool> {
+        self.transport.send_and_wait_ack(msg, self.timeout)
+    }
+
+    /// Like [`configure()`](Self::configure), but for variable-length
+    /// messages such as CFG-PRT.
+    pub fn configure_var<M: VarMessage>(&mut self, msg: &M) -> io::Result<bool> {
+        self.transport.send_and_wait_ack_var(msg, self.timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::{Frame, MockTransport};
+    use crate::messages::{
+        ack::{Ack, Nak},
+        cfg::{
+            hnr::CfgHnr,
+            prt::{InProtoMask, OutProtoMask, Prt, TxReady},
+        },
+        nav::{Eoe, Nav, Pvt},
+    };
+
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    #[test]
+    fn test_receiver() {
+        let mut mock = MockTransport::new();
+        for _ in 0..2 {
+            mock.push_message(&Ack {
+                class: SetMsgRates::CLASS,
+                id: SetMsgRates::ID,
+            })
+            .unwrap();
+        }
+        mock.push_message(&Eoe { iTOW: 1000 }).unwrap();
+        // Truncated NAV-EOE, followed by UBX-ACK-NAK for CFG-HNR.
+        let truncated = Frame {
+            class: Eoe::CLASS,
+            id: Eoe::ID,
+            message: std::vec![0],
+        };
+        mock.push_bytes(&truncated.into_framed_vec());
+        mock.push_message(&Nak {
+            class: CfgHnr::CLASS,
+            id: CfgHnr::ID,
+        })
+        .unwrap();
+
+        let mut receiver = Receiver::new(mock);
+        receiver.set_timeout(TIMEOUT);
+        // CFG-MSG rate[6] is indexed by port ID: USB is rate[3] and
+        // UART2 is rate[2].
+        assert!(receiver.set_rate::<Pvt>(PortId::Usb, 2).unwrap());
+        assert_eq!(
+            receiver.get_mut().take_written(),
+            [
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x07, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00,
+                0x19, 0xe2
+            ]
+        );
+        assert!(receiver.set_rate::<Pvt>(PortId::Uart2, 1).unwrap());
+        assert_eq!(
+            receiver.get_mut().take_written(),
+            [
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x07, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+                0x18, 0xe0
+            ]
+        );
+
+        match receiver.next_message().unwrap() {
+            Msg::Nav(Nav::Eoe(eoe)) => assert_eq!(eoe.iTOW, 1000),
+            msg => panic!("expected NAV-EOE, got {:?}", msg),
+        }
+        assert_eq!(
+            receiver.next_message().unwrap_err().kind(),
+            ErrorKind::InvalidData
+        );
+
+        assert!(!receiver.configure(&CfgHnr { high_nav_rate: 30 }).unwrap());
+        assert_eq!(
+            receiver.next_message().unwrap_err().kind(),
+            ErrorKind::TimedOut
+        );
+        assert_eq!(receiver.get_ref().unread(), 0);
+    }
+
+    #[test]
+    fn test_configure_var() {
+        let mut mock = MockTransport::new();
+        mock.push_message(&Ack {
+            class: Prt::CLASS,
+            id: Prt::ID,
+        })
+        .unwrap();
+
+        let mut receiver = Receiver::new(mock);
+        receiver.set_timeout(TIMEOUT);
+        let prt = Prt::Usb {
+            tx_ready: TxReady(0),
+            in_proto_mask: InProtoMask(0x0001),
+            out_proto_mask: OutProtoMask(0x0001),
+        };
+        assert!(receiver.configure_var(&prt).unwrap());
+        assert_eq!(
+            receiver.get_mut().take_written(),
+            [
+                0xb5, 0x62, 0x06, 0x00, 0x14, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1f, 0x92
+            ]
+        );
+        assert_eq!(
+            receiver.configure_var(&prt).unwrap_err().kind(),
+            ErrorKind::TimedOut
+        );
+    }
+}
@@ -0,0 +1,251 @@
+//! Combined UBX and RTCM3 deframing.
+
+use crate::framing::{Deframer, Frame, FrameVec};
+
+/// RTCM3 frame preamble.
+const RTCM_PREAMBLE: u8 = 0xD3;
+
+/// Length of RTCM3 frame header (preamble + length).
+const RTCM_HEADER_LEN: usize = 3;
+
+/// Length of RTCM3 frame CRC.
+const RTCM_CRC_LEN: usize = 3;
+
+/// A frame returned by [`MultiDeframer::push()`].
+///
+/// [`MultiDeframer::push()`]: struct.MultiDeframer.html#method.push
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StreamFrame {
+    /// A UBX frame.
+    Ubx(Frame),
+    /// An RTCM3 frame.
+    Rtcm {
+        /// RTCM3 message number, i.e. the first 12 bits of `payload`.
+        msg_type: u16,
+        /// Frame payload, excluding the header and CRC.
+        payload: FrameVec,
+    },
+}
+
+/// A deframer for byte streams containing interleaved UBX and RTCM3
+/// frames, as output by RTK base stations.
+///
+/// RTCM3 frames begin with the preamble `0xD3`, followed by 6 reserved
+/// (zero) bits and a 10-bit payload length, and end with a CRC-24Q.
+/// Frames failing CRC validation are discarded.
+#[derive(Clone, Debug, Default)]
+pub struct MultiDeframer {
+    ubx: Deframer,
+    rtcm: Option<FrameVec>,
+}
+
+impl MultiDeframer {
+    /// Returns a new deframer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Incrementally parses a UBX or RTCM3 frame with the given
+    /// `input`.
+    pub fn push(&mut self, input: u8) -> Option<StreamFrame> {
+        // The UBX deframer sees every byte, even while an RTCM3 frame
+        // is in progress, so a false preamble cannot hide UBX frames.
+        let ubx = self.ubx.push(input).map(StreamFrame::Ubx);
+        let buf = match &mut self.rtcm {
+            None if input == RTCM_PREAMBLE && self.ubx.is_syncing() => {
+                trace!("rtcm preamble");
+                let mut buf = FrameVec::with_capacity(RTCM_HEADER_LEN);
+                buf.push(input);
+                self.rtcm = Some(buf);
+                return ubx;
+            }
+            None => return ubx,
+            Some(buf) => buf,
+        };
+        if ubx.is_some() {
+            trace!("ubx frame inside rtcm frame, resyncing");
+            self.rtcm = None;
+            return ubx;
+        }
+
+        buf.push(input);
+        self.complete_rtcm()
+    }
+
+    /// Returns the RTCM3 frame in progress if it is complete and
+    /// valid.
+    ///
+    /// An invalid frame is abandoned, and its bytes following the
+    /// preamble are searched for the next preamble. The UBX deframer
+    /// has already seen them.
+    fn complete_rtcm(&mut self) -> Option<StreamFrame> {
+        let buf = self.rtcm.as_mut()?;
+        // The 6 bits following the preamble are reserved and must be
+        // zero, else this wasn't an RTCM3 frame after all.
+        if buf.len() >= 2 && buf[1] & 0xFC != 0 {
+            trace!("rtcm reserved bits set, resyncing");
+            return self.resync();
+        }
+        if buf.len() < RTCM_HEADER_LEN {
+            return None;
+        }
+        let len = (usize::from(buf[1] & 0x03) << 8) | usize::from(buf[2]);
+        if buf.len() == RTCM_HEADER_LEN {
+            buf.reserve(len + RTCM_CRC_LEN);
+        }
+        if buf.len() < RTCM_HEADER_LEN + len + RTCM_CRC_LEN {
+            return None;
+        }
+
+        let crc_pos = RTCM_HEADER_LEN + len;
+        let crc_calc = crc24q(&buf[..crc_pos]);
+        let crc_rx = (u32::from(buf[crc_pos]) << 16)
+            | (u32::from(buf[crc_pos + 1]) << 8)
+            | u32::from(buf[crc_pos + 2]);
+        if crc_calc != crc_rx {
+            warn!(
+                "rtcm crc mismatch, expected {:#08x}, got {:#08x}",
+                crc_calc, crc_rx
+            );
+            return self.resync();
+        }
+
+        let mut buf = self.rtcm.take().unwrap();
+        // The UBX deframer may have synced on bytes of this frame.
+        self.ubx = Deframer::new();
+        if len < 2 {
+            warn!("rtcm payload too short to contain a message number");
+            return None;
+        }
+        buf.truncate(crc_pos);
+        buf.drain(..RTCM_HEADER_LEN);
+        let msg_type = (u16::from(buf[0]) << 4) | (u16::from(buf[1]) >> 4);
+        Some(StreamFrame::Rtcm {
+            msg_type,
+            payload: buf,
+        })
+    }
+
+    /// Abandons the current RTCM3 frame, restarting it at the next
+    /// preamble among its bytes, if any.
+    fn resync(&mut self) -> Option<StreamFrame> {
+        let mut buf = self.rtcm.take()?;
+        let pos = buf[1..].iter().position(|&b| b == RTCM_PREAMBLE)?;
+        buf.drain(..=pos);
+        self.rtcm = Some(buf);
+        self.complete_rtcm()
+    }
+}
+
+/// Returns the CRC-24Q of `bytes`.
+fn crc24q(bytes: &[u8]) -> u32 {
+    const POLY: u32 = 0x0186_4CFB;
+    let mut crc = 0_u32;
+    for &b in bytes {
+        crc ^= u32::from(b) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= POLY;
+            }
+        }
+    }
+    crc & 0x00FF_FFFF
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MultiDeframer, StreamFrame};
+    use alloc::vec::Vec;
+
+    // RTCM3 message 1005 (stationary reference station ARP).
+    const RTCM_1005: [u8; 25] = [
+        0xD3, 0x00, 0x13, 0x3E, 0xD7, 0xD3, 0x02, 0x02, 0x98, 0x0E, 0xDE, 0xEF, 0x34, 0xB4, 0xBD,
+        0x62, 0xAC, 0x09, 0x41, 0x98, 0x6F, 0x33, 0x36, 0x0B, 0x98,
+    ];
+
+    const UBX: [u8; 9] = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+
+    #[test]
+    fn test_rtcm_and_ubx() {
+        let mut deframer = MultiDeframer::new();
+        let frames: Vec<StreamFrame> = RTCM_1005
+            .iter()
+            .chain(UBX.iter())
+            .chain(RTCM_1005.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert_eq!(frames.len(), 3);
+        match &frames[0] {
+            StreamFrame::Rtcm { msg_type, payload } => {
+                assert_eq!(*msg_type, 1005);
+                assert_eq!(payload.as_slice(), &RTCM_1005[3..22]);
+            }
+            other => panic!("expected RTCM frame, got {:?}", other),
+        }
+        assert!(matches!(frames[1], StreamFrame::Ubx(_)));
+        assert_eq!(frames[0], frames[2]);
+    }
+
+    #[test]
+    fn test_rtcm_bad_crc() {
+        let mut corrupt = RTCM_1005;
+        corrupt[10] ^= 0x01;
+        let mut deframer = MultiDeframer::new();
+        let frames: Vec<StreamFrame> = corrupt
+            .iter()
+            .chain(UBX.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert!(matches!(frames.as_slice(), [StreamFrame::Ubx(_)]));
+    }
+
+    #[test]
+    fn test_false_preamble() {
+        // `0xD3` followed by nonzero reserved bits must not swallow
+        // the next UBX frame.
+        let mut deframer = MultiDeframer::new();
+        let frames: Vec<StreamFrame> = [0xD3, 0xFF]
+            .iter()
+            .chain(UBX.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert!(matches!(frames.as_slice(), [StreamFrame::Ubx(_)]));
+    }
+
+    #[test]
+    fn test_ubx_inside_false_rtcm() {
+        // `0xD3` followed by zero reserved bits claims a 0x3FF-byte
+        // payload, but the UBX frame within it must still be found,
+        // as must the RTCM3 frame following it.
+        let mut deframer = MultiDeframer::new();
+        let frames: Vec<StreamFrame> = [0xD3, 0x03, 0xFF, 0x00]
+            .iter()
+            .chain(UBX.iter())
+            .chain(RTCM_1005.iter())
+            .filter_map(|&b| deframer.push(b))
+            .collect();
+        assert!(matches!(
+            frames.as_slice(),
+            [
+                StreamFrame::Ubx(_),
+                StreamFrame::Rtcm { msg_type: 1005, .. }
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_rtcm_inside_corrupt_rtcm() {
+        // A corrupt RTCM3 frame whose CRC window ends inside a real
+        // RTCM3 frame must not swallow it.
+        let mut stream = Vec::new();
+        stream.extend_from_slice(&[0xD3, 0x00, 0x08, 0x00, 0x00]);
+        stream.extend_from_slice(&RTCM_1005);
+        let mut deframer = MultiDeframer::new();
+        let frames: Vec<StreamFrame> = stream.iter().filter_map(|&b| deframer.push(b)).collect();
+        assert!(matches!(
+            frames.as_slice(),
+            [StreamFrame::Rtcm { msg_type: 1005, .. }]
+        ));
+    }
+}
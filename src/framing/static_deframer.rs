@@ -0,0 +1,345 @@
+//! Heap-free u-blox deframer with a fixed capacity payload buffer.
+
+use crate::framing::{BytesFrame, Checksum, Frame, FrameError};
+
+/// A [`StaticDeframer`] whose capacity matches the 128 byte scratch
+/// buffers commonly used for I2C and SPI transfers.
+pub type StaticDeframer128 = StaticDeframer<128>;
+
+/// A u-blox frame borrowed from a [`StaticDeframer`]'s payload
+/// buffer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameRef<'a> {
+    /// Message class.
+    pub class: u8,
+    /// Message ID.
+    pub id: u8,
+    /// The message's payload.
+    pub message: &'a [u8],
+}
+
+impl<'a> FrameRef<'a> {
+    /// Copies `self` into an owned [`Frame`].
+    pub fn to_frame(&self) -> Frame {
+        Frame {
+            class: self.class,
+            id: self.id,
+            message: self.message.to_vec(),
+        }
+    }
+}
+
+impl<'a> From<&'a Frame> for FrameRef<'a> {
+    fn from(frame: &'a Frame) -> Self {
+        FrameRef {
+            class: frame.class,
+            id: frame.id,
+            message: &frame.message,
+        }
+    }
+}
+
+impl<'a> From<&'a BytesFrame> for FrameRef<'a> {
+    fn from(frame: &'a BytesFrame) -> Self {
+        FrameRef {
+            class: frame.class,
+            id: frame.id,
+            message: &frame.message,
+        }
+    }
+}
+
+/// A type for 'deframing' u-blox message frames into a fixed `N`
+/// byte payload buffer.
+///
+/// Unlike [`Deframer`](super::Deframer), `StaticDeframer` never
+/// allocates, which makes it suitable for `no_std` targets without a
+/// heap. Frames with payloads longer than `N` bytes are rejected.
+#[derive(Debug, Clone)]
+pub struct StaticDeframer<const N: usize> {
+    state: State,
+    buf: [u8; N],
+    cursor: usize,
+}
+
+impl<const N: usize> StaticDeframer<N> {
+    /// Returns a new deframer.
+    pub const fn new() -> Self {
+        StaticDeframer {
+            state: State::Sync { accum: 0 },
+            buf: [0; N],
+            cursor: 0,
+        }
+    }
+
+    /// Returns the largest payload `self` can store.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Incrementally parses a u-blox message frame with the given
+    /// `input`, returning a an error or optional [`FrameRef`].
+    ///
+    /// The deframer resumes searching for the next syncword after
+    /// returning an error.
+    pub fn push(&mut self, input: u8) -> Result<Option<FrameRef<'_>>, FrameError> {
+        use self::State::*;
+        match self.state {
+            Sync { accum } => {
+                const SYNCWORD: u16 = 0xB5_62;
+                let accum = (accum << 8) | u16::from(input);
+                self.state = if accum == SYNCWORD {
+                    Class
+                } else {
+                    Sync { accum }
+                };
+            }
+
+            Class => {
+                trace!("class {:#04x} ← sync", input);
+                self.state = Id {
+                    class: input,
+                    cksum: Checksum::with(input),
+                };
+            }
+
+            Id { class, mut cksum } => {
+                trace!("id {:#04x} ← class", input);
+                self.state = LengthLsb {
+                    class,
+                    id: cksum.push(input),
+                    cksum,
+                };
+            }
+
+            LengthLsb {
+                class,
+                id,
+                mut cksum,
+            } => {
+                trace!("len_l {:#04x} ← id", input);
+                self.state = LengthMsb {
+                    class,
+                    id,
+                    len_b0: cksum.push(input),
+                    cksum,
+                };
+            }
+
+            LengthMsb {
+                class,
+                id,
+                len_b0,
+                mut cksum,
+            } => {
+                let len = (usize::from(cksum.push(input)) << 8) | usize::from(len_b0);
+                if len > N {
+                    warn!("declared message length {} exceeds capacity {}", len, N);
+                    self.reset();
+                    return Err(FrameError::Size {
+                        declared: len,
+                        capacity: N,
+                    });
+                }
+                trace!("len_h {:#04x} ← len_lsb", input);
+                self.cursor = 0;
+                self.state = if len == 0 {
+                    CkA {
+                        class,
+                        id,
+                        cksum_calc: cksum.take(),
+                    }
+                } else {
+                    Message {
+                        class,
+                        id,
+                        len,
+                        cksum,
+                    }
+                };
+            }
+
+            Message {
+                class,
+                id,
+                len,
+                mut cksum,
+            } => {
+                self.buf[self.cursor] = cksum.push(input);
+                self.cursor += 1;
+                self.state = if self.cursor == len {
+                    CkA {
+                        class,
+                        id,
+                        cksum_calc: cksum.take(),
+                    }
+                } else {
+                    Message {
+                        class,
+                        id,
+                        len,
+                        cksum,
+                    }
+                };
+            }
+
+            CkA {
+                class,
+                id,
+                cksum_calc,
+            } => {
+                trace!("ck_a {:#04x} ← mesg", input);
+                if input != cksum_calc.0 {
+                    warn!(
+                        "ck_a mismatch, expected {:#04x}, got {:#04x}",
+                        cksum_calc.0, input
+                    );
+                    self.reset();
+                    return Err(FrameError::Checksum);
+                }
+                self.state = CkB {
+                    class,
+                    id,
+                    cksum_calc,
+                };
+            }
+
+            CkB {
+                class,
+                id,
+                cksum_calc,
+            } => {
+                trace!("ck_b {:#04x} ← ck_a", input);
+                let len = self.cursor;
+                self.reset();
+                if input != cksum_calc.1 {
+                    warn!(
+                        "ck_b mismatch, expected {:#04x}, got {:#04x}",
+                        cksum_calc.1, input
+                    );
+                    return Err(FrameError::Checksum);
+                }
+                return Ok(Some(FrameRef {
+                    class,
+                    id,
+                    message: &self.buf[..len],
+                }));
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn reset(&mut self) {
+        self.state = State::Sync { accum: 0 };
+    }
+}
+
+impl<const N: usize> Default for StaticDeframer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Deframer states.
+///
+/// Payload bytes are stored in the deframer's buffer rather than the
+/// state, hence all states are `Copy`.
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Shift in every byte until matches value equals the syncword.
+    Sync { accum: u16 },
+
+    /// No data, as the byte received durning this state is passed to
+    /// next state.
+    Class,
+
+    /// Byte received during this state is passed to next state.
+    Id { class: u8, cksum: Checksum },
+
+    /// Length LSB received during this state is passed to next state.
+    LengthLsb { class: u8, id: u8, cksum: Checksum },
+
+    /// Collect length's MSB.
+    LengthMsb {
+        class: u8,
+        id: u8,
+        len_b0: u8,
+        cksum: Checksum,
+    },
+
+    /// Store rx bytes in buffer until `cursor == len`.
+    Message {
+        class: u8,
+        id: u8,
+        len: usize,
+        cksum: Checksum,
+    },
+
+    /// Go to initial state if received byte doesnt match first byte
+    /// of running checksum.
+    CkA {
+        class: u8,
+        id: u8,
+        cksum_calc: (u8, u8),
+    },
+
+    /// Go to initial state if received byte doesn't match second byte
+    /// of running checksum.
+    CkB {
+        class: u8,
+        id: u8,
+        cksum_calc: (u8, u8),
+    },
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FrameRef, StaticDeframer, StaticDeframer128};
+    use crate::framing::FrameError;
+
+    #[test]
+    fn test_deframe() {
+        let msg = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x26];
+        let mut deframer = StaticDeframer128::new();
+        for &b in &msg[..msg.len() - 1] {
+            assert_eq!(deframer.push(b), Ok(None));
+        }
+        assert_eq!(
+            deframer.push(msg[msg.len() - 1]),
+            Ok(Some(FrameRef {
+                class: 0x05,
+                id: 0x01,
+                message: &[0x06],
+            }))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let oversize = [0xb5, 0x62, 0x05, 0x01, 0x05, 0x00];
+        let bad = [0xb5, 0x62, 0x05, 0x01, 0x01, 0x00, 0x06, 0x0d, 0x27];
+        let empty = [0xb5, 0x62, 0x0a, 0x04, 0x00, 0x00, 0x0e, 0x34];
+        let mut deframer = StaticDeframer::<4>::new();
+        let mut results = oversize
+            .iter()
+            .chain(bad.iter())
+            .chain(empty.iter())
+            .map(|&b| {
+                deframer
+                    .push(b)
+                    .map(|f| f.map(|f| (f.class, f.message.len())))
+            })
+            .filter(|res| res != &Ok(None));
+        assert_eq!(
+            results.next(),
+            Some(Err(FrameError::Size {
+                declared: 5,
+                capacity: 4
+            }))
+        );
+        assert_eq!(results.next(), Some(Err(FrameError::Checksum)));
+        assert_eq!(results.next(), Some(Ok(Some((0x0a, 0)))));
+        assert_eq!(results.next(), None);
+    }
+}
@@ -0,0 +1,156 @@
+//! Asynchronous frame stream over a Tokio byte source.
+
+use crate::framing::{Deframer, Frame};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use std::io;
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Size of the internal read buffer.
+const READ_BUF_LEN: usize = 256;
+
+/// A [`Stream`] of frames deframed from an [`AsyncRead`] byte source,
+/// e.g. a TCP connection or an async serial port.
+///
+/// The stream ends once `inner` reaches EOF and every complete frame
+/// read has been yielded. Read errors are yielded as items, and the
+/// stream may be polled again afterward.
+///
+/// # Backpressure
+///
+/// `inner` is only read while the stream is being polled, and only
+/// once all previously read bytes have been deframed. At most 256
+/// bytes are buffered, so a slow consumer slows reading from `inner`
+/// rather than growing a queue.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use tokio::net::TcpStream;
+/// use ublox::{framing::FrameStream, messages::Msg};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() -> std::io::Result<()> {
+/// let conn = TcpStream::connect("127.0.0.1:2947").await?;
+/// let mut frames = FrameStream::new(conn);
+/// while let Some(frame) = frames.next().await {
+///     println!("{:?}", Msg::from_frame(&frame?));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct FrameStream<R> {
+    inner: R,
+    deframer: Deframer,
+    buf: [u8; READ_BUF_LEN],
+    pos: usize,
+    len: usize,
+}
+
+impl<R: AsyncRead + Unpin> FrameStream<R> {
+    /// Returns a new `FrameStream` reading from `inner`.
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            deframer: Deframer::new(),
+            buf: [0; READ_BUF_LEN],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns a reference to the deframer, e.g. to read its
+    /// diagnostic counters.
+    pub fn deframer(&self) -> &Deframer {
+        &self.deframer
+    }
+
+    /// Consumes `self`, returning the underlying byte source.
+    ///
+    /// Any bytes read but not yet deframed are lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for FrameStream<R> {
+    type Item = io::Result<Frame>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            while this.pos < this.len {
+                let b = this.buf[this.pos];
+                this.pos += 1;
+                if let Some(frame) = this.deframer.push(b) {
+                    return Poll::Ready(Some(Ok(frame)));
+                }
+            }
+
+            let mut buf = ReadBuf::new(&mut this.buf);
+            match Pin::new(&mut this.inner).poll_read(cx, &mut buf) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(Ok(())) => {
+                    let len = buf.filled().len();
+                    if len == 0 {
+                        // Frames may still be queued for rescanning.
+                        return Poll::Ready(this.deframer.poll().map(Ok));
+                    }
+                    this.pos = 0;
+                    this.len = len;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::{executor::block_on, StreamExt};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_frame_stream() {
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let bytes: Vec<u8> = [0x00, 0x24]
+            .iter()
+            .chain(ack.iter())
+            .chain(ack.iter())
+            .copied()
+            .collect();
+        let frames: Vec<Frame> = block_on(FrameStream::new(bytes.as_slice()).collect::<Vec<_>>())
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].class, frames[0].id), (0x05, 0x01));
+        assert_eq!(frames[0].message, [0x06, 0x01]);
+    }
+
+    #[test]
+    fn test_frame_stream_drains_at_eof() {
+        // A false syncword declaring a 24 byte payload which swallows
+        // two ACKs, followed by a bad checksum as the final byte.
+        let ack = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let bytes: Vec<u8> = [0xb5, 0x62, 0x42, 0x42, 0x18, 0x00]
+            .iter()
+            .chain(ack.iter())
+            .chain(ack.iter())
+            .chain([0x00; 5].iter())
+            .copied()
+            .collect();
+        let frames: Vec<Frame> = block_on(FrameStream::new(bytes.as_slice()).collect::<Vec<_>>())
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0], frames[1]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Blocking request/response helpers over a byte transport.
+
+use crate::framing::{frame, frame_var, Deframer, Frame, FrameRef};
+use crate::messages::{ack::AckNak, parse_var, Message, VarMessage};
+use std::{
+    io::{self, ErrorKind, Read, Write},
+    time::{Duration, Instant},
+    vec,
+};
+
+/// Owns a `Read + Write` transport (e.g. a serial port) and provides
+/// blocking helpers for exchanging messages with a receiver.
+///
+/// Reads returning [`ErrorKind::TimedOut`], [`ErrorKind::WouldBlock`],
+/// or [`ErrorKind::Interrupted`] are treated as "no data yet", so
+/// transports configured with a short read timeout work as expected.
+#[derive(Debug)]
+pub struct Transport<T> {
+    inner: T,
+    deframer: Deframer,
+}
+
+impl<T: Read + Write> Transport<T> {
+    /// Returns a new `Transport` wrapping `inner`.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            deframer: Deframer::new(),
+        }
+    }
+
+    /// Returns a reference to the underlying transport.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying transport.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consumes `self`, returning the underlying transport.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Frames and writes `msg` to the transport.
+    pub fn send<M: Message>(&mut self, msg: &M) -> io::Result<()> {
+        let mut buf = vec![0; M::LEN + 8];
+        let len = frame(msg, &mut buf)
+            .map_err(|()| io::Error::new(ErrorKind::InvalidInput, "failed to frame message"))?;
+        self.inner.write_all(&buf[..len])?;
+        self.inner.flush()
+    }
+
+    /// Frames and writes variable-length `msg` to the transport.
+    pub fn send_var<M: VarMessage>(&mut self, msg: &M) -> io::Result<()> {
+        let mut buf = vec![0; msg.payload_len() + 8];
+        let len = frame_var(msg, &mut buf)
+            .map_err(|()| io::Error::new(ErrorKind::InvalidInput, "failed to frame message"))?;
+        self.inner.write_all(&buf[..len])?;
+        self.inner.flush()
+    }
+
+    /// Returns the next frame received before `deadline`, or `None`
+    /// if `deadline` passes first.
+    pub fn recv_frame(&mut self, deadline: Instant) -> io::Result<Option<Frame>> {
+        // Frames left queued for rescanning by the previous call are
+        // complete, so return them without waiting for more input.
+        if let Some(frame) = self.deframer.poll() {
+            return Ok(Some(frame));
+        }
+        let mut byte = [0_u8];
+        while Instant::now() < deadline {
+            match self.inner.read(&mut byte) {
+                Ok(0) => return Err(ErrorKind::UnexpectedEof.into()),
+                Ok(_) => {
+                    if let Some(frame) = self.deframer.push(byte[0]) {
+                        return Ok(Some(frame));
+                    }
+                }
+                Err(ref e)
+                    if e.kind() == ErrorKind::TimedOut
+                        || e.kind() == ErrorKind::WouldBlock
+                        || e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Sends `msg`, then waits up to `timeout` for the receiver to
+    /// acknowledge or reject it.
+    ///
+    /// Returns `Ok(true)` upon receiving a matching UBX-ACK-ACK,
+    /// `Ok(false)` upon receiving a matching UBX-ACK-NAK, and an
+    /// error of kind [`ErrorKind::TimedOut`] if neither arrives in
+    /// time. Any other frames received while waiting are discarded.
+    pub fn send_and_wait_ack<M: Message>(
+        &mut self,
+        msg: &M,
+        timeout: Duration,
+    ) -> io::Result<bool> {
+        self.send(msg)?;
+        self.wait_ack(M::CLASS, M::ID, timeout)
+    }
+
+    /// Like [`send_and_wait_ack()`](Self::send_and_wait_ack), but for
+    /// variable-length messages.
+    pub fn send_and_wait_ack_var<M: VarMessage>(
+        &mut self,
+        msg: &M,
+        timeout: Duration,
+    ) -> io::Result<bool> {
+        self.send_var(msg)?;
+        self.wait_ack(M::CLASS, M::ID, timeout)
+    }
+
+    fn wait_ack(&mut self, class: u8, id: u8, timeout: Duration) -> io::Result<bool> {
+        let deadline = Instant::now() + timeout;
+        while let Some(frame) = self.recv_frame(deadline)? {
+            match AckNak::from_frame(&frame) {
+                Ok(AckNak::Ack(ack)) if (ack.class, ack.id) == (class, id) => return Ok(true),
+                Ok(AckNak::Nak(nak)) if (nak.class, nak.id) == (class, id) => return Ok(false),
+                _ => trace!("discarding frame while waiting for ack: {:?}", frame),
+            }
+        }
+        Err(io::Error::new(
+            ErrorKind::TimedOut,
+            "timed out waiting for ack",
+        ))
+    }
+
+    /// Polls the receiver for message `M` by sending an empty `M`
+    /// frame, then waits up to `timeout` for the response.
+    ///
+    /// Returns an error of kind [`ErrorKind::TimedOut`] if no
+    /// response arrives in time, of kind [`ErrorKind::PermissionDenied`]
+    /// if the receiver rejects the poll with UBX-ACK-NAK, or of kind
+    /// [`ErrorKind::InvalidData`] if the response cannot be parsed.
+    /// Any other frames received while waiting are discarded.
+    pub fn poll_response<M: VarMessage>(&mut self, timeout: Duration) -> io::Result<M> {
+        let poll = Frame {
+            class: M::CLASS,
+            id: M::ID,
+            message: vec![],
+        };
+        self.inner.write_all(&poll.into_framed_vec())?;
+        self.inner.flush()?;
+        let deadline = Instant::now() + timeout;
+        while let Some(frame) = self.recv_frame(deadline)? {
+            if (frame.class, frame.id) == (M::CLASS, M::ID) && !frame.message.is_empty() {
+                return parse_var(FrameRef::from(&frame))
+                    .map_err(|e| io::Error::new(ErrorKind::InvalidData, e));
+            }
+            if let Ok(AckNak::Nak(nak)) = AckNak::from_frame(&frame) {
+                if (nak.class, nak.id) == (M::CLASS, M::ID) {
+                    return Err(io::Error::new(
+                        ErrorKind::PermissionDenied,
+                        "poll rejected by receiver",
+                    ));
+                }
+            }
+            trace!("discarding frame while waiting for response: {:?}", frame);
+        }
+        Err(io::Error::new(
+            ErrorKind::TimedOut,
+            "timed out waiting for response",
+        ))
+    }
+}
@@ -0,0 +1,195 @@
+//! Streaming frame output.
+
+use crate::framing::Checksum;
+use crate::messages::{Message, VarMessage};
+use bytes::BufMut;
+use core::mem::MaybeUninit;
+use std::io::{self, ErrorKind, Write};
+
+/// Size of the staging buffer used while streaming a message body.
+const CHUNK_LEN: usize = 32;
+
+/// Writes framed messages to an underlying [`Write`]r.
+///
+/// Unlike [`frame()`](super::frame), `FramedWriter` does not require
+/// a buffer large enough for the whole frame. The message body is
+/// streamed through a small fixed-size staging buffer while the
+/// checksum is computed incrementally, which keeps memory use
+/// constant for large messages such as assistance data uploads.
+#[derive(Debug)]
+pub struct FramedWriter<W> {
+    inner: W,
+}
+
+impl<W: Write> FramedWriter<W> {
+    /// Returns a new `FramedWriter` wrapping `inner`.
+    pub fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Returns a reference to the underlying writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes `self`, returning the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Frames and writes `msg`.
+    ///
+    /// If `msg` fails to serialize, a partial frame may already have
+    /// been written.
+    pub fn write_message<M: Message>(&mut self, msg: &M) -> io::Result<()> {
+        self.write_with(M::CLASS, M::ID, M::LEN, |body| msg.serialize(body))
+    }
+
+    /// Frames and writes variable-length `msg`.
+    ///
+    /// If `msg` fails to serialize, a partial frame may already have
+    /// been written.
+    pub fn write_var_message<M: VarMessage>(&mut self, msg: &M) -> io::Result<()> {
+        self.write_with(M::CLASS, M::ID, msg.payload_len(), |body| {
+            msg.serialize(body)
+        })
+    }
+
+    fn write_with<F>(&mut self, class: u8, id: u8, len: usize, serialize: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut BodyWriter<W>) -> Result<(), ()>,
+    {
+        if len > usize::from(u16::MAX) {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "message too long to frame",
+            ));
+        }
+        let [len_lsb, len_msb] = (len as u16).to_le_bytes();
+        let prelude = [0xB5, 0x62, class, id, len_lsb, len_msb];
+        self.inner.write_all(&prelude)?;
+
+        let mut cksum = Checksum::default();
+        for &b in &prelude[2..] {
+            cksum.push(b);
+        }
+        let mut body = BodyWriter {
+            inner: &mut self.inner,
+            cksum,
+            chunk: [0; CHUNK_LEN],
+            pos: 0,
+            remaining: len,
+            error: None,
+        };
+        let serialized = serialize(&mut body);
+        body.flush_chunk();
+        if let Some(e) = body.error {
+            return Err(e);
+        }
+        if serialized.is_err() || body.remaining != 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "failed to serialize message",
+            ));
+        }
+
+        let (ck_a, ck_b) = body.cksum.take();
+        self.inner.write_all(&[ck_a, ck_b])
+    }
+}
+
+/// A [`BufMut`] which stages a message body in a small buffer,
+/// updating a running checksum as each chunk is written out.
+///
+/// I/O errors can't be returned through `BufMut`, so the first one is
+/// stored and all further output is discarded.
+struct BodyWriter<'a, W> {
+    inner: &'a mut W,
+    cksum: Checksum,
+    chunk: [u8; CHUNK_LEN],
+    pos: usize,
+    remaining: usize,
+    error: Option<io::Error>,
+}
+
+impl<'a, W: Write> BodyWriter<'a, W> {
+    fn flush_chunk(&mut self) {
+        let chunk = &self.chunk[..self.pos];
+        self.pos = 0;
+        for &b in chunk {
+            self.cksum.push(b);
+        }
+        if self.error.is_none() {
+            if let Err(e) = self.inner.write_all(chunk) {
+                self.error = Some(e);
+            }
+        }
+    }
+}
+
+impl<'a, W: Write> BufMut for BodyWriter<'a, W> {
+    fn remaining_mut(&self) -> usize {
+        self.remaining
+    }
+
+    unsafe fn advance_mut(&mut self, cnt: usize) {
+        assert!(cnt <= self.remaining && self.pos + cnt <= CHUNK_LEN);
+        self.pos += cnt;
+        self.remaining -= cnt;
+        if self.pos == CHUNK_LEN {
+            self.flush_chunk();
+        }
+    }
+
+    fn bytes_mut(&mut self) -> &mut [MaybeUninit<u8>] {
+        let end = usize::min(CHUNK_LEN, self.pos + self.remaining);
+        let free = &mut self.chunk[self.pos..end];
+        // Safety: `MaybeUninit<u8>` has the same layout as `u8`, and
+        // `free` is already initialized.
+        unsafe { &mut *(free as *mut [u8] as *mut [MaybeUninit<u8>]) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::frame;
+    use crate::messages::{cfg::SetMsgRates, nav::Pvt};
+    use std::vec::Vec;
+
+    #[test]
+    fn test_matches_frame() {
+        let msg = SetMsgRates {
+            class: 0x01,
+            id: 0x07,
+            ddc: 0,
+            uart1: 1,
+            uart2: 0,
+            usb: 0,
+            spi: 0,
+        };
+        let mut expected = [0_u8; SetMsgRates::LEN + 8];
+        frame(&msg, &mut expected).unwrap();
+
+        let mut writer = FramedWriter::new(Vec::new());
+        writer.write_message(&msg).unwrap();
+        assert_eq!(writer.into_inner(), expected);
+    }
+
+    #[test]
+    fn test_multiple_chunks() {
+        let payload: Vec<u8> = (0..Pvt::LEN as u8).collect();
+        let pvt = Pvt::deserialize(&mut payload.as_slice()).unwrap();
+        let mut expected = [0_u8; Pvt::LEN + 8];
+        frame(&pvt, &mut expected).unwrap();
+
+        let mut writer = FramedWriter::new(Vec::new());
+        writer.write_message(&pvt).unwrap();
+        assert_eq!(writer.get_ref().as_slice(), &expected[..]);
+    }
+}
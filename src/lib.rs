@@ -1,11 +1,22 @@
 #![allow(non_snake_case)]
 #![recursion_limit = "128"]
 #![deny(missing_docs)]
+#![allow(clippy::result_unit_err)]
 #![no_std]
 
 //! A collection of types and parsers for u-blox v8 messages.
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+#[macro_use]
+mod macros;
+
+#[cfg(feature = "embedded-hal")]
+pub mod device;
 pub mod framing;
 pub mod messages;
+#[cfg(feature = "nmea")]
+pub mod nmea;
+pub mod prelude;
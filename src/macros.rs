@@ -0,0 +1,34 @@
+//! Diagnostic logging macros.
+//!
+//! With the `log` feature enabled these forward to the `log` crate.
+//! Otherwise they expand to nothing, but still type-check their
+//! arguments so that builds with and without the feature warn about
+//! the same things.
+
+#[cfg(feature = "log")]
+macro_rules! trace {
+    ($($arg:tt)*) => { ::log::trace!($($arg)*) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
+
+#[cfg(feature = "log")]
+macro_rules! warn {
+    ($($arg:tt)*) => { ::log::warn!($($arg)*) };
+}
+
+#[cfg(not(feature = "log"))]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        if false {
+            let _ = format_args!($($arg)*);
+        }
+    };
+}
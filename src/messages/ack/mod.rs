@@ -4,8 +4,8 @@
 //! Messages in the UBX-ACK class output the
 //! processing results to UBX-CFG and some other messages.
 
-use crate::framing::Frame;
-use crate::messages::Message;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec, parse, Message, ParseError};
 
 /// Ack/Nak.
 #[allow(missing_docs)]
@@ -19,20 +19,62 @@ impl AckNak {
     /// ACK class.
     pub const CLASS: u8 = 0x05;
 
-    /// Parses a Ack/Nak message from a [`Frame`].
-    pub fn from_frame(frame: &Frame) -> Result<Self, ()> {
+    /// Parses a Ack/Nak message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    ///
+    /// A truncated payload, or the empty payload some firmware sends
+    /// in place of an acknowledgement to certain polls, results in
+    /// [`ParseError::UnexpectedLength`], as neither identifies the
+    /// message being acknowledged.
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
         if frame.class != Self::CLASS {
-            return Err(());
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
         };
 
-        match (frame.class, frame.id, frame.message.len()) {
-            (Ack::CLASS, Ack::ID, Ack::LEN) => {
-                Ok(AckNak::Ack(Ack::deserialize(&mut frame.message.as_ref())?))
-            }
-            (Nak::CLASS, Nak::ID, Nak::LEN) => {
-                Ok(AckNak::Nak(Nak::deserialize(&mut frame.message.as_ref())?))
-            }
-            _ => Err(()),
+        match frame.id {
+            Ack::ID => parse(frame).map(AckNak::Ack),
+            Nak::ID => parse(frame).map(AckNak::Nak),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Returns the class of the acknowledged or rejected message.
+    pub fn class(&self) -> u8 {
+        match self {
+            AckNak::Ack(ack) => ack.class,
+            AckNak::Nak(nak) => nak.class,
+        }
+    }
+
+    /// Returns the ID of the acknowledged or rejected message.
+    pub fn id(&self) -> u8 {
+        match self {
+            AckNak::Ack(ack) => ack.id,
+            AckNak::Nak(nak) => nak.id,
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            AckNak::Ack(msg) => framed_vec(msg),
+            AckNak::Nak(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            AckNak::Ack(msg) => msg.class_id(),
+            AckNak::Nak(msg) => msg.class_id(),
         }
     }
 }
@@ -48,6 +90,21 @@ pub struct Ack {
     pub id: u8,
 }
 
+impl Ack {
+    /// Returns `true` if `self` acknowledges a message of type `M`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ublox::messages::{ack::Ack, cfg::SetMsgRates};
+    /// let ack = Ack { class: 0x06, id: 0x01 };
+    /// assert!(ack.acknowledges::<SetMsgRates>());
+    /// ```
+    pub fn acknowledges<M: Message>(&self) -> bool {
+        self.class == M::CLASS && self.id == M::ID
+    }
+}
+
 impl Message for Ack {
     const CLASS: u8 = 0x05;
     const ID: u8 = 0x01;
@@ -81,8 +138,17 @@ impl Message for Ack {
 /// A UBX-ACK-NAK is sent as soon as possible but at least within one second.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Nak {
-    class: u8,
-    id: u8,
+    /// Rejected message's class.
+    pub class: u8,
+    /// Rejected message's ID.
+    pub id: u8,
+}
+
+impl Nak {
+    /// Returns `true` if `self` rejects a message of type `M`.
+    pub fn rejects<M: Message>(&self) -> bool {
+        self.class == M::CLASS && self.id == M::ID
+    }
 }
 
 impl Message for Nak {
@@ -112,3 +178,77 @@ impl Message for Nak {
         Ok(Self { class, id })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::Frame;
+    use crate::messages::{cfg::SetMsgRates, nav::Pvt};
+
+    #[test]
+    fn test_ack_matching() {
+        let ack = Ack {
+            class: SetMsgRates::CLASS,
+            id: SetMsgRates::ID,
+        };
+        assert!(ack.acknowledges::<SetMsgRates>());
+        assert!(!ack.acknowledges::<Pvt>());
+
+        let nak = Nak {
+            class: SetMsgRates::CLASS,
+            id: SetMsgRates::ID,
+        };
+        assert!(nak.rejects::<SetMsgRates>());
+        assert!(!nak.rejects::<Pvt>());
+
+        let acknak = AckNak::Nak(nak);
+        assert_eq!(
+            (acknak.class(), acknak.id()),
+            (SetMsgRates::CLASS, SetMsgRates::ID)
+        );
+    }
+
+    #[test]
+    fn test_malformed_acks() {
+        for len in 0..Ack::LEN {
+            let frame = Frame {
+                class: 0x05,
+                id: 0x01,
+                message: [0x06, 0x01][..len].to_vec(),
+            };
+            assert_eq!(
+                AckNak::from_frame(&frame),
+                Err(ParseError::UnexpectedLength {
+                    class: 0x05,
+                    id: 0x01,
+                    len
+                })
+            );
+        }
+
+        // An empty ACK survives deframing and is reported as such.
+        let bytes = [0xb5, 0x62, 0x05, 0x01, 0x00, 0x00, 0x06, 0x17];
+        let frame = crate::framing::deframe(bytes.iter().copied()).unwrap();
+        assert_eq!(
+            AckNak::from_frame(&frame),
+            Err(ParseError::UnexpectedLength {
+                class: 0x05,
+                id: 0x01,
+                len: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_nak_fields() {
+        let frame = Frame {
+            class: 0x05,
+            id: 0x00,
+            message: [0x06, 0x01].to_vec(),
+        };
+        match AckNak::from_frame(&frame).unwrap() {
+            AckNak::Nak(nak) => assert_eq!((nak.class, nak.id), (0x06, 0x01)),
+            other => panic!("expected NAK, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,100 @@
+//! Antenna control configuration messages.
+
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+
+/// Antenna control settings.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgAnt {
+    /// Antenna flag mask.
+    pub flags: Flags,
+    /// Antenna pin configuration.
+    pub pins: Pins,
+}
+
+impl Message for CfgAnt {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x13;
+    const LEN: usize = 4;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        let &Self { flags, pins } = self;
+
+        dst.put_u16_le(flags.0);
+        dst.put_u16_le(pins.0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let flags = Flags(src.get_u16_le());
+        let pins = Pins(src.get_u16_le());
+
+        Ok(Self { flags, pins })
+    }
+}
+
+bitfield! {
+    /// Antenna flag mask.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags(X2);
+    impl Debug;
+    /// Enable automatic recovery from short state
+    pub recovery, set_recovery: 4;
+    /// Power down antenna supply if short condition is detected.
+    /// (only if short circuit detection is enabled)
+    pub pdwn_on_scd, set_pdwn_on_scd: 3;
+    /// Enable open circuit detection
+    pub ocd, set_ocd: 2;
+    /// Enable short circuit detection
+    pub scd, set_scd: 1;
+    /// Enable antenna supply voltage control signal
+    pub svcs, set_svcs: 0;
+}
+
+bitfield! {
+    /// Antenna pin configuration.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Pins(X2);
+    impl Debug;
+    u8;
+    /// If set to one, and this command is sent to the receiver, the
+    /// receiver will reconfigure the pins as specified.
+    pub reconfig, set_reconfig: 15;
+    /// PIO-pin used for detecting open/not connected antenna
+    pub pin_ocd, set_pin_ocd: 14, 10;
+    /// PIO-pin used for detecting a short in the antenna supply
+    pub pin_scd, set_pin_scd: 9, 5;
+    /// PIO-pin used for switching antenna supply
+    pub pin_switch, set_pin_switch: 4, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_encode() {
+        let msg = CfgAnt {
+            flags: {
+                let mut flags = Flags(0);
+                flags.set_svcs(true);
+                flags.set_scd(true);
+                flags
+            },
+            pins: Pins(0),
+        };
+        let mut buf = [0_u8; CfgAnt::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x03, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, CfgAnt::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
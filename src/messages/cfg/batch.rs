@@ -0,0 +1,115 @@
+//! Batched output configuration messages.
+
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+
+/// Get/set data batching configuration.
+///
+/// When batching is enabled the receiver buffers navigation solutions,
+/// which can later be retrieved with UBX-LOG-RETRIEVEBATCH.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgBatch {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+    /// Flags.
+    pub flags: Flags,
+    /// Size of buffer in number of epochs to store.
+    pub buf_size: U2,
+    /// Buffer fill level that triggers PIO notification, in number of
+    /// epochs stored.
+    pub notif_thrs: U2,
+    /// PIO ID to use for buffer level notification.
+    pub pio_id: U1,
+}
+
+impl CfgBatch {
+    /// Current message version.
+    pub const VERSION: U1 = 0x00;
+}
+
+impl Message for CfgBatch {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x93;
+    const LEN: usize = 8;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.version);
+        dst.put_u8(self.flags.0);
+        dst.put_u16_le(self.buf_size);
+        dst.put_u16_le(self.notif_thrs);
+        dst.put_u8(self.pio_id);
+        // reserved1
+        dst.put_u8(0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        let flags = Flags(src.get_u8());
+        let buf_size = src.get_u16_le();
+        let notif_thrs = src.get_u16_le();
+        let pio_id = src.get_u8();
+        // reserved1
+        src.advance(1);
+
+        Ok(Self {
+            version,
+            flags,
+            buf_size,
+            notif_thrs,
+            pio_id,
+        })
+    }
+}
+
+bitfield! {
+    /// Batching flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags(X1);
+    impl Debug;
+    /// Drive the notification PIO low when the buffer fill level
+    /// reaches `notif_thrs`, otherwise drive it high
+    pub pio_active_low, set_pio_active_low: 6;
+    /// Enable PIO notification of the buffer fill level
+    pub pio_enable, set_pio_enable: 5;
+    /// Store odometer data in the batching buffer
+    pub extra_odo, set_extra_odo: 3;
+    /// Store additional PVT information in the batching buffer
+    pub extra_pvt, set_extra_pvt: 2;
+    /// Enable data batching
+    pub enable, set_enable: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_batching() {
+        let msg = CfgBatch {
+            version: CfgBatch::VERSION,
+            flags: {
+                let mut flags = Flags(0);
+                flags.set_enable(true);
+                flags.set_extra_pvt(true);
+                flags
+            },
+            buf_size: 128,
+            notif_thrs: 0,
+            pio_id: 0,
+        };
+        let mut buf = [0_u8; CfgBatch::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x00, 0x05, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, CfgBatch::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
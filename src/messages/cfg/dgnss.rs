@@ -0,0 +1,97 @@
+//! Differential GNSS configuration messages.
+
+use crate::messages::Message;
+
+/// DGNSS configuration.
+///
+/// Selects how the receiver resolves carrier phase ambiguities when
+/// computing an RTK solution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgDgnss {
+    /// Differential GNSS mode.
+    pub dgnss_mode: DgnssMode,
+}
+
+/// Differential GNSS mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DgnssMode {
+    /// RTK float: no attempts are made to fix ambiguities.
+    RtkFloat,
+    /// RTK fixed: ambiguities are fixed whenever possible.
+    RtkFixed,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for DgnssMode {
+    fn from(raw: u8) -> Self {
+        match raw {
+            2 => DgnssMode::RtkFloat,
+            3 => DgnssMode::RtkFixed,
+            other => DgnssMode::Unknown(other),
+        }
+    }
+}
+
+impl From<DgnssMode> for u8 {
+    fn from(mode: DgnssMode) -> Self {
+        match mode {
+            DgnssMode::RtkFloat => 2,
+            DgnssMode::RtkFixed => 3,
+            DgnssMode::Unknown(other) => other,
+        }
+    }
+}
+
+impl Message for CfgDgnss {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x70;
+    const LEN: usize = 4;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.dgnss_mode.into());
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let dgnss_mode = DgnssMode::from(src.get_u8());
+        // reserved1
+        src.advance(3);
+
+        Ok(Self { dgnss_mode })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dgnss_mode() {
+        let msg = CfgDgnss {
+            dgnss_mode: DgnssMode::RtkFixed,
+        };
+        let mut buf = [0xff_u8; CfgDgnss::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x03, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, CfgDgnss::deserialize(&mut buf.as_ref()).unwrap());
+
+        let bytes = [0x02, 0x00, 0x00, 0x00];
+        let msg = CfgDgnss::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.dgnss_mode, DgnssMode::RtkFloat);
+        for raw in 0..=u8::MAX {
+            assert_eq!(u8::from(DgnssMode::from(raw)), raw);
+        }
+    }
+}
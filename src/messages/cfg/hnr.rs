@@ -0,0 +1,57 @@
+//! High navigation rate configuration messages.
+
+use crate::messages::{primitive::*, Message};
+
+/// High navigation rate settings.
+///
+/// Sets the rate of the HNR class messages output by ADR and UDR
+/// receivers, independently of the rate of the GNSS solution.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgHnr {
+    /// Rate of navigation solution output, in Hz.
+    pub high_nav_rate: U1,
+}
+
+impl Message for CfgHnr {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x5C;
+    const LEN: usize = 4;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.high_nav_rate);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let high_nav_rate = src.get_u8();
+        // reserved1
+        src.advance(3);
+
+        Ok(Self { high_nav_rate })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_high_nav_rate() {
+        let msg = CfgHnr { high_nav_rate: 30 };
+        let mut buf = [0xff_u8; CfgHnr::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x1e, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, CfgHnr::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
@@ -0,0 +1,185 @@
+//! Information message configuration messages.
+
+use crate::messages::{primitive::*, VarMessage};
+use bitfield::bitfield;
+
+/// Information message configuration.
+///
+/// Selects which UBX-INF (or NMEA TXT) severities are output on each
+/// port for a given protocol.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CfgInf {
+    /// Poll the information message configuration of `protocol_id`.
+    Poll {
+        /// Protocol identifier, see [`CfgInf::PROTOCOL_UBX`] and
+        /// [`CfgInf::PROTOCOL_NMEA`].
+        protocol_id: U1,
+    },
+    /// Get/set the information message configuration of `protocol_id`.
+    Set {
+        /// Protocol identifier, see [`CfgInf::PROTOCOL_UBX`] and
+        /// [`CfgInf::PROTOCOL_NMEA`].
+        protocol_id: U1,
+        /// Information message enable mask, one per port.
+        ///
+        /// Indexed by port ID, i.e. 0 = I2C (DDC), 1 = UART1, 2 = UART2,
+        /// 3 = USB, 4 = SPI, 5 = reserved.
+        inf_msg_mask: [InfMsgMask; 6],
+    },
+}
+
+/// Severity of an information message.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Notice,
+    Test,
+    Debug,
+}
+
+impl CfgInf {
+    /// UBX protocol identifier.
+    pub const PROTOCOL_UBX: U1 = 0;
+    /// NMEA protocol identifier.
+    pub const PROTOCOL_NMEA: U1 = 1;
+
+    const LEN_POLL: usize = 1;
+    const LEN_SET: usize = 10;
+
+    /// Returns a `Set` configuration for `protocol_id` with all
+    /// severities disabled on all ports.
+    pub fn disabled(protocol_id: U1) -> Self {
+        CfgInf::Set {
+            protocol_id,
+            inf_msg_mask: [InfMsgMask(0); 6],
+        }
+    }
+
+    /// Enables output of `severity` messages on `port`.
+    ///
+    /// Returns an error if `self` is a `Poll` or `port` is out of
+    /// range.
+    pub fn enable(&mut self, port: u8, severity: Severity) -> Result<(), ()> {
+        match self {
+            CfgInf::Poll { .. } => Err(()),
+            CfgInf::Set { inf_msg_mask, .. } => {
+                let mask = inf_msg_mask.get_mut(usize::from(port)).ok_or(())?;
+                match severity {
+                    Severity::Error => mask.set_error(true),
+                    Severity::Warning => mask.set_warning(true),
+                    Severity::Notice => mask.set_notice(true),
+                    Severity::Test => mask.set_test(true),
+                    Severity::Debug => mask.set_debug(true),
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl VarMessage for CfgInf {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x02;
+
+    fn payload_len(&self) -> usize {
+        match self {
+            CfgInf::Poll { .. } => Self::LEN_POLL,
+            CfgInf::Set { .. } => Self::LEN_SET,
+        }
+    }
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        };
+
+        match self {
+            CfgInf::Poll { protocol_id } => dst.put_u8(*protocol_id),
+            CfgInf::Set {
+                protocol_id,
+                inf_msg_mask,
+            } => {
+                dst.put_u8(*protocol_id);
+                // reserved1
+                dst.put_slice([0_u8; 3].as_ref());
+                for mask in inf_msg_mask {
+                    dst.put_u8(mask.0);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        match src.remaining() {
+            Self::LEN_POLL => Ok(CfgInf::Poll {
+                protocol_id: src.get_u8(),
+            }),
+            Self::LEN_SET => {
+                let protocol_id = src.get_u8();
+                // reserved1
+                src.advance(3);
+                let mut inf_msg_mask = [InfMsgMask(0); 6];
+                for mask in inf_msg_mask.iter_mut() {
+                    *mask = InfMsgMask(src.get_u8());
+                }
+                Ok(CfgInf::Set {
+                    protocol_id,
+                    inf_msg_mask,
+                })
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+bitfield! {
+    /// Information message enable mask for a single port.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct InfMsgMask(X1);
+    impl Debug;
+    /// Enable DEBUG
+    pub debug, set_debug: 4;
+    /// Enable TEST
+    pub test, set_test: 3;
+    /// Enable NOTICE
+    pub notice, set_notice: 2;
+    /// Enable WARNING
+    pub warning, set_warning: 1;
+    /// Enable ERROR
+    pub error, set_error: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_encode_set() {
+        let mut msg = CfgInf::disabled(CfgInf::PROTOCOL_UBX);
+        msg.enable(1, Severity::Debug).unwrap();
+        msg.enable(1, Severity::Error).unwrap();
+        assert!(msg.enable(6, Severity::Error).is_err());
+
+        let mut buf = [0_u8; 10];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(
+            buf,
+            [0x00, 0x00, 0x00, 0x00, 0x00, 0x11, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(msg, CfgInf::deserialize(&mut buf.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_can_parse_poll() {
+        assert_eq!(
+            CfgInf::deserialize(&mut [0x01_u8].as_ref()).unwrap(),
+            CfgInf::Poll {
+                protocol_id: CfgInf::PROTOCOL_NMEA
+            }
+        );
+    }
+}
@@ -0,0 +1,122 @@
+//! Data logger configuration messages.
+
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+
+/// Data logger configuration.
+///
+/// Controls which navigation solutions are recorded to the data log.
+/// With all thresholds set to zero, every position fix is recorded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgLogFilter {
+    /// Message version (0x01 for this version).
+    pub version: U1,
+    /// Flags.
+    pub flags: Flags,
+    /// Minimum time interval between logged positions in seconds, 0
+    /// = not set. This is only applied in combination with the speed
+    /// and/or position thresholds.
+    pub min_interval: U2,
+    /// If the time difference is greater than the threshold in
+    /// seconds then the position is logged, 0 = not set.
+    pub time_threshold: U2,
+    /// If the current speed is greater than the threshold in m/s then
+    /// the position is logged, 0 = not set. `min_interval` also
+    /// applies.
+    pub speed_threshold: U2,
+    /// If the 3D position difference is greater than the threshold in
+    /// meters then the position is logged, 0 = not set.
+    /// `min_interval` also applies.
+    pub position_threshold: U4,
+}
+
+impl CfgLogFilter {
+    /// Current message version.
+    pub const VERSION: U1 = 0x01;
+}
+
+impl Message for CfgLogFilter {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x47;
+    const LEN: usize = 12;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.version);
+        dst.put_u8(self.flags.0);
+        dst.put_u16_le(self.min_interval);
+        dst.put_u16_le(self.time_threshold);
+        dst.put_u16_le(self.speed_threshold);
+        dst.put_u32_le(self.position_threshold);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        let flags = Flags(src.get_u8());
+        let min_interval = src.get_u16_le();
+        let time_threshold = src.get_u16_le();
+        let speed_threshold = src.get_u16_le();
+        let position_threshold = src.get_u32_le();
+
+        Ok(Self {
+            version,
+            flags,
+            min_interval,
+            time_threshold,
+            speed_threshold,
+            position_threshold,
+        })
+    }
+}
+
+bitfield! {
+    /// Data logger flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags(X1);
+    impl Debug;
+    /// Apply all filter settings, otherwise only `recording_enabled`
+    pub apply_all_filter_settings, set_apply_all_filter_settings: 2;
+    /// Enable recording only one single position per PSM on/off
+    /// wake-up period
+    pub psm_once_per_wakup_enabled, set_psm_once_per_wakup_enabled: 1;
+    /// Enable recording
+    pub recording_enabled, set_recording_enabled: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_recording() {
+        let msg = CfgLogFilter {
+            version: CfgLogFilter::VERSION,
+            flags: {
+                let mut flags = Flags(0);
+                flags.set_recording_enabled(true);
+                flags.set_apply_all_filter_settings(true);
+                flags
+            },
+            min_interval: 5,
+            time_threshold: 0,
+            speed_threshold: 0,
+            position_threshold: 0,
+        };
+        let mut buf = [0_u8; CfgLogFilter::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(
+            buf,
+            [0x01, 0x05, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+        assert_eq!(msg, CfgLogFilter::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
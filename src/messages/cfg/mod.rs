@@ -6,34 +6,330 @@
 //! processed successfully or rejected (with message UBX-ACK-NAK) if
 //! processing unsuccessfully.
 
+pub mod ant;
+pub mod batch;
+pub mod dgnss;
+pub mod hnr;
+pub mod inf;
+pub mod logfilter;
 mod msg;
+pub mod navx5;
+pub mod pms;
 pub mod prt;
-use crate::framing::Frame;
-use crate::messages::Message;
-pub use msg::SetMsgRates;
+pub mod rinv;
+pub mod tmode3;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{
+    framed_vec, framed_vec_var, parse, parse_var,
+    primitive::{GnssId, U1},
+    Message, ParseError, VarMessage,
+};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+pub use msg::{SetMsgRate, SetMsgRates};
 
 /// Configuration messages.
 #[allow(missing_docs)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Cfg {
     SetMsgRates(msg::SetMsgRates),
+    SetMsgRate(msg::SetMsgRate),
+    Ant(ant::CfgAnt),
+    Inf(inf::CfgInf),
+    NavX5(navx5::NavX5),
+    Rinv(rinv::CfgRinv),
+    Prt(prt::Prt),
+    LogFilter(logfilter::CfgLogFilter),
+    Batch(batch::CfgBatch),
+    Dgnss(dgnss::CfgDgnss),
+    Hnr(hnr::CfgHnr),
+    Tmode3(tmode3::CfgTmode3),
+    Pms(pms::CfgPms),
 }
 
 impl Cfg {
     /// CFG class.
     pub const CLASS: u8 = 0x06;
 
-    /// Parses a configuration message from a [`Frame`].
-    pub fn from_frame(frame: &Frame) -> Result<Self, ()> {
+    /// Parses a configuration message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
         if frame.class != Self::CLASS {
-            return Err(());
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
         };
 
-        match (frame.class, frame.id, frame.message.len()) {
-            (msg::SetMsgRates::CLASS, msg::SetMsgRates::ID, msg::SetMsgRates::LEN) => Ok(
-                Cfg::SetMsgRates(msg::SetMsgRates::deserialize(&mut frame.message.as_ref())?),
-            ),
+        match frame.id {
+            msg::SetMsgRate::ID if frame.message.len() == msg::SetMsgRate::LEN => {
+                parse(frame).map(Cfg::SetMsgRate)
+            }
+            msg::SetMsgRates::ID => parse(frame).map(Cfg::SetMsgRates),
+            ant::CfgAnt::ID => parse(frame).map(Cfg::Ant),
+            inf::CfgInf::ID => parse_var(frame).map(Cfg::Inf),
+            navx5::NavX5::ID => parse_var(frame).map(Cfg::NavX5),
+            rinv::CfgRinv::ID => parse_var(frame).map(Cfg::Rinv),
+            prt::Prt::ID => parse_var(frame).map(Cfg::Prt),
+            logfilter::CfgLogFilter::ID => parse(frame).map(Cfg::LogFilter),
+            batch::CfgBatch::ID => parse(frame).map(Cfg::Batch),
+            dgnss::CfgDgnss::ID => parse(frame).map(Cfg::Dgnss),
+            hnr::CfgHnr::ID => parse(frame).map(Cfg::Hnr),
+            tmode3::CfgTmode3::ID => parse(frame).map(Cfg::Tmode3),
+            pms::CfgPms::ID => parse(frame).map(Cfg::Pms),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Cfg::SetMsgRates(msg) => framed_vec(msg),
+            Cfg::SetMsgRate(msg) => framed_vec(msg),
+            Cfg::Ant(msg) => framed_vec(msg),
+            Cfg::Inf(msg) => framed_vec_var(msg),
+            Cfg::NavX5(msg) => framed_vec_var(msg),
+            Cfg::Rinv(msg) => framed_vec_var(msg),
+            Cfg::Prt(msg) => framed_vec_var(msg),
+            Cfg::LogFilter(msg) => framed_vec(msg),
+            Cfg::Batch(msg) => framed_vec(msg),
+            Cfg::Dgnss(msg) => framed_vec(msg),
+            Cfg::Hnr(msg) => framed_vec(msg),
+            Cfg::Tmode3(msg) => framed_vec(msg),
+            Cfg::Pms(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Cfg::SetMsgRates(msg) => msg.class_id(),
+            Cfg::SetMsgRate(msg) => msg.class_id(),
+            Cfg::Ant(msg) => msg.class_id(),
+            Cfg::Inf(msg) => msg.class_id(),
+            Cfg::NavX5(msg) => msg.class_id(),
+            Cfg::Rinv(msg) => msg.class_id(),
+            Cfg::Prt(msg) => msg.class_id(),
+            Cfg::LogFilter(msg) => msg.class_id(),
+            Cfg::Batch(msg) => msg.class_id(),
+            Cfg::Dgnss(msg) => msg.class_id(),
+            Cfg::Hnr(msg) => msg.class_id(),
+            Cfg::Tmode3(msg) => msg.class_id(),
+            Cfg::Pms(msg) => msg.class_id(),
+        }
+    }
+}
+
+/// Receiver I/O port identifier, as used by CFG-PRT and CFG-MSG.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum PortId {
+    /// I2C (DDC) port.
+    Ddc = 0,
+    /// UART 1 port.
+    Uart1 = 1,
+    /// UART 2 port.
+    Uart2 = 2,
+    /// USB port.
+    Usb = 3,
+    /// SPI port.
+    Spi = 4,
+}
+
+impl TryFrom<U1> for PortId {
+    type Error = ();
+
+    fn try_from(raw: U1) -> Result<Self, ()> {
+        match raw {
+            0 => Ok(PortId::Ddc),
+            1 => Ok(PortId::Uart1),
+            2 => Ok(PortId::Uart2),
+            3 => Ok(PortId::Usb),
+            4 => Ok(PortId::Spi),
             _ => Err(()),
         }
     }
 }
+
+impl From<PortId> for U1 {
+    fn from(port: PortId) -> Self {
+        port as U1
+    }
+}
+
+/// Returns framed [`SetMsgRates`] commands enabling each `(class, id,
+/// rate)` in `specs` on all ports.
+///
+/// The returned frames are ready to be written to the receiver in
+/// order.
+pub fn enable_messages(specs: &[(u8, u8, u8)]) -> Result<Vec<FrameVec>, ()> {
+    specs
+        .iter()
+        .map(|&(class, id, rate)| {
+            framed_vec(&SetMsgRates {
+                class,
+                id,
+                ddc: rate,
+                uart1: rate,
+                uart2: rate,
+                usb: rate,
+                spi: rate,
+            })
+        })
+        .collect()
+}
+
+/// Returns framed [`SetMsgRates`] commands enabling each `(class, id,
+/// rate)` in `specs` on `port` only, and disabling them on all other
+/// ports.
+pub fn enable_messages_on(port: PortId, specs: &[(u8, u8, u8)]) -> Result<Vec<FrameVec>, ()> {
+    specs
+        .iter()
+        .map(|&(class, id, rate)| framed_vec(&SetMsgRates::on_port(port, class, id, rate)))
+        .collect()
+}
+
+/// Returns the highest navigation update rate, in Hz, which a u-blox
+/// M8 receiver supports with `constellations` enabled.
+///
+/// The receiver silently caps the rate configured with CFG-RATE to
+/// this limit, so use it to validate a rate before sending it. Per
+/// the M8 datasheets, the limit is 18 Hz when tracking a single major
+/// GNSS (GPS, Galileo, BeiDou, or GLONASS), and 10 Hz when tracking
+/// several concurrently. Augmentation systems (SBAS, QZSS and IMES)
+/// do not count towards the limit.
+pub fn max_nav_rate_hz(constellations: &[GnssId]) -> f32 {
+    /// `(major GNSS count, max rate)` pairs, by ascending count.
+    const LIMITS: [(usize, f32); 2] = [(1, 18.0), (usize::MAX, 10.0)];
+
+    let major = [
+        GnssId::Gps,
+        GnssId::Galileo,
+        GnssId::BeiDou,
+        GnssId::Glonass,
+    ];
+    let count = major
+        .iter()
+        .filter(|gnss| constellations.contains(gnss))
+        .count();
+    LIMITS
+        .iter()
+        .find(|&&(max_count, _)| count <= max_count)
+        .map_or(10.0, |&(_, rate)| rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enable_messages() {
+        // CFG-MSG payload: msgClass, msgID, rate[6] indexed by port ID
+        // (DDC, UART1, UART2, USB, SPI, reserved).
+        let specs = [(0x01, 0x07, 1), (0x01, 0x35, 5)];
+        let frames = enable_messages(&specs).unwrap();
+        assert_eq!(
+            frames[0].as_slice(),
+            &[
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x07, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00,
+                0x1c, 0xf0
+            ][..]
+        );
+        assert_eq!(
+            frames[1].as_slice(),
+            &[
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x35, 0x05, 0x05, 0x05, 0x05, 0x05, 0x00,
+                0x5e, 0x82
+            ][..]
+        );
+
+        let frames = enable_messages_on(PortId::Usb, &specs[..1]).unwrap();
+        assert_eq!(
+            frames[0].as_slice(),
+            &[
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x07, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00,
+                0x18, 0xdf
+            ][..]
+        );
+        let frames = enable_messages_on(PortId::Uart2, &specs[..1]).unwrap();
+        assert_eq!(
+            frames[0].as_slice(),
+            &[
+                0xb5, 0x62, 0x06, 0x01, 0x08, 0x00, 0x01, 0x07, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+                0x18, 0xe0
+            ][..]
+        );
+    }
+
+    #[test]
+    fn test_from_frame_msg() {
+        let rate = SetMsgRate {
+            class: 0x01,
+            id: 0x07,
+            rate: 1,
+        };
+        let rates = SetMsgRates {
+            class: 0x01,
+            id: 0x07,
+            ddc: 0,
+            uart1: 1,
+            uart2: 0,
+            usb: 1,
+            spi: 0,
+        };
+        for msg in [Cfg::SetMsgRate(rate), Cfg::SetMsgRates(rates)].iter() {
+            let framed = msg.to_framed_vec().unwrap();
+            let frame = crate::framing::deframe(framed).unwrap();
+            assert_eq!(&Cfg::from_frame(&frame).unwrap(), msg);
+        }
+
+        let frame = crate::framing::Frame {
+            class: 0x06,
+            id: 0x01,
+            message: alloc::vec![0x01, 0x07],
+        };
+        assert_eq!(
+            Cfg::from_frame(&frame),
+            Err(ParseError::UnexpectedLength {
+                class: 0x06,
+                id: 0x01,
+                len: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_port_id() {
+        let ports = [
+            PortId::Ddc,
+            PortId::Uart1,
+            PortId::Uart2,
+            PortId::Usb,
+            PortId::Spi,
+        ];
+        for (raw, &port) in ports.iter().enumerate() {
+            assert_eq!(PortId::try_from(raw as U1), Ok(port));
+            assert_eq!(U1::from(port), raw as U1);
+        }
+        assert_eq!(PortId::try_from(5), Err(()));
+        assert_eq!(prt::Prt::poll(PortId::Spi), prt::Prt::Poll { port_id: 4 });
+    }
+
+    #[test]
+    fn test_max_nav_rate_hz() {
+        assert_eq!(max_nav_rate_hz(&[GnssId::Gps]), 18.0);
+        assert_eq!(
+            max_nav_rate_hz(&[GnssId::Gps, GnssId::Sbas, GnssId::Qzss]),
+            18.0
+        );
+        assert_eq!(max_nav_rate_hz(&[GnssId::Gps, GnssId::Glonass]), 10.0);
+        assert_eq!(
+            max_nav_rate_hz(&[GnssId::Gps, GnssId::Galileo, GnssId::Glonass]),
+            10.0
+        );
+    }
+}
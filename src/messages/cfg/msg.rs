@@ -1,3 +1,4 @@
+use super::PortId;
 use crate::messages::{primitive::*, Message};
 
 /// Get/set message rate configuration(s) to/from the receiver.
@@ -15,12 +16,37 @@ pub struct SetMsgRates {
     pub ddc: U1,
     /// UART 1 rate.
     pub uart1: U1,
+    /// UART 2 rate.
+    pub uart2: U1,
     /// USB rate.
     pub usb: U1,
     /// SPI rate.
     pub spi: U1,
     // reserved1: U1,
-    // reserved2: U1,
+}
+
+impl SetMsgRates {
+    /// Returns a command sending message `class`/`id` at `rate` on
+    /// `port` only, and disabling it on all other ports.
+    pub fn on_port(port: PortId, class: u8, id: u8, rate: u8) -> Self {
+        let mut msg = Self {
+            class,
+            id,
+            ddc: 0,
+            uart1: 0,
+            uart2: 0,
+            usb: 0,
+            spi: 0,
+        };
+        match port {
+            PortId::Ddc => msg.ddc = rate,
+            PortId::Uart1 => msg.uart1 = rate,
+            PortId::Uart2 => msg.uart2 = rate,
+            PortId::Usb => msg.usb = rate,
+            PortId::Spi => msg.spi = rate,
+        }
+        msg
+    }
 }
 
 impl Message for SetMsgRates {
@@ -38,6 +64,7 @@ impl Message for SetMsgRates {
             id,
             ddc,
             uart1,
+            uart2,
             usb,
             spi,
         } = self;
@@ -46,12 +73,11 @@ impl Message for SetMsgRates {
         dst.put_u8(id);
         dst.put_u8(ddc);
         dst.put_u8(uart1);
+        dst.put_u8(uart2);
         dst.put_u8(usb);
         dst.put_u8(spi);
         // Reserved 1
         dst.put_u8(0);
-        // Reserved 2
-        dst.put_u8(0);
 
         Ok(())
     }
@@ -65,22 +91,67 @@ impl Message for SetMsgRates {
         let id = src.get_u8();
         let ddc = src.get_u8();
         let uart1 = src.get_u8();
+        let uart2 = src.get_u8();
         let usb = src.get_u8();
         let spi = src.get_u8();
         let _reserved1 = src.get_u8();
-        let _reserved2 = src.get_u8();
 
         Ok(Self {
             class,
             id,
             ddc,
             uart1,
+            uart2,
             usb,
             spi,
         })
     }
 }
 
+/// Set message rate configuration for the current port.
+///
+/// Compact form of [`SetMsgRates`] which only sets the rate on the
+/// port the command arrives on, leaving all other ports unchanged.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetMsgRate {
+    /// Message class of message to configure (not `Self`'s class).
+    pub class: U1,
+    /// Message identifier of message to configure (not `Self`'s identifier).
+    pub id: U1,
+    /// Send rate on current port.
+    pub rate: U1,
+}
+
+impl Message for SetMsgRate {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x01;
+    const LEN: usize = 3;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.class);
+        dst.put_u8(self.id);
+        dst.put_u8(self.rate);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let class = src.get_u8();
+        let id = src.get_u8();
+        let rate = src.get_u8();
+
+        Ok(Self { class, id, rate })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,7 +166,8 @@ mod tests {
                 id: 0x20,
                 ddc: 0x00,
                 uart1: 0x01,
-                usb: 0x01,
+                uart2: 0x01,
+                usb: 0x00,
                 spi: 0x00,
             }
         )
@@ -103,16 +175,54 @@ mod tests {
 
     #[test]
     fn test_can_encode() {
-        let bytes = [0x01_u8, 0x20, 0x00, 0x01, 0x01, 0x00, 0x00, 0x00];
         let msg = SetMsgRates {
             class: 0x01,
             id: 0x20,
             ddc: 0x00,
             uart1: 0x01,
+            uart2: 0x00,
             usb: 0x01,
             spi: 0x00,
         };
+        let bytes = [0x01_u8, 0x20, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00];
 
+        let mut buf = [0xff_u8; SetMsgRates::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
         assert_eq!(msg, SetMsgRates::deserialize(&mut &bytes[..]).unwrap());
     }
+
+    #[test]
+    fn test_on_port() {
+        // rate[6] is indexed by port ID.
+        let ports = [
+            PortId::Ddc,
+            PortId::Uart1,
+            PortId::Uart2,
+            PortId::Usb,
+            PortId::Spi,
+        ];
+        for &port in ports.iter() {
+            let mut buf = [0xff_u8; SetMsgRates::LEN];
+            SetMsgRates::on_port(port, 0x01, 0x07, 5)
+                .serialize(&mut buf.as_mut())
+                .unwrap();
+            let mut expected = [0x01, 0x07, 0, 0, 0, 0, 0, 0];
+            expected[2 + port as usize] = 5;
+            assert_eq!(buf, expected);
+        }
+    }
+
+    #[test]
+    fn test_set_msg_rate() {
+        let msg = SetMsgRate {
+            class: 0x01,
+            id: 0x07,
+            rate: 1,
+        };
+        let mut buf = [0xff_u8; SetMsgRate::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x01, 0x07, 0x01]);
+        assert_eq!(msg, SetMsgRate::deserialize(&mut buf.as_ref()).unwrap());
+    }
 }
@@ -0,0 +1,246 @@
+//! Navigation engine expert settings.
+
+use crate::messages::{primitive::*, VarMessage};
+use bitfield::bitfield;
+
+/// Navigation engine expert settings.
+///
+/// Only parameters whose bit is set in `mask1` or `mask2` are applied
+/// by the receiver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct NavX5 {
+    /// Message version.
+    ///
+    /// Versions 0 and 2 have a 40 byte payload, version 3 a 44 byte
+    /// payload.
+    pub version: U2,
+    /// First parameters bitmask.
+    pub mask1: Mask1,
+    /// Second parameters bitmask.
+    pub mask2: Mask2,
+    /// Minimum number of satellites for navigation.
+    pub min_svs: U1,
+    /// Maximum number of satellites for navigation.
+    pub max_svs: U1,
+    /// Minimum satellite signal level for navigation, in dBHz.
+    pub min_cno: U1,
+    /// 1 = initial fix must be 3D.
+    pub ini_fix_3d: U1,
+    /// 1 = issue acknowledgements for assistance message input.
+    pub ack_aiding: U1,
+    /// GPS week rollover number.
+    ///
+    /// GPS week numbers will be set correctly from this week up to
+    /// 1024 weeks after this week. Setting this to 0 reverts to
+    /// firmware default.
+    pub wkn_rollover: U2,
+    /// Only supported on certain products, zero for version 0.
+    pub sig_atten_comp_mode: U1,
+    /// 1 = use Precise Point Positioning (only available with the PPP
+    /// product variant).
+    pub use_ppp: U1,
+    /// AssistNow Autonomous configuration.
+    pub aop_cfg: AopCfg,
+    /// Maximum acceptable (modeled) AssistNow Autonomous orbit error
+    /// in meters, 0 = reset to firmware default.
+    pub aop_orb_max_err: U2,
+    /// Enable/disable ADR/UDR sensor fusion (if 0: sensor fusion is
+    /// disabled, if 1: sensor fusion is enabled).
+    pub use_adr: U1,
+}
+
+impl NavX5 {
+    const LEN_V0: usize = 40;
+    const LEN_V3: usize = 44;
+}
+
+impl VarMessage for NavX5 {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x23;
+
+    fn payload_len(&self) -> usize {
+        if self.version >= 3 {
+            Self::LEN_V3
+        } else {
+            Self::LEN_V0
+        }
+    }
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        };
+
+        dst.put_u16_le(self.version);
+        dst.put_u16_le(self.mask1.0);
+        dst.put_u32_le(self.mask2.0);
+        // reserved1
+        dst.put_u16_le(0);
+        dst.put_u8(self.min_svs);
+        dst.put_u8(self.max_svs);
+        dst.put_u8(self.min_cno);
+        // reserved2
+        dst.put_u8(0);
+        dst.put_u8(self.ini_fix_3d);
+        // reserved3
+        dst.put_u16_le(0);
+        dst.put_u8(self.ack_aiding);
+        dst.put_u16_le(self.wkn_rollover);
+        dst.put_u8(self.sig_atten_comp_mode);
+        // reserved4, reserved5, reserved6
+        dst.put_slice([0_u8; 5].as_ref());
+        dst.put_u8(self.use_ppp);
+        dst.put_u8(self.aop_cfg.0);
+        // reserved7
+        dst.put_u16_le(0);
+        dst.put_u16_le(self.aop_orb_max_err);
+        // reserved8, reserved9
+        dst.put_slice([0_u8; 7].as_ref());
+        dst.put_u8(self.use_adr);
+        if self.payload_len() == Self::LEN_V3 {
+            // reserved10, reserved11
+            dst.put_u32_le(0);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        let len = src.remaining();
+        if len != Self::LEN_V0 && len != Self::LEN_V3 {
+            return Err(());
+        }
+
+        let version = src.get_u16_le();
+        let mask1 = Mask1(src.get_u16_le());
+        let mask2 = Mask2(src.get_u32_le());
+        // reserved1
+        src.advance(2);
+        let min_svs = src.get_u8();
+        let max_svs = src.get_u8();
+        let min_cno = src.get_u8();
+        // reserved2
+        src.advance(1);
+        let ini_fix_3d = src.get_u8();
+        // reserved3
+        src.advance(2);
+        let ack_aiding = src.get_u8();
+        let wkn_rollover = src.get_u16_le();
+        let sig_atten_comp_mode = src.get_u8();
+        // reserved4, reserved5, reserved6
+        src.advance(5);
+        let use_ppp = src.get_u8();
+        let aop_cfg = AopCfg(src.get_u8());
+        // reserved7
+        src.advance(2);
+        let aop_orb_max_err = src.get_u16_le();
+        // reserved8, reserved9
+        src.advance(7);
+        let use_adr = src.get_u8();
+        // reserved10, reserved11
+        src.advance(len - Self::LEN_V0);
+
+        Ok(Self {
+            version,
+            mask1,
+            mask2,
+            min_svs,
+            max_svs,
+            min_cno,
+            ini_fix_3d,
+            ack_aiding,
+            wkn_rollover,
+            sig_atten_comp_mode,
+            use_ppp,
+            aop_cfg,
+            aop_orb_max_err,
+            use_adr,
+        })
+    }
+}
+
+bitfield! {
+    /// First parameters bitmask.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Mask1(X2);
+    impl Debug;
+    /// Apply AssistNow Autonomous settings
+    pub aop, set_aop: 14;
+    /// Apply PPP flag
+    pub ppp, set_ppp: 13;
+    /// Apply assistance acknowledgement settings
+    pub ack_aid, set_ack_aid: 10;
+    /// Apply GPS weeknumber rollover settings
+    pub wkn_roll, set_wkn_roll: 9;
+    /// Apply initial 3D fix settings
+    pub initial_3d_fix, set_initial_3d_fix: 6;
+    /// Apply minimum C/N0 setting
+    pub min_cno, set_min_cno: 3;
+    /// Apply min/max SVs settings
+    pub min_max, set_min_max: 2;
+}
+
+bitfield! {
+    /// Second parameters bitmask.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Mask2(X4);
+    impl Debug;
+    /// Apply signal attenuation compensation feature settings
+    pub sig_atten_comp, set_sig_atten_comp: 7;
+    /// Apply ADR/UDR sensor fusion on/off setting
+    pub adr, set_adr: 6;
+}
+
+bitfield! {
+    /// AssistNow Autonomous configuration.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct AopCfg(X1);
+    impl Debug;
+    /// Use AssistNow Autonomous
+    pub use_aop, set_use_aop: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_v2() {
+        let bytes = [
+            0x02, 0x00, 0x4c, 0x46, 0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x14, 0x06, 0x00,
+            0x01, 0x00, 0x00, 0x01, 0x5f, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x64, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        ];
+        let msg = NavX5::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.version, 2);
+        assert!(msg.mask1.min_max());
+        assert!(msg.mask1.min_cno());
+        assert!(msg.mask1.initial_3d_fix());
+        assert!(msg.mask1.aop());
+        assert!(!msg.mask1.ppp());
+        assert!(msg.mask2.adr());
+        assert_eq!(msg.min_svs, 3);
+        assert_eq!(msg.max_svs, 20);
+        assert_eq!(msg.min_cno, 6);
+        assert_eq!(msg.ini_fix_3d, 1);
+        assert_eq!(msg.ack_aiding, 1);
+        assert_eq!(msg.wkn_rollover, 2143);
+        assert!(msg.aop_cfg.use_aop());
+        assert_eq!(msg.aop_orb_max_err, 100);
+        assert_eq!(msg.use_adr, 1);
+
+        let mut buf = [0_u8; 40];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+
+    #[test]
+    fn test_lengths() {
+        let mut bytes = [0_u8; 44];
+        bytes[0] = 3;
+        let msg = NavX5::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.payload_len(), 44);
+        assert!(NavX5::deserialize(&mut bytes[..42].as_ref()).is_err());
+    }
+}
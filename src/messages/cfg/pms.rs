@@ -0,0 +1,201 @@
+//! Power mode setup messages.
+
+use crate::messages::{primitive::*, Message};
+
+/// Power mode setup.
+///
+/// Selects one of the receiver's predefined power setups, trading
+/// power consumption against performance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgPms {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+    /// Power setup value.
+    pub power_setup_value: PowerSetup,
+    /// Position update period, in seconds. Only valid for
+    /// [`PowerSetup::Interval`], otherwise must be 0.
+    pub period: U2,
+    /// Duration of the ON phase, in seconds. Must be smaller than
+    /// `period`. Only valid for [`PowerSetup::Interval`], otherwise
+    /// must be 0.
+    pub on_time: U2,
+}
+
+/// Predefined power setup.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowerSetup {
+    /// Full power.
+    Full,
+    /// Balanced.
+    Balanced,
+    /// Interval, with `period` and `on_time` of [`CfgPms`].
+    Interval,
+    /// Aggressive with 1 Hz.
+    Aggressive1Hz,
+    /// Aggressive with 2 Hz.
+    Aggressive2Hz,
+    /// Aggressive with 4 Hz.
+    Aggressive4Hz,
+    /// Invalid, only reported when polling.
+    Invalid,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for PowerSetup {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0x00 => PowerSetup::Full,
+            0x01 => PowerSetup::Balanced,
+            0x02 => PowerSetup::Interval,
+            0x03 => PowerSetup::Aggressive1Hz,
+            0x04 => PowerSetup::Aggressive2Hz,
+            0x05 => PowerSetup::Aggressive4Hz,
+            0xFF => PowerSetup::Invalid,
+            other => PowerSetup::Unknown(other),
+        }
+    }
+}
+
+impl From<PowerSetup> for u8 {
+    fn from(setup: PowerSetup) -> Self {
+        match setup {
+            PowerSetup::Full => 0x00,
+            PowerSetup::Balanced => 0x01,
+            PowerSetup::Interval => 0x02,
+            PowerSetup::Aggressive1Hz => 0x03,
+            PowerSetup::Aggressive2Hz => 0x04,
+            PowerSetup::Aggressive4Hz => 0x05,
+            PowerSetup::Invalid => 0xFF,
+            PowerSetup::Unknown(other) => other,
+        }
+    }
+}
+
+impl CfgPms {
+    /// Current message version.
+    pub const VERSION: U1 = 0x00;
+
+    fn preset(power_setup_value: PowerSetup) -> Self {
+        Self {
+            version: Self::VERSION,
+            power_setup_value,
+            period: 0,
+            on_time: 0,
+        }
+    }
+
+    /// Returns a message selecting the full power setup.
+    pub fn full_power() -> Self {
+        Self::preset(PowerSetup::Full)
+    }
+
+    /// Returns a message selecting the balanced power setup.
+    pub fn balanced() -> Self {
+        Self::preset(PowerSetup::Balanced)
+    }
+
+    /// Returns a message selecting the interval power setup, with a
+    /// position update every `period` seconds and an ON phase of
+    /// `on_time` seconds.
+    pub fn interval(period: U2, on_time: U2) -> Self {
+        Self {
+            period,
+            on_time,
+            ..Self::preset(PowerSetup::Interval)
+        }
+    }
+
+    /// Returns a message selecting the aggressive power setup with
+    /// 1 Hz updates.
+    pub fn aggressive_1hz() -> Self {
+        Self::preset(PowerSetup::Aggressive1Hz)
+    }
+
+    /// Returns a message selecting the aggressive power setup with
+    /// 2 Hz updates.
+    pub fn aggressive_2hz() -> Self {
+        Self::preset(PowerSetup::Aggressive2Hz)
+    }
+
+    /// Returns a message selecting the aggressive power setup with
+    /// 4 Hz updates.
+    pub fn aggressive_4hz() -> Self {
+        Self::preset(PowerSetup::Aggressive4Hz)
+    }
+}
+
+impl Message for CfgPms {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x86;
+    const LEN: usize = 8;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.version);
+        dst.put_u8(self.power_setup_value.into());
+        dst.put_u16_le(self.period);
+        dst.put_u16_le(self.on_time);
+        // reserved1
+        dst.put_u16_le(0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        let power_setup_value = PowerSetup::from(src.get_u8());
+        let period = src.get_u16_le();
+        let on_time = src.get_u16_le();
+        // reserved1
+        src.advance(2);
+
+        Ok(Self {
+            version,
+            power_setup_value,
+            period,
+            on_time,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let msg = CfgPms::balanced();
+        assert_eq!(u8::from(msg.power_setup_value), 1);
+        let mut buf = [0xff_u8; CfgPms::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, CfgPms::deserialize(&mut buf.as_ref()).unwrap());
+
+        let msg = CfgPms::interval(60, 10);
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x00, 0x02, 0x3c, 0x00, 0x0a, 0x00, 0x00, 0x00]);
+
+        let presets = [
+            (CfgPms::full_power(), 0),
+            (CfgPms::aggressive_1hz(), 3),
+            (CfgPms::aggressive_2hz(), 4),
+            (CfgPms::aggressive_4hz(), 5),
+        ];
+        for (msg, raw) in presets.iter() {
+            assert_eq!(u8::from(msg.power_setup_value), *raw);
+            assert_eq!((msg.period, msg.on_time), (0, 0));
+        }
+        for raw in 0..=u8::MAX {
+            assert_eq!(u8::from(PowerSetup::from(raw)), raw);
+        }
+        assert_eq!(PowerSetup::from(0xff), PowerSetup::Invalid);
+    }
+}
@@ -1,11 +1,20 @@
 //! Port configuration messages.
 
-use crate::messages::{primitive::*, Message};
+use super::PortId;
+use crate::messages::{primitive::*, VarMessage};
 use bitfield::bitfield;
 
 /// Port configuration.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Prt {
+    /// Poll the configuration of a port.
+    ///
+    /// The receiver responds with the configuration of the port
+    /// identified by `port_id`.
+    Poll {
+        /// Port identifier number.
+        port_id: U1,
+    },
     /// Port configuration for UART ports
     ///
     /// Note that this message can affect baud rate and other
@@ -17,6 +26,8 @@ pub enum Prt {
     /// receive future messages, including the acknowledge message
     /// resulting from the CFG-PRT message.
     Uart {
+        /// Port identifier number, 1 for UART1 or 2 for UART2.
+        port_id: U1,
         /// TX ready PIN configuration.
         tx_ready: TxReady,
         /// A bit mask describing the UART mode.
@@ -74,26 +85,74 @@ pub enum Prt {
         /// Flags bit mask
         flags: Flags,
     },
+    /// Port configuration for USB port.
+    Usb {
+        /// TX ready PIN configuration.
+        tx_ready: TxReady,
+        /// A mask describing which input protocols are active.
+        ///
+        /// Each bit of this mask is used for a protocol. Through
+        /// that, multiple protocols can be defined on a single port.
+        in_proto_mask: InProtoMask,
+        /// A mask describing which output protocols are active.
+        ///
+        /// Each bit of this mask is used for a protocol. Through that,
+        /// multiple protocols can be defined on a single port.
+        out_proto_mask: OutProtoMask,
+    },
 }
 
 impl Prt {
-    const I2C_PORT: u8 = 0;
-    const UART_PORT: u8 = 1;
-    const SPI_PORT: u8 = 4;
+    const I2C_PORT: u8 = PortId::Ddc as u8;
+    const UART1_PORT: u8 = PortId::Uart1 as u8;
+    const UART2_PORT: u8 = PortId::Uart2 as u8;
+    const USB_PORT: u8 = PortId::Usb as u8;
+    const SPI_PORT: u8 = PortId::Spi as u8;
+
+    const POLL_LEN: usize = 1;
+    const LEN: usize = 20;
+
+    /// Returns a message polling the configuration of `port`.
+    pub fn poll(port: PortId) -> Self {
+        Prt::Poll {
+            port_id: port.into(),
+        }
+    }
+
+    /// Returns the identifier of the port this message polls or
+    /// describes.
+    pub fn port_id(&self) -> U1 {
+        match self {
+            Prt::Poll { port_id } | Prt::Uart { port_id, .. } => *port_id,
+            Prt::I2c { .. } => Self::I2C_PORT,
+            Prt::Usb { .. } => Self::USB_PORT,
+            Prt::Spi { .. } => Self::SPI_PORT,
+        }
+    }
 }
 
-impl Message for Prt {
+impl VarMessage for Prt {
     const CLASS: u8 = 0x06;
     const ID: u8 = 0x00;
-    const LEN: usize = 20;
+
+    fn payload_len(&self) -> usize {
+        match self {
+            Prt::Poll { .. } => Self::POLL_LEN,
+            _ => Self::LEN,
+        }
+    }
 
     fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
-        if dst.remaining_mut() < Self::LEN {
+        if dst.remaining_mut() < self.payload_len() {
             return Err(());
         };
 
         match self {
+            Prt::Poll { port_id } => {
+                dst.put_u8(*port_id);
+            }
             Prt::Uart {
+                port_id,
                 tx_ready,
                 mode,
                 baud_rate,
@@ -101,7 +160,7 @@ impl Message for Prt {
                 out_proto_mask,
                 flags,
             } => {
-                dst.put_u8(Self::UART_PORT);
+                dst.put_u8(*port_id);
                 // reserved 1
                 dst.put_u8(0);
                 dst.put_u16_le(tx_ready.0);
@@ -153,17 +212,41 @@ impl Message for Prt {
                 // reserved3
                 dst.put_u16_le(0);
             }
+            Prt::Usb {
+                tx_ready,
+                in_proto_mask,
+                out_proto_mask,
+            } => {
+                dst.put_u8(Self::USB_PORT);
+                // reserved 1
+                dst.put_u8(0);
+                dst.put_u16_le(tx_ready.0);
+                // reserved2
+                dst.put_u64_le(0);
+                dst.put_u16_le(in_proto_mask.0);
+                dst.put_u16_le(out_proto_mask.0);
+                // reserved3
+                dst.put_u16_le(0);
+                // reserved4
+                dst.put_u16_le(0);
+            }
         }
         Ok(())
     }
 
     fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
-        if src.remaining() < Self::LEN {
-            return Err(());
+        match src.remaining() {
+            Self::POLL_LEN => {
+                return Ok(Self::Poll {
+                    port_id: src.get_u8(),
+                })
+            }
+            Self::LEN => (),
+            _ => return Err(()),
         }
 
         match src.get_u8() {
-            Self::UART_PORT => {
+            port_id @ Self::UART1_PORT | port_id @ Self::UART2_PORT => {
                 // reserved 1
                 let _ = src.get_u8();
                 let tx_ready = TxReady(src.get_u16_le());
@@ -175,6 +258,7 @@ impl Message for Prt {
                 // reserved2
                 let _ = src.get_u16_le();
                 Ok(Self::Uart {
+                    port_id,
                     tx_ready,
                     mode,
                     baud_rate,
@@ -223,6 +307,24 @@ impl Message for Prt {
                     flags,
                 })
             }
+            Self::USB_PORT => {
+                // reserved 1
+                let _ = src.get_u8();
+                let tx_ready = TxReady(src.get_u16_le());
+                // reserved2
+                let _ = src.get_u64_le();
+                let in_proto_mask = InProtoMask(src.get_u16_le());
+                let out_proto_mask = OutProtoMask(src.get_u16_le());
+                // reserved3
+                let _ = src.get_u16_le();
+                // reserved4
+                let _ = src.get_u16_le();
+                Ok(Self::Usb {
+                    tx_ready,
+                    in_proto_mask,
+                    out_proto_mask,
+                })
+            }
             _ => Err(()),
         }
     }
@@ -260,6 +362,24 @@ bitfield! {
     pub en, set_en: 0;
 }
 
+impl TxReady {
+    /// Largest threshold, in bytes, which `thres` can encode.
+    pub const MAX_THRESHOLD_BYTES: u16 = 0x1FF * 8;
+
+    /// Sets the threshold to `bytes`, rounded up to the next multiple
+    /// of 8.
+    ///
+    /// Returns an error, leaving `self` unchanged, if the rounded
+    /// threshold exceeds [`TxReady::MAX_THRESHOLD_BYTES`].
+    pub fn set_threshold_bytes(&mut self, bytes: u16) -> Result<(), ()> {
+        if bytes > Self::MAX_THRESHOLD_BYTES {
+            return Err(());
+        }
+        self.set_thres(bytes.div_ceil(8));
+        Ok(())
+    }
+}
+
 bitfield! {
     /// Bitfield `mode` for uart port configuration.
     #[derive(Clone, Copy, Eq, PartialEq)]
@@ -298,6 +418,21 @@ bitfield! {
     pub slave_addr, set_slave_addr: 7, 1;
 }
 
+impl I2cMode {
+    /// Sets the slave address to `addr`.
+    ///
+    /// Returns an error, leaving `self` unchanged, if `addr` is not a
+    /// 7-bit address or is reserved by the I2C specification, i.e. is
+    /// in 0x00..=0x07 or 0x78..=0x7F.
+    pub fn set_address(&mut self, addr: u8) -> Result<(), ()> {
+        if !(0x08..=0x77).contains(&addr) {
+            return Err(());
+        }
+        self.set_slave_addr(addr);
+        Ok(())
+    }
+}
+
 bitfield! {
     /// Bitfield `mode` for spi port configuration.
     #[derive(Clone, Copy, Eq, PartialEq)]
@@ -358,3 +493,242 @@ bitfield! {
     /// TX memory.
     pub extended_tx_timeout, set_extended_tx_timeout: 1;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_poll() {
+        let msg = Prt::Poll { port_id: 1 };
+        let mut buf = [0xff_u8; 1];
+        assert_eq!(msg.payload_len(), 1);
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x01]);
+        assert_eq!(Prt::deserialize(&mut buf.as_ref()), Ok(msg));
+    }
+
+    #[test]
+    fn test_port_id() {
+        let bytes = [
+            0x02, 0x00, 0x00, 0x00, 0xc0, 0x08, 0x00, 0x00, 0x80, 0x25, 0x00, 0x00, 0x01, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let msg = Prt::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.port_id(), 2);
+        match &msg {
+            Prt::Uart { baud_rate, .. } => assert_eq!(*baud_rate, 9600),
+            _ => panic!("expected UART configuration, got {:?}", msg),
+        }
+        let mut buf = [0_u8; 20];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(Prt::deserialize(&mut bytes[..19].as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_byte_layout() {
+        let msg = Prt::I2c {
+            tx_ready: TxReady(0x1234),
+            mode: I2cMode(0x0000_0084),
+            in_proto_mask: InProtoMask(0x0007),
+            out_proto_mask: OutProtoMask(0x0023),
+            flags: Flags(0x0002),
+        };
+        let bytes = [
+            0x00, 0x00, 0x34, 0x12, 0x84, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07, 0x00,
+            0x23, 0x00, 0x02, 0x00, 0x00, 0x00,
+        ];
+        let mut buf = [0xff_u8; 20];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert_eq!(Prt::deserialize(&mut bytes.as_ref()), Ok(msg));
+    }
+
+    #[test]
+    fn test_usb() {
+        // Response to `Prt::poll(PortId::Usb)`, with UBX and NMEA
+        // enabled in both directions.
+        let bytes = [
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00,
+            0x03, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let msg = Prt::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.port_id(), PortId::Usb as u8);
+        match &msg {
+            Prt::Usb {
+                in_proto_mask,
+                out_proto_mask,
+                ..
+            } => {
+                assert!(in_proto_mask.in_ubx() && in_proto_mask.in_nmea());
+                assert!(out_proto_mask.out_ubx() && out_proto_mask.out_nmea());
+            }
+            _ => panic!("expected USB configuration, got {:?}", msg),
+        }
+        let mut buf = [0xff_u8; 20];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+
+    #[test]
+    fn test_spi() {
+        let bytes = [
+            0x04, 0x00, 0x00, 0x00, 0x02, 0x32, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+        let msg = Prt::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.port_id(), 4);
+        match &msg {
+            Prt::Spi {
+                mode,
+                in_proto_mask,
+                out_proto_mask,
+                ..
+            } => {
+                assert_eq!(mode.spi_mode(), 0b01);
+                assert_eq!(mode.ff_cnt(), 50);
+                assert!(in_proto_mask.in_ubx());
+                assert!(out_proto_mask.out_ubx());
+            }
+            _ => panic!("expected SPI configuration, got {:?}", msg),
+        }
+        let mut buf = [0xff_u8; 20];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+
+        // Bytes 8..12 are reserved for SPI, rather than the baud rate.
+        let mut bytes = bytes;
+        bytes[8..12].copy_from_slice(&[0x80, 0x25, 0x00, 0x00]);
+        assert_eq!(Prt::deserialize(&mut bytes.as_ref()), Ok(msg));
+    }
+
+    #[test]
+    fn test_lengths() {
+        let msgs = [
+            Prt::Uart {
+                port_id: 1,
+                tx_ready: TxReady(0),
+                mode: UartMode(0),
+                baud_rate: 115_200,
+                in_proto_mask: InProtoMask(0),
+                out_proto_mask: OutProtoMask(0),
+                flags: Flags(0),
+            },
+            Prt::I2c {
+                tx_ready: TxReady(0),
+                mode: I2cMode(0),
+                in_proto_mask: InProtoMask(0),
+                out_proto_mask: OutProtoMask(0),
+                flags: Flags(0),
+            },
+            Prt::Spi {
+                tx_ready: TxReady(0),
+                mode: SpiMode(0),
+                in_proto_mask: InProtoMask(0),
+                out_proto_mask: OutProtoMask(0),
+                flags: Flags(0),
+            },
+        ];
+        for msg in &msgs {
+            let mut buf = alloc::vec::Vec::new();
+            msg.serialize(&mut buf).unwrap();
+            assert_eq!(buf.len(), Prt::LEN);
+            assert_eq!(msg.payload_len(), Prt::LEN);
+            assert_eq!(Prt::deserialize(&mut buf.as_slice()).as_ref(), Ok(msg));
+
+            // A short buffer is an error, not a panic.
+            let mut short = [0_u8; Prt::LEN - 1];
+            assert!(msg.serialize(&mut short.as_mut()).is_err());
+        }
+    }
+
+    #[test]
+    fn test_set_threshold_bytes() {
+        let mut txr = TxReady(0);
+        txr.set_en(true);
+        txr.set_threshold_bytes(0).unwrap();
+        assert_eq!(txr.thres(), 0);
+        txr.set_threshold_bytes(8).unwrap();
+        assert_eq!(txr.thres(), 1);
+        txr.set_threshold_bytes(10).unwrap();
+        assert_eq!(txr.thres(), 2);
+        txr.set_threshold_bytes(4088).unwrap();
+        assert_eq!(txr.thres(), 0x1FF);
+        assert!(txr.en());
+        assert!(txr.set_threshold_bytes(4089).is_err());
+        assert!(txr.set_threshold_bytes(4096).is_err());
+        assert_eq!(txr.thres(), 0x1FF);
+    }
+
+    #[test]
+    fn test_bitfields() {
+        let mut txr = TxReady(0);
+        txr.set_thres(1);
+        assert_eq!(txr.0, 0x0080);
+        txr.set_thres(0x1ff);
+        assert_eq!(txr.0, 0xff80);
+        let mut txr = TxReady(0);
+        txr.set_pin(13);
+        assert_eq!(txr.0, 0x0034);
+        txr.set_pol(true);
+        txr.set_en(true);
+        assert_eq!(txr.0, 0x0037);
+        assert_eq!((txr.thres(), txr.pin()), (0, 13));
+
+        let mut mode = UartMode(0);
+        mode.set_char_len(0b11);
+        assert_eq!(mode.0, 0x0000_00c0);
+        mode.set_parity(0b100);
+        assert_eq!(mode.0, 0x0000_08c0);
+        mode.set_n_stop_bits(0b10);
+        assert_eq!(mode.0, 0x0000_28c0);
+        assert_eq!(
+            (mode.char_len(), mode.parity(), mode.n_stop_bits()),
+            (0b11, 0b100, 0b10)
+        );
+
+        let mut mode = I2cMode(0);
+        mode.set_slave_addr(0x42);
+        assert_eq!(mode.0, 0x0000_0084);
+        mode.set_slave_addr(0x7f);
+        assert_eq!(mode.0, 0x0000_00fe);
+
+        let mut mode = SpiMode(0);
+        mode.set_spi_mode(0b11);
+        assert_eq!(mode.0, 0x0000_0006);
+        mode.set_ff_cnt(63);
+        assert_eq!(mode.0, 0x0000_3f06);
+        assert_eq!((mode.spi_mode(), mode.ff_cnt()), (0b11, 63));
+
+        let mut mask = InProtoMask(0);
+        mask.set_in_ubx(true);
+        mask.set_in_nmea(true);
+        mask.set_in_rtcm(true);
+        mask.set_in_rtcm3(true);
+        assert_eq!(mask.0, 0x0027);
+        let mut mask = OutProtoMask(0);
+        mask.set_out_ubx(true);
+        mask.set_out_nmea(true);
+        mask.set_out_rtcm3(true);
+        assert_eq!(mask.0, 0x0023);
+        let mut flags = Flags(0);
+        flags.set_extended_tx_timeout(true);
+        assert_eq!(flags.0, 0x0002);
+    }
+
+    #[test]
+    fn test_set_address() {
+        let mut mode = I2cMode(0);
+        mode.set_address(0x42).unwrap();
+        assert_eq!(mode.slave_addr(), 0x42);
+        mode.set_address(0x08).unwrap();
+        assert_eq!(mode.slave_addr(), 0x08);
+        mode.set_address(0x77).unwrap();
+        assert_eq!(mode.slave_addr(), 0x77);
+        for &addr in [0x00, 0x07, 0x78, 0x7f, 0x80, 0xff].iter() {
+            assert!(mode.set_address(addr).is_err());
+        }
+        assert_eq!(mode.0, 0x0000_00ee);
+    }
+}
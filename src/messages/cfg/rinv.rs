@@ -0,0 +1,122 @@
+//! Remote inventory configuration messages.
+
+use crate::messages::{primitive::*, VarMessage};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+use bitfield::bitfield;
+
+/// Contents of the remote inventory.
+///
+/// The remote inventory is a small block of user data, e.g. an asset
+/// tag, stored in the receiver's non-volatile memory.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgRinv {
+    /// Flags.
+    pub flags: Flags,
+    data: Vec<u8>,
+}
+
+impl CfgRinv {
+    /// Maximum length of the remote inventory data.
+    pub const MAX_DATA_LEN: usize = 30;
+
+    /// Returns a remote inventory containing `data`.
+    ///
+    /// Returns an error if `data` is longer than
+    /// [`MAX_DATA_LEN`](Self::MAX_DATA_LEN).
+    pub fn new(flags: Flags, data: &[u8]) -> Result<Self, ()> {
+        if data.len() > Self::MAX_DATA_LEN {
+            return Err(());
+        }
+        Ok(CfgRinv {
+            flags,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Returns a remote inventory containing text `s`.
+    ///
+    /// Returns an error if `s` is longer than
+    /// [`MAX_DATA_LEN`](Self::MAX_DATA_LEN) bytes.
+    pub fn text(s: &str) -> Result<Self, ()> {
+        Self::new(Flags(0), s.as_bytes())
+    }
+
+    /// Returns the stored data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the stored data as text, replacing any invalid UTF-8
+    /// sequences.
+    pub fn to_string_lossy(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.data)
+    }
+}
+
+impl VarMessage for CfgRinv {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x34;
+
+    fn payload_len(&self) -> usize {
+        1 + self.data.len()
+    }
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        };
+
+        dst.put_u8(self.flags.0);
+        dst.put_slice(&self.data);
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < 1 || src.remaining() > 1 + Self::MAX_DATA_LEN {
+            return Err(());
+        }
+
+        let flags = Flags(src.get_u8());
+        let mut data = alloc::vec![0; src.remaining()];
+        src.copy_to_slice(&mut data);
+
+        Ok(Self { flags, data })
+    }
+}
+
+bitfield! {
+    /// Remote inventory flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags(X1);
+    impl Debug;
+    /// Data is binary.
+    pub binary, set_binary: 1;
+    /// Dump data at startup. Does not work if flag binary is set.
+    pub dump, set_dump: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = CfgRinv::text("ASSET-0042").unwrap();
+        let mut buf = [0_u8; 11];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(&buf, b"\x00ASSET-0042");
+
+        let decoded = CfgRinv::deserialize(&mut buf.as_ref()).unwrap();
+        assert_eq!(decoded, msg);
+        assert_eq!(decoded.to_string_lossy(), "ASSET-0042");
+    }
+
+    #[test]
+    fn test_max_len() {
+        assert!(CfgRinv::text(&"x".repeat(CfgRinv::MAX_DATA_LEN)).is_ok());
+        assert!(CfgRinv::text(&"x".repeat(CfgRinv::MAX_DATA_LEN + 1)).is_err());
+        assert!(CfgRinv::deserialize(&mut [0_u8; 32].as_ref()).is_err());
+    }
+}
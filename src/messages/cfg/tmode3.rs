@@ -0,0 +1,265 @@
+//! Time mode configuration messages.
+
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+
+/// Time mode settings.
+///
+/// Configures the receiver's own position for RTK base station and
+/// timing applications, either by surveying it in or by setting it to
+/// a known, fixed position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CfgTmode3 {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+    /// Receiver mode and position format.
+    pub flags: Flags,
+    /// WGS84 ECEF X coordinate in cm, or latitude in 1e-7 degrees if
+    /// `flags.lla()` is set.
+    pub ecef_x_or_lat: I4,
+    /// WGS84 ECEF Y coordinate in cm, or longitude in 1e-7 degrees if
+    /// `flags.lla()` is set.
+    pub ecef_y_or_lon: I4,
+    /// WGS84 ECEF Z coordinate or altitude, in cm.
+    pub ecef_z_or_alt: I4,
+    /// High-precision part of `ecef_x_or_lat`, in 0.1 mm, or 1e-9
+    /// degrees if `flags.lla()` is set. Range -99..99.
+    pub ecef_x_or_lat_hp: I1,
+    /// High-precision part of `ecef_y_or_lon`, in 0.1 mm, or 1e-9
+    /// degrees if `flags.lla()` is set. Range -99..99.
+    pub ecef_y_or_lon_hp: I1,
+    /// High-precision part of `ecef_z_or_alt`, in 0.1 mm. Range
+    /// -99..99.
+    pub ecef_z_or_alt_hp: I1,
+    /// Fixed position 3D accuracy, in 0.1 mm.
+    pub fixed_pos_acc: U4,
+    /// Survey-in minimum duration, in seconds.
+    pub svin_min_dur: U4,
+    /// Survey-in position accuracy limit, in 0.1 mm.
+    pub svin_acc_limit: U4,
+}
+
+/// Receiver time mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeMode {
+    /// Time mode is disabled.
+    Disabled,
+    /// Survey-in the receiver's position.
+    SurveyIn,
+    /// Use the fixed position in the message.
+    Fixed,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for TimeMode {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => TimeMode::Disabled,
+            1 => TimeMode::SurveyIn,
+            2 => TimeMode::Fixed,
+            other => TimeMode::Unknown(other),
+        }
+    }
+}
+
+impl From<TimeMode> for u8 {
+    fn from(mode: TimeMode) -> Self {
+        match mode {
+            TimeMode::Disabled => 0,
+            TimeMode::SurveyIn => 1,
+            TimeMode::Fixed => 2,
+            TimeMode::Unknown(other) => other,
+        }
+    }
+}
+
+impl CfgTmode3 {
+    /// Current message version.
+    pub const VERSION: U1 = 0x00;
+
+    /// Returns a message putting the receiver in survey-in mode.
+    ///
+    /// Survey-in ends once at least `min_dur_s` seconds have elapsed
+    /// and the position accuracy is within `acc_limit_mm`.
+    pub fn survey_in(min_dur_s: U4, acc_limit_mm: U4) -> Self {
+        let mut flags = Flags(0);
+        flags.set_mode(TimeMode::SurveyIn.into());
+        Self {
+            version: Self::VERSION,
+            flags,
+            ecef_x_or_lat: 0,
+            ecef_y_or_lon: 0,
+            ecef_z_or_alt: 0,
+            ecef_x_or_lat_hp: 0,
+            ecef_y_or_lon_hp: 0,
+            ecef_z_or_alt_hp: 0,
+            fixed_pos_acc: 0,
+            svin_min_dur: min_dur_s,
+            svin_acc_limit: acc_limit_mm.saturating_mul(10),
+        }
+    }
+
+    /// Returns a message putting the receiver in fixed mode at
+    /// latitude `lat_deg`, longitude `lon_deg`, and altitude `alt_m`,
+    /// known to within `pos_acc_mm`.
+    ///
+    /// Coordinates are split into standard and high-precision parts,
+    /// i.e. to 1e-9 degree and 0.1 mm resolution.
+    pub fn fixed_llh(lat_deg: f64, lon_deg: f64, alt_m: f64, pos_acc_mm: U4) -> Self {
+        // Splits `value * scale`, rounded, into a value in units of
+        // 100 and a high-precision remainder.
+        fn split(value: f64, scale: f64) -> (I4, I1) {
+            let half = if value < 0.0 { -0.5 } else { 0.5 };
+            let raw = (value * scale + half) as i64;
+            ((raw / 100) as I4, (raw % 100) as I1)
+        }
+
+        let (lat, lat_hp) = split(lat_deg, 1e9);
+        let (lon, lon_hp) = split(lon_deg, 1e9);
+        let (alt, alt_hp) = split(alt_m, 1e4);
+        let mut flags = Flags(0);
+        flags.set_mode(TimeMode::Fixed.into());
+        flags.set_lla(true);
+        Self {
+            version: Self::VERSION,
+            flags,
+            ecef_x_or_lat: lat,
+            ecef_y_or_lon: lon,
+            ecef_z_or_alt: alt,
+            ecef_x_or_lat_hp: lat_hp,
+            ecef_y_or_lon_hp: lon_hp,
+            ecef_z_or_alt_hp: alt_hp,
+            fixed_pos_acc: pos_acc_mm.saturating_mul(10),
+            svin_min_dur: 0,
+            svin_acc_limit: 0,
+        }
+    }
+
+    /// Returns receiver time mode.
+    pub fn mode(&self) -> TimeMode {
+        TimeMode::from(self.flags.mode())
+    }
+}
+
+impl Message for CfgTmode3 {
+    const CLASS: u8 = 0x06;
+    const ID: u8 = 0x71;
+    const LEN: usize = 40;
+
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        };
+
+        dst.put_u8(self.version);
+        // reserved1
+        dst.put_u8(0);
+        dst.put_u16_le(self.flags.0);
+        dst.put_i32_le(self.ecef_x_or_lat);
+        dst.put_i32_le(self.ecef_y_or_lon);
+        dst.put_i32_le(self.ecef_z_or_alt);
+        dst.put_i8(self.ecef_x_or_lat_hp);
+        dst.put_i8(self.ecef_y_or_lon_hp);
+        dst.put_i8(self.ecef_z_or_alt_hp);
+        // reserved2
+        dst.put_u8(0);
+        dst.put_u32_le(self.fixed_pos_acc);
+        dst.put_u32_le(self.svin_min_dur);
+        dst.put_u32_le(self.svin_acc_limit);
+        // reserved3
+        dst.put_slice([0_u8; 8].as_ref());
+
+        Ok(())
+    }
+
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        // reserved1
+        src.advance(1);
+        let flags = Flags(src.get_u16_le());
+        let ecef_x_or_lat = src.get_i32_le();
+        let ecef_y_or_lon = src.get_i32_le();
+        let ecef_z_or_alt = src.get_i32_le();
+        let ecef_x_or_lat_hp = src.get_i8();
+        let ecef_y_or_lon_hp = src.get_i8();
+        let ecef_z_or_alt_hp = src.get_i8();
+        // reserved2
+        src.advance(1);
+        let fixed_pos_acc = src.get_u32_le();
+        let svin_min_dur = src.get_u32_le();
+        let svin_acc_limit = src.get_u32_le();
+        // reserved3
+        src.advance(8);
+
+        Ok(Self {
+            version,
+            flags,
+            ecef_x_or_lat,
+            ecef_y_or_lon,
+            ecef_z_or_alt,
+            ecef_x_or_lat_hp,
+            ecef_y_or_lon_hp,
+            ecef_z_or_alt_hp,
+            fixed_pos_acc,
+            svin_min_dur,
+            svin_acc_limit,
+        })
+    }
+}
+
+bitfield! {
+    /// Time mode flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags(X2);
+    impl Debug;
+    u8;
+    /// Position is given in LAT/LON/ALT (default is ECEF)
+    pub lla, set_lla: 8;
+    /// Receiver mode, see [`TimeMode`]
+    pub mode, set_mode: 7, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_survey_in() {
+        let msg = CfgTmode3::survey_in(300, 2_000);
+        assert_eq!(msg.mode(), TimeMode::SurveyIn);
+        assert!(!msg.flags.lla());
+
+        let mut buf = [0xff_u8; CfgTmode3::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        let mut expected = [0_u8; CfgTmode3::LEN];
+        expected[2] = 0x01;
+        expected[24..28].copy_from_slice(&300_u32.to_le_bytes());
+        expected[28..32].copy_from_slice(&20_000_u32.to_le_bytes());
+        assert_eq!(buf, expected);
+        assert_eq!(msg, CfgTmode3::deserialize(&mut buf.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_fixed_llh() {
+        let msg = CfgTmode3::fixed_llh(40.712_812_345, -74.006_012_345, 10.123_45, 50);
+        assert_eq!(msg.mode(), TimeMode::Fixed);
+        assert!(msg.flags.lla());
+        assert_eq!((msg.ecef_x_or_lat, msg.ecef_x_or_lat_hp), (407_128_123, 45));
+        assert_eq!(
+            (msg.ecef_y_or_lon, msg.ecef_y_or_lon_hp),
+            (-740_060_123, -45)
+        );
+        assert_eq!((msg.ecef_z_or_alt, msg.ecef_z_or_alt_hp), (1_012, 35));
+        assert_eq!(msg.fixed_pos_acc, 500);
+
+        let mut buf = [0_u8; CfgTmode3::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(&buf[2..4], [0x02, 0x01]);
+        assert_eq!(msg, CfgTmode3::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
@@ -0,0 +1,163 @@
+//! Grouping of navigation messages by epoch.
+
+use crate::messages::nav::{
+    Dop, Geofence, Nav, Odo, PosEcef, Pvt, Sat, Sig, Slas, Status, Svin, TimeGps, VelEcef,
+};
+use crate::messages::primitive::*;
+
+/// All navigation messages output for a single navigation epoch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Epoch {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub itow: U4,
+    /// NAV-PVT, if received.
+    pub pvt: Option<Pvt>,
+    /// NAV-DOP, if received.
+    pub dop: Option<Dop>,
+    /// NAV-SAT, if received.
+    pub sat: Option<Sat>,
+    /// NAV-TIMEGPS, if received.
+    pub time_gps: Option<TimeGps>,
+    /// NAV-POSECEF, if received.
+    pub pos_ecef: Option<PosEcef>,
+    /// NAV-VELECEF, if received.
+    pub vel_ecef: Option<VelEcef>,
+    /// NAV-ODO, if received.
+    pub odo: Option<Odo>,
+    /// NAV-GEOFENCE, if received.
+    pub geofence: Option<Geofence>,
+    /// NAV-SIG, if received.
+    pub sig: Option<Sig>,
+    /// NAV-SVIN, if received.
+    pub svin: Option<Svin>,
+    /// NAV-SLAS, if received.
+    pub slas: Option<Slas>,
+    /// NAV-STATUS, if received.
+    pub status: Option<Status>,
+}
+
+impl Epoch {
+    fn new(itow: U4) -> Self {
+        Epoch {
+            itow,
+            ..Default::default()
+        }
+    }
+
+    fn insert(&mut self, msg: Nav) {
+        match msg {
+            Nav::Pvt(msg) => self.pvt = Some(msg),
+            Nav::Dop(msg) => self.dop = Some(msg),
+            Nav::Sat(msg) => self.sat = Some(msg),
+            Nav::TimeGps(msg) => self.time_gps = Some(msg),
+            Nav::PosEcef(msg) => self.pos_ecef = Some(msg),
+            Nav::VelEcef(msg) => self.vel_ecef = Some(msg),
+            Nav::Odo(msg) => self.odo = Some(msg),
+            Nav::Geofence(msg) => self.geofence = Some(msg),
+            Nav::Sig(msg) => self.sig = Some(msg),
+            Nav::Svin(msg) => self.svin = Some(msg),
+            Nav::Slas(msg) => self.slas = Some(msg),
+            Nav::Status(msg) => self.status = Some(msg),
+            Nav::Eoe(_) => (),
+        }
+    }
+}
+
+/// Assembles navigation messages into [`Epoch`]s.
+///
+/// An epoch is complete when NAV-EOE for its iTOW arrives, or when a
+/// message for a different iTOW arrives, e.g. because NAV-EOE is not
+/// enabled.
+#[derive(Clone, Debug, Default)]
+pub struct EpochCollector {
+    current: Option<Epoch>,
+}
+
+impl EpochCollector {
+    /// Returns a new collector.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `msg` to the current epoch, returning the previous epoch
+    /// if `msg` completes it.
+    ///
+    /// Messages without an iTOW are ignored.
+    pub fn push(&mut self, msg: Nav) -> Option<Epoch> {
+        let itow = msg.itow()?;
+        let completed = match &self.current {
+            Some(epoch) if epoch.itow != itow => self.current.take(),
+            _ => None,
+        };
+
+        if let Nav::Eoe(_) = msg {
+            return completed.or_else(|| self.current.take());
+        }
+
+        self.current
+            .get_or_insert_with(|| Epoch::new(itow))
+            .insert(msg);
+        completed
+    }
+
+    /// Returns the current, possibly incomplete, epoch.
+    pub fn flush(&mut self) -> Option<Epoch> {
+        self.current.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::nav::Eoe;
+    use crate::messages::Message;
+
+    fn pvt(itow: U4) -> Pvt {
+        let mut payload = [0_u8; Pvt::LEN];
+        payload[..4].copy_from_slice(&itow.to_le_bytes());
+        Pvt::deserialize(&mut payload.as_ref()).unwrap()
+    }
+
+    fn dop(itow: U4) -> Dop {
+        Dop {
+            iTOW: itow,
+            gDOP: 150,
+            pDOP: 120,
+            tDOP: 90,
+            vDOP: 100,
+            hDOP: 80,
+            nDOP: 60,
+            eDOP: 50,
+        }
+    }
+
+    #[test]
+    fn test_collect_on_eoe() {
+        let mut collector = EpochCollector::new();
+        assert_eq!(collector.push(Nav::Pvt(pvt(1000))), None);
+        assert_eq!(collector.push(Nav::Dop(dop(1000))), None);
+        let epoch = collector.push(Nav::Eoe(Eoe { iTOW: 1000 })).unwrap();
+        assert_eq!(epoch.itow, 1000);
+        assert_eq!(epoch.pvt, Some(pvt(1000)));
+        assert_eq!(epoch.dop, Some(dop(1000)));
+        assert_eq!(epoch.sat, None);
+        assert_eq!(collector.flush(), None);
+    }
+
+    #[test]
+    fn test_collect_on_itow_change() {
+        let mut collector = EpochCollector::new();
+        assert_eq!(collector.push(Nav::Pvt(pvt(1000))), None);
+        let epoch = collector.push(Nav::Dop(dop(2000))).unwrap();
+        assert_eq!(epoch.itow, 1000);
+        assert_eq!(epoch.dop, None);
+
+        let epoch = collector.push(Nav::Eoe(Eoe { iTOW: 3000 })).unwrap();
+        assert_eq!(epoch.itow, 2000);
+        assert_eq!(epoch.dop, Some(dop(2000)));
+        assert_eq!(collector.flush(), None);
+    }
+}
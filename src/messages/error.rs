@@ -0,0 +1,104 @@
+use crate::framing::FrameError;
+use core::fmt;
+
+/// The error type returned by message parsers such as
+/// [`Msg::from_frame()`].
+///
+/// [`Msg::from_frame()`]: enum.Msg.html#method.from_frame
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The frame's class and ID do not correspond to any known message.
+    UnknownMessage {
+        /// Frame's message class.
+        class: u8,
+        /// Frame's message ID.
+        id: u8,
+    },
+
+    /// The frame's class and ID correspond to a known message, but
+    /// its payload length is not one the message can have.
+    ///
+    /// This is usually the result of a truncated frame, or a newer
+    /// firmware version extending a message.
+    UnexpectedLength {
+        /// Frame's message class.
+        class: u8,
+        /// Frame's message ID.
+        id: u8,
+        /// Frame's payload length.
+        len: usize,
+    },
+
+    /// The frame's payload could not be deserialized.
+    InvalidPayload {
+        /// Frame's message class.
+        class: u8,
+        /// Frame's message ID.
+        id: u8,
+    },
+
+    /// The input did not contain a complete frame.
+    Incomplete,
+
+    /// The input contained a malformed frame.
+    Frame(FrameError),
+}
+
+impl From<FrameError> for ParseError {
+    fn from(err: FrameError) -> Self {
+        ParseError::Frame(err)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseError::UnknownMessage { class, id } => {
+                write!(f, "unknown message {:#04x}:{:#04x}", class, id)
+            }
+            ParseError::UnexpectedLength { class, id, len } => write!(
+                f,
+                "unexpected payload length {} for message {:#04x}:{:#04x}",
+                len, class, id
+            ),
+            ParseError::InvalidPayload { class, id } => {
+                write!(f, "invalid payload for message {:#04x}:{:#04x}", class, id)
+            }
+            ParseError::Incomplete => f.write_str("incomplete frame"),
+            ParseError::Frame(err) => write!(f, "malformed frame: {}", err),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Frame(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            ParseError::UnexpectedLength {
+                class: 0x01,
+                id: 0x07,
+                len: 80
+            }
+            .to_string(),
+            "unexpected payload length 80 for message 0x01:0x07"
+        );
+        assert_eq!(
+            ParseError::from(FrameError::Checksum).to_string(),
+            "malformed frame: checksum mismatch"
+        );
+    }
+}
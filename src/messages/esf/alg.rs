@@ -0,0 +1,169 @@
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// IMU alignment information.
+///
+/// Reports the status of the automatic IMU-mount alignment and the
+/// IMU-mount roll, pitch and yaw angles.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EsfAlg {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x01 for this version).
+    pub version: U1,
+
+    /// Alignment status flags.
+    pub flags: EsfAlgFlags,
+
+    /// Alignment error flags.
+    pub error: EsfAlgError,
+
+    /// IMU-mount yaw angle (range 0 to 360).
+    ///
+    /// ### Unit
+    /// 1e-2 degree
+    pub yaw: U4,
+
+    /// IMU-mount pitch angle (range -90 to 90).
+    ///
+    /// ### Unit
+    /// 1e-2 degree
+    pub pitch: I2,
+
+    /// IMU-mount roll angle (range -180 to 180).
+    ///
+    /// ### Unit
+    /// 1e-2 degree
+    pub roll: I2,
+}
+
+impl EsfAlg {
+    /// Returns IMU-mount yaw angle in degrees.
+    pub fn yaw_deg(&self) -> f64 {
+        f64::from(self.yaw) * 1e-2
+    }
+
+    /// Returns IMU-mount pitch angle in degrees.
+    pub fn pitch_deg(&self) -> f64 {
+        f64::from(self.pitch) * 1e-2
+    }
+
+    /// Returns IMU-mount roll angle in degrees.
+    pub fn roll_deg(&self) -> f64 {
+        f64::from(self.roll) * 1e-2
+    }
+}
+
+impl Message for EsfAlg {
+    const CLASS: u8 = 0x10;
+    const ID: u8 = 0x14;
+    const LEN: usize = 16;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        dst.put_u8(self.flags.0);
+        dst.put_u8(self.error.0);
+        // reserved1
+        dst.put_u8(0);
+        dst.put_u32_le(self.yaw);
+        dst.put_i16_le(self.pitch);
+        dst.put_i16_le(self.roll);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        let flags = EsfAlgFlags(src.get_u8());
+        let error = EsfAlgError(src.get_u8());
+        // reserved1
+        src.advance(1);
+        let yaw = src.get_u32_le();
+        let pitch = src.get_i16_le();
+        let roll = src.get_i16_le();
+
+        Ok(EsfAlg {
+            iTOW,
+            version,
+            flags,
+            error,
+            yaw,
+            pitch,
+            roll,
+        })
+    }
+}
+
+bitfield! {
+    /// Bitfield `flags`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct EsfAlgFlags(X1);
+    impl Debug;
+    u8;
+    /// Status of the IMU-mount alignment
+    ///
+    /// - 0 user-defined/fixed angles are used
+    /// - 1 IMU-mount roll/pitch angles alignment is ongoing
+    /// - 2 IMU-mount roll/pitch/yaw angles alignment is ongoing
+    /// - 3 coarse IMU-mount alignment are used
+    /// - 4 fine IMU-mount alignment are used
+    pub status, _: 3, 1;
+    /// Automatic IMU-mount alignment on/off bit
+    pub autoMntAlgOn, _: 0;
+}
+
+bitfield! {
+    /// Bitfield `error`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct EsfAlgError(X1);
+    impl Debug;
+    /// IMU-mount misalignment Euler angle singularity error
+    pub angleError, _: 2;
+    /// IMU-mount yaw alignment error
+    pub yawAlgError, _: 1;
+    /// IMU-mount tilt (roll and/or pitch) alignment error
+    pub tiltAlgError, _: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse() {
+        let bytes = [
+            0x10, 0x27, 0x00, 0x00, 0x01, 0x09, 0x02, 0x00, 0xa0, 0x8c, 0x00, 0x00, 0x0c, 0xfe,
+            0xe8, 0x03,
+        ];
+        let msg = EsfAlg::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 10_000);
+        assert_eq!(msg.version, 1);
+        assert!(msg.flags.autoMntAlgOn());
+        assert_eq!(msg.flags.status(), 4);
+        assert!(!msg.error.tiltAlgError());
+        assert!(msg.error.yawAlgError());
+        assert!(!msg.error.angleError());
+        assert_eq!(msg.yaw_deg(), 360.0);
+        assert_eq!(msg.pitch_deg(), -5.0);
+        assert_eq!(msg.roll_deg(), 10.0);
+
+        let mut buf = [0_u8; EsfAlg::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+}
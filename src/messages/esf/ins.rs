@@ -0,0 +1,186 @@
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// Vehicle dynamics information.
+///
+/// Reports the compensated angular rates and accelerations of the
+/// vehicle, i.e. with sensor biases and gravity removed, in the
+/// vehicle frame. Each value is only meaningful if its validity bit
+/// in `bitfield0` is set.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EsfIns {
+    /// Message version and validity flags.
+    pub bitfield0: EsfInsBitfield0,
+
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Compensated x-axis angular rate.
+    ///
+    /// ### Unit
+    /// 1e-3 degree/second
+    pub xAngRate: I4,
+
+    /// Compensated y-axis angular rate.
+    ///
+    /// ### Unit
+    /// 1e-3 degree/second
+    pub yAngRate: I4,
+
+    /// Compensated z-axis angular rate.
+    ///
+    /// ### Unit
+    /// 1e-3 degree/second
+    pub zAngRate: I4,
+
+    /// Compensated x-axis acceleration (gravity-free).
+    ///
+    /// ### Unit
+    /// 1e-2 meter/second^2
+    pub xAccel: I4,
+
+    /// Compensated y-axis acceleration (gravity-free).
+    ///
+    /// ### Unit
+    /// 1e-2 meter/second^2
+    pub yAccel: I4,
+
+    /// Compensated z-axis acceleration (gravity-free).
+    ///
+    /// ### Unit
+    /// 1e-2 meter/second^2
+    pub zAccel: I4,
+}
+
+impl EsfIns {
+    /// Returns compensated angular rates, `(x, y, z)`, in degrees per
+    /// second.
+    pub fn ang_rate_dps(&self) -> (f64, f64, f64) {
+        (
+            f64::from(self.xAngRate) * 1e-3,
+            f64::from(self.yAngRate) * 1e-3,
+            f64::from(self.zAngRate) * 1e-3,
+        )
+    }
+
+    /// Returns compensated accelerations, `(x, y, z)`, in meters per
+    /// second squared.
+    pub fn accel_mps2(&self) -> (f64, f64, f64) {
+        (
+            f64::from(self.xAccel) * 1e-2,
+            f64::from(self.yAccel) * 1e-2,
+            f64::from(self.zAccel) * 1e-2,
+        )
+    }
+}
+
+impl Message for EsfIns {
+    const CLASS: u8 = 0x10;
+    const ID: u8 = 0x15;
+    const LEN: usize = 36;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        dst.put_u32_le(self.bitfield0.0);
+        // reserved1
+        dst.put_u32_le(0);
+        dst.put_u32_le(self.iTOW);
+        dst.put_i32_le(self.xAngRate);
+        dst.put_i32_le(self.yAngRate);
+        dst.put_i32_le(self.zAngRate);
+        dst.put_i32_le(self.xAccel);
+        dst.put_i32_le(self.yAccel);
+        dst.put_i32_le(self.zAccel);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let bitfield0 = EsfInsBitfield0(src.get_u32_le());
+        // reserved1
+        src.advance(4);
+        let iTOW = src.get_u32_le();
+        let xAngRate = src.get_i32_le();
+        let yAngRate = src.get_i32_le();
+        let zAngRate = src.get_i32_le();
+        let xAccel = src.get_i32_le();
+        let yAccel = src.get_i32_le();
+        let zAccel = src.get_i32_le();
+
+        Ok(EsfIns {
+            bitfield0,
+            iTOW,
+            xAngRate,
+            yAngRate,
+            zAngRate,
+            xAccel,
+            yAccel,
+            zAccel,
+        })
+    }
+}
+
+bitfield! {
+    /// Bitfield `bitfield0`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct EsfInsBitfield0(X4);
+    impl Debug;
+    u8;
+    /// Compensated z-axis acceleration data valid
+    pub zAccelValid, _: 13;
+    /// Compensated y-axis acceleration data valid
+    pub yAccelValid, _: 12;
+    /// Compensated x-axis acceleration data valid
+    pub xAccelValid, _: 11;
+    /// Compensated z-axis angular rate data valid
+    pub zAngRateValid, _: 10;
+    /// Compensated y-axis angular rate data valid
+    pub yAngRateValid, _: 9;
+    /// Compensated x-axis angular rate data valid
+    pub xAngRateValid, _: 8;
+    /// Message version (0x01 for this version)
+    pub version, _: 7, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse() {
+        let mut bytes = [0_u8; EsfIns::LEN];
+        bytes[..4].copy_from_slice(&0x0000_3901_u32.to_le_bytes());
+        bytes[8..12].copy_from_slice(&10_000_u32.to_le_bytes());
+        bytes[12..16].copy_from_slice(&1_500_i32.to_le_bytes());
+        bytes[20..24].copy_from_slice(&(-250_i32).to_le_bytes());
+        bytes[24..28].copy_from_slice(&981_i32.to_le_bytes());
+        bytes[32..36].copy_from_slice(&(-5_i32).to_le_bytes());
+
+        let msg = EsfIns::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.bitfield0.version(), 1);
+        assert!(msg.bitfield0.xAngRateValid());
+        assert!(!msg.bitfield0.yAngRateValid());
+        assert!(!msg.bitfield0.zAngRateValid());
+        assert!(msg.bitfield0.xAccelValid());
+        assert!(msg.bitfield0.yAccelValid());
+        assert!(msg.bitfield0.zAccelValid());
+        assert_eq!(msg.iTOW, 10_000);
+        assert_eq!(msg.ang_rate_dps(), (1.5, 0.0, -0.25));
+        assert_eq!(msg.accel_mps2(), (9.81, 0.0, -0.05));
+
+        let mut buf = [0xff_u8; EsfIns::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+}
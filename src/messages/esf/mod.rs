@@ -0,0 +1,71 @@
+//! External Sensor Fusion Messages: i.e. External Sensor Measurements
+//! and Status Information.
+//!
+//! Messages in the ESF class are used to output external sensor fusion
+//! information from the receiver.
+
+mod alg;
+mod ins;
+mod status;
+pub use self::alg::*;
+pub use self::ins::*;
+pub use self::status::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{
+    framed_vec, framed_vec_var, parse, parse_var, Message, ParseError, VarMessage,
+};
+
+/// External sensor fusion messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Esf {
+    Status(EsfStatus),
+    Alg(EsfAlg),
+    Ins(EsfIns),
+}
+
+impl Esf {
+    /// ESF class.
+    pub const CLASS: u8 = 0x10;
+
+    /// Parses an external sensor fusion message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            EsfStatus::ID => parse_var(frame).map(Esf::Status),
+            EsfAlg::ID => parse(frame).map(Esf::Alg),
+            EsfIns::ID => parse(frame).map(Esf::Ins),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Esf::Status(msg) => framed_vec_var(msg),
+            Esf::Alg(msg) => framed_vec(msg),
+            Esf::Ins(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Esf::Status(msg) => msg.class_id(),
+            Esf::Alg(msg) => msg.class_id(),
+            Esf::Ins(msg) => msg.class_id(),
+        }
+    }
+}
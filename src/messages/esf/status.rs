@@ -0,0 +1,247 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// External sensor fusion status.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EsfStatus {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x02 for this version).
+    pub version: U1,
+
+    /// Fusion mode.
+    pub fusionMode: FusionMode,
+
+    /// Status of each sensor.
+    pub sensors: Vec<SensorStatus>,
+}
+
+/// Sensor fusion mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FusionMode {
+    /// Receiver is initializing some unknown values required for
+    /// doing sensor fusion.
+    Init,
+    /// GNSS and sensor data are used for navigation solution
+    /// computation.
+    Fusion,
+    /// Sensor fusion is temporarily disabled due to e.g. invalid
+    /// sensor data or detected ferry.
+    Suspended,
+    /// Sensor fusion is permanently disabled until receiver reset due
+    /// e.g. to sensor error.
+    Disabled,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for FusionMode {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => FusionMode::Init,
+            1 => FusionMode::Fusion,
+            2 => FusionMode::Suspended,
+            3 => FusionMode::Disabled,
+            other => FusionMode::Unknown(other),
+        }
+    }
+}
+
+impl From<FusionMode> for u8 {
+    fn from(mode: FusionMode) -> Self {
+        match mode {
+            FusionMode::Init => 0,
+            FusionMode::Fusion => 1,
+            FusionMode::Suspended => 2,
+            FusionMode::Disabled => 3,
+            FusionMode::Unknown(other) => other,
+        }
+    }
+}
+
+/// Status of a single sensor.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SensorStatus {
+    /// Sensor type and usage.
+    pub sensStatus1: SensStatus1,
+
+    /// Calibration and time tag status.
+    pub sensStatus2: SensStatus2,
+
+    /// Observation frequency.
+    ///
+    /// ### Unit
+    /// Hz
+    pub freq: U1,
+
+    /// Sensor faults.
+    pub faults: Faults,
+}
+
+bitfield! {
+    /// Sensor type and usage.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct SensStatus1(X1);
+    impl Debug;
+    u8;
+    /// Sensor data is available and ready for use.
+    pub ready, _: 7;
+    /// Sensor data is used for the current sensor fusion solution.
+    pub used, _: 6;
+    /// Sensor data type.
+    pub sensor_type, _: 5, 0;
+}
+
+bitfield! {
+    /// Calibration and time tag status.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct SensStatus2(X1);
+    impl Debug;
+    u8;
+    /// Time tag status.
+    ///
+    /// - 0 no data
+    /// - 1 reception of the first byte used to tag the measurement
+    /// - 2 event input used to tag the measurement
+    /// - 3 time tag provided with the data
+    pub time_status, _: 3, 2;
+    /// Calibration status.
+    ///
+    /// - 0 sensor not calibrated
+    /// - 1 sensor is calibrating
+    /// - 2, 3 sensor is calibrated
+    pub calib_status, _: 1, 0;
+}
+
+bitfield! {
+    /// Sensor faults.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Faults(X1);
+    impl Debug;
+    /// Measurements noisy
+    pub noisy_meas, _: 3;
+    /// Measurements missing or time misaligned
+    pub missing_meas, _: 2;
+    /// Bad measurement time tags
+    pub bad_ttag, _: 1;
+    /// Bad measurements
+    pub bad_meas, _: 0;
+}
+
+impl EsfStatus {
+    const LEN_HEADER: usize = 16;
+    const LEN_BLOCK: usize = 4;
+}
+
+impl VarMessage for EsfStatus {
+    const CLASS: u8 = 0x10;
+    const ID: u8 = 0x10;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.sensors.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let num_sens = U1::try_from(self.sensors.len()).map_err(|_| ())?;
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        // reserved1
+        dst.put_slice([0_u8; 7].as_ref());
+        dst.put_u8(self.fusionMode.into());
+        // reserved2
+        dst.put_u16_le(0);
+        dst.put_u8(num_sens);
+        for sensor in &self.sensors {
+            dst.put_u8(sensor.sensStatus1.0);
+            dst.put_u8(sensor.sensStatus2.0);
+            dst.put_u8(sensor.freq);
+            dst.put_u8(sensor.faults.0);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        // reserved1
+        src.advance(7);
+        let fusionMode = FusionMode::from(src.get_u8());
+        // reserved2
+        src.advance(2);
+        let num_sens = usize::from(src.get_u8());
+        if src.remaining() != num_sens * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let sensors = (0..num_sens)
+            .map(|_| SensorStatus {
+                sensStatus1: SensStatus1(src.get_u8()),
+                sensStatus2: SensStatus2(src.get_u8()),
+                freq: src.get_u8(),
+                faults: Faults(src.get_u8()),
+            })
+            .collect();
+
+        Ok(EsfStatus {
+            iTOW,
+            version,
+            fusionMode,
+            sensors,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_two_sensors() {
+        let bytes = [
+            0xe8, 0x03, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00,
+            0x00, 0x02, //
+            0xcb, 0x0a, 0x0a, 0x00, //
+            0x10, 0x01, 0x64, 0x08,
+        ];
+        let msg = EsfStatus::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 1000);
+        assert_eq!(msg.fusionMode, FusionMode::Fusion);
+        assert_eq!(msg.sensors.len(), 2);
+
+        let wt = msg.sensors[0];
+        assert_eq!(wt.sensStatus1.sensor_type(), 11);
+        assert!(wt.sensStatus1.used());
+        assert!(wt.sensStatus1.ready());
+        assert_eq!(wt.sensStatus2.calib_status(), 2);
+        assert_eq!(wt.sensStatus2.time_status(), 2);
+        assert_eq!(wt.freq, 10);
+
+        let gyro = msg.sensors[1];
+        assert_eq!(gyro.sensStatus1.sensor_type(), 16);
+        assert!(!gyro.sensStatus1.used());
+        assert_eq!(gyro.sensStatus2.calib_status(), 1);
+        assert_eq!(gyro.freq, 100);
+        assert!(gyro.faults.noisy_meas());
+
+        let mut buf = [0_u8; 24];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(EsfStatus::deserialize(&mut bytes[..20].as_ref()).is_err());
+    }
+}
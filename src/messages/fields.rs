@@ -0,0 +1,69 @@
+use crate::messages::primitive::*;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// A raw message field value, tagged with its u-blox number format.
+///
+/// Values are not scaled, e.g. a latitude is the [`I4`] as
+/// transmitted, in units of 1e-7 degree.
+#[allow(missing_docs)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldValue {
+    U1(U1),
+    I1(I1),
+    X1(X1),
+    U2(U2),
+    I2(I2),
+    X2(X2),
+    U4(U4),
+    I4(I4),
+    X4(X4),
+    R4(R4),
+    R8(R8),
+}
+
+impl fmt::Display for FieldValue {
+    /// Formats bitfields (`X1`, `X2`, `X4`) as zero-padded hex, and
+    /// all other values as decimal.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FieldValue::U1(v) => write!(f, "{}", v),
+            FieldValue::I1(v) => write!(f, "{}", v),
+            FieldValue::X1(v) => write!(f, "{:#04x}", v),
+            FieldValue::U2(v) => write!(f, "{}", v),
+            FieldValue::I2(v) => write!(f, "{}", v),
+            FieldValue::X2(v) => write!(f, "{:#06x}", v),
+            FieldValue::U4(v) => write!(f, "{}", v),
+            FieldValue::I4(v) => write!(f, "{}", v),
+            FieldValue::X4(v) => write!(f, "{:#010x}", v),
+            FieldValue::R4(v) => write!(f, "{}", v),
+            FieldValue::R8(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+/// Exposes a parsed message's fields by name, e.g. for generic
+/// tabular output.
+///
+/// Unlike `{:#?}` output, bitfields are yielded as their raw value
+/// and reserved fields are omitted.
+pub trait DecodeFields {
+    /// Returns `(name, value)` pairs for `self`'s fields, in payload
+    /// order. Names match the u-blox protocol specification.
+    fn fields(&self) -> Vec<(&'static str, FieldValue)>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(FieldValue::I4(-740_060_000).to_string(), "-740060000");
+        assert_eq!(FieldValue::X1(0x07).to_string(), "0x07");
+        assert_eq!(FieldValue::X2(0x1c4c).to_string(), "0x1c4c");
+        assert_eq!(FieldValue::X4(0x40).to_string(), "0x00000040");
+        assert_eq!(FieldValue::R4(1.5).to_string(), "1.5");
+    }
+}
@@ -0,0 +1,58 @@
+//! High Rate Navigation Results Messages: i.e. High rate time,
+//! position, speed, heading.
+//!
+//! Messages in the HNR class are output by ADR and UDR receivers at
+//! the rate configured with CFG-HNR, which may be higher than the
+//! rate of the NAV class messages.
+
+mod pvt;
+pub use self::pvt::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec, parse, Message, ParseError};
+
+/// High rate navigation messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Hnr {
+    Pvt(HnrPvt),
+}
+
+impl Hnr {
+    /// HNR class.
+    pub const CLASS: u8 = 0x28;
+
+    /// Parses a high rate navigation message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            HnrPvt::ID => parse(frame).map(Hnr::Pvt),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Hnr::Pvt(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Hnr::Pvt(msg) => msg.class_id(),
+        }
+    }
+}
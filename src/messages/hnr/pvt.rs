@@ -0,0 +1,329 @@
+use crate::messages::{nav::FixType, primitive::*, Message};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// High rate output of PVT solution.
+///
+/// Like NAV-PVT, but output at the rate configured with CFG-HNR and
+/// without the DOP and satellite count fields.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HnrPvt {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Year (UTC).
+    pub year: U2,
+
+    /// Month, range 1..12 (UTC).
+    pub month: U1,
+
+    /// Day of month, range 1..31 (UTC).
+    pub day: U1,
+
+    /// Hour of day, range 0..23 (UTC).
+    pub hour: U1,
+
+    /// Minute of hour, range 0..59 (UTC).
+    pub min: U1,
+
+    /// Seconds of minute, range 0..60 (UTC).
+    pub sec: U1,
+
+    /// Validity flags.
+    pub valid: HnrPvtValid,
+
+    /// Fraction of second, range -1e9..1e9 (UTC).
+    ///
+    /// ### Unit
+    /// nanosecond
+    pub nano: I4,
+
+    /// GNSS fix type, see [`FixType`].
+    pub gpsFix: U1,
+
+    /// Fix status flags.
+    pub flags: HnrPvtFlags,
+
+    /// Longitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lon: I4,
+
+    /// Latitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lat: I4,
+
+    /// Height above ellipsoid.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub height: I4,
+
+    /// Height above mean sea level.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub hMSL: I4,
+
+    /// Ground speed (2-D).
+    ///
+    /// ### Unit
+    /// millimeter/second
+    pub gSpeed: I4,
+
+    /// Speed (3-D).
+    ///
+    /// ### Unit
+    /// millimeter/second
+    pub speed: I4,
+
+    /// Heading of motion (2-D).
+    ///
+    /// ### Unit
+    /// 1e-5 degree
+    pub headMot: I4,
+
+    /// Heading of vehicle (2-D).
+    ///
+    /// ### Unit
+    /// 1e-5 degree
+    pub headVeh: I4,
+
+    /// Horizontal accuracy.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub hAcc: U4,
+
+    /// Vertical accuracy.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub vAcc: U4,
+
+    /// Speed accuracy.
+    ///
+    /// ### Unit
+    /// millimeter/second
+    pub sAcc: U4,
+
+    /// Heading accuracy.
+    ///
+    /// ### Unit
+    /// 1e-5 degree
+    pub headAcc: U4,
+}
+
+bitfield! {
+    /// Bitfield `valid`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct HnrPvtValid(X1);
+    impl Debug;
+    /// UTC time of day has been fully resolved (no seconds
+    /// uncertainty)
+    pub fullyResolved, _: 2;
+    /// Valid UTC time of day
+    pub validTime, _: 1;
+    /// Valid UTC date
+    pub validDate, _: 0;
+}
+
+bitfield! {
+    /// Bitfield `flags`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct HnrPvtFlags(X1);
+    impl Debug;
+    /// Heading of vehicle is valid
+    pub headVehValid, _: 4;
+    /// Valid GPS time of week (iTOW)
+    pub TOWSET, _: 3;
+    /// Valid GPS week number
+    pub WKNSET, _: 2;
+    /// Differential corrections were applied
+    pub DiffSoln, _: 1;
+    /// Fix within limits, e.g. DOP and accuracy
+    pub GPSfixOK, _: 0;
+}
+
+impl HnrPvt {
+    /// Returns GNSS fix type.
+    pub fn fix_type(&self) -> FixType {
+        FixType::from(self.gpsFix)
+    }
+
+    /// Returns latitude.
+    pub fn lat(&self) -> Deg1e7 {
+        Deg1e7(self.lat)
+    }
+
+    /// Returns longitude.
+    pub fn lon(&self) -> Deg1e7 {
+        Deg1e7(self.lon)
+    }
+
+    /// Returns ground speed (2-D) in meters per second.
+    pub fn ground_speed_mps(&self) -> f64 {
+        f64::from(self.gSpeed) * 1e-3
+    }
+
+    /// Returns heading of motion (2-D) in degrees.
+    pub fn heading_of_motion_deg(&self) -> f64 {
+        Deg1e5(self.headMot).as_f64()
+    }
+
+    /// Returns heading of vehicle (2-D) in degrees, if valid.
+    pub fn heading_of_vehicle_deg(&self) -> Option<f64> {
+        if self.flags.headVehValid() {
+            Some(Deg1e5(self.headVeh).as_f64())
+        } else {
+            None
+        }
+    }
+}
+
+impl Message for HnrPvt {
+    const CLASS: u8 = 0x28;
+    const ID: u8 = 0x00;
+    const LEN: usize = 72;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u16_le(self.year);
+        dst.put_u8(self.month);
+        dst.put_u8(self.day);
+        dst.put_u8(self.hour);
+        dst.put_u8(self.min);
+        dst.put_u8(self.sec);
+        dst.put_u8(self.valid.0);
+        dst.put_i32_le(self.nano);
+        dst.put_u8(self.gpsFix);
+        dst.put_u8(self.flags.0);
+        // reserved1
+        dst.put_u16_le(0);
+        dst.put_i32_le(self.lon);
+        dst.put_i32_le(self.lat);
+        dst.put_i32_le(self.height);
+        dst.put_i32_le(self.hMSL);
+        dst.put_i32_le(self.gSpeed);
+        dst.put_i32_le(self.speed);
+        dst.put_i32_le(self.headMot);
+        dst.put_i32_le(self.headVeh);
+        dst.put_u32_le(self.hAcc);
+        dst.put_u32_le(self.vAcc);
+        dst.put_u32_le(self.sAcc);
+        dst.put_u32_le(self.headAcc);
+        // reserved2
+        dst.put_u32_le(0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let year = src.get_u16_le();
+        let month = src.get_u8();
+        let day = src.get_u8();
+        let hour = src.get_u8();
+        let min = src.get_u8();
+        let sec = src.get_u8();
+        let valid = HnrPvtValid(src.get_u8());
+        let nano = src.get_i32_le();
+        let gpsFix = src.get_u8();
+        let flags = HnrPvtFlags(src.get_u8());
+        // reserved1
+        src.advance(2);
+        let lon = src.get_i32_le();
+        let lat = src.get_i32_le();
+        let height = src.get_i32_le();
+        let hMSL = src.get_i32_le();
+        let gSpeed = src.get_i32_le();
+        let speed = src.get_i32_le();
+        let headMot = src.get_i32_le();
+        let headVeh = src.get_i32_le();
+        let hAcc = src.get_u32_le();
+        let vAcc = src.get_u32_le();
+        let sAcc = src.get_u32_le();
+        let headAcc = src.get_u32_le();
+        // reserved2
+        src.advance(4);
+
+        Ok(HnrPvt {
+            iTOW,
+            year,
+            month,
+            day,
+            hour,
+            min,
+            sec,
+            valid,
+            nano,
+            gpsFix,
+            flags,
+            lon,
+            lat,
+            height,
+            hMSL,
+            gSpeed,
+            speed,
+            headMot,
+            headVeh,
+            hAcc,
+            vAcc,
+            sAcc,
+            headAcc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse() {
+        let mut bytes = [0_u8; HnrPvt::LEN];
+        bytes[..4].copy_from_slice(&403_200_040_u32.to_le_bytes());
+        bytes[4..6].copy_from_slice(&2020_u16.to_le_bytes());
+        bytes[6..12].copy_from_slice(&[4, 9, 15, 59, 42, 0x07]);
+        bytes[16] = 4;
+        bytes[17] = 0x1d;
+        bytes[20..24].copy_from_slice(&(-740_060_000_i32).to_le_bytes());
+        bytes[24..28].copy_from_slice(&407_128_000_i32.to_le_bytes());
+        bytes[36..40].copy_from_slice(&12_500_i32.to_le_bytes());
+        bytes[44..48].copy_from_slice(&9_000_000_i32.to_le_bytes());
+        bytes[48..52].copy_from_slice(&9_050_000_i32.to_le_bytes());
+
+        let msg = HnrPvt::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 403_200_040);
+        assert_eq!((msg.year, msg.month, msg.day), (2020, 4, 9));
+        assert_eq!((msg.hour, msg.min, msg.sec), (15, 59, 42));
+        assert!(msg.valid.validDate() && msg.valid.validTime() && msg.valid.fullyResolved());
+        assert_eq!(msg.fix_type(), FixType::GnssDeadReckoning);
+        assert!(msg.flags.GPSfixOK());
+        assert!(msg.flags.TOWSET());
+        assert!(!msg.flags.DiffSoln());
+        assert_eq!(msg.lat().as_f64(), 40.7128);
+        assert_eq!(msg.lon().as_f64(), -74.006);
+        assert_eq!(msg.ground_speed_mps(), 12.5);
+        assert_eq!(msg.heading_of_motion_deg(), 90.0);
+        assert_eq!(msg.heading_of_vehicle_deg(), Some(90.5));
+
+        let mut buf = [0xff_u8; HnrPvt::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+}
@@ -0,0 +1,56 @@
+//! Logging Messages: i.e. Log creation, deletion, info and retrieval.
+//!
+//! Messages in the LOG class are used to configure and report status
+//! information of the logging feature.
+
+mod retrievepos;
+pub use self::retrievepos::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec, parse, Message, ParseError};
+
+/// Logging messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Log {
+    RetrievePos(LogRetrievePos),
+}
+
+impl Log {
+    /// LOG class.
+    pub const CLASS: u8 = 0x21;
+
+    /// Parses a logging message from a borrowed frame, e.g. a
+    /// `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            LogRetrievePos::ID => parse(frame).map(Log::RetrievePos),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Log::RetrievePos(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Log::RetrievePos(msg) => msg.class_id(),
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::messages::{nav::FixType, primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Position fix log entry.
+///
+/// Sent by the receiver in response to a log retrieval request, one
+/// message per recorded position fix.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LogRetrievePos {
+    /// Index of this log entry.
+    pub entryIndex: U4,
+
+    /// Longitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lon: I4,
+
+    /// Latitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lat: I4,
+
+    /// Height above mean sea level.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub hMSL: I4,
+
+    /// Horizontal accuracy estimate.
+    ///
+    /// ### Unit
+    /// millimeter
+    pub hAcc: U4,
+
+    /// Ground speed (2-D).
+    ///
+    /// ### Unit
+    /// millimeter/second
+    pub gSpeed: U4,
+
+    /// Heading.
+    ///
+    /// ### Unit
+    /// 1e-5 degree
+    pub heading: U4,
+
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// Fix type, see [`FixType`].
+    pub fixType: U1,
+
+    /// Year (UTC).
+    pub year: U2,
+
+    /// Month, range 1..12 (UTC).
+    pub month: U1,
+
+    /// Day of month, range 1..31 (UTC).
+    pub day: U1,
+
+    /// Hour of day, range 0..23 (UTC).
+    pub hour: U1,
+
+    /// Minute of hour, range 0..59 (UTC).
+    pub minute: U1,
+
+    /// Seconds of minute, range 0..60 (UTC).
+    pub second: U1,
+
+    /// Number of satellites used in the position fix.
+    pub numSV: U1,
+}
+
+impl LogRetrievePos {
+    /// Returns fix type.
+    pub fn fix_type(&self) -> FixType {
+        FixType::from(self.fixType)
+    }
+
+    /// Returns latitude.
+    pub fn lat(&self) -> Deg1e7 {
+        Deg1e7(self.lat)
+    }
+
+    /// Returns longitude.
+    pub fn lon(&self) -> Deg1e7 {
+        Deg1e7(self.lon)
+    }
+
+    /// Returns height above mean sea level in meters.
+    pub fn height_msl_m(&self) -> f64 {
+        f64::from(self.hMSL) * 1e-3
+    }
+
+    /// Returns horizontal accuracy estimate in meters.
+    pub fn h_acc_m(&self) -> f64 {
+        f64::from(self.hAcc) * 1e-3
+    }
+
+    /// Returns ground speed (2-D) in meters per second.
+    pub fn ground_speed_mps(&self) -> f64 {
+        f64::from(self.gSpeed) * 1e-3
+    }
+
+    /// Returns heading in degrees.
+    pub fn heading_deg(&self) -> f64 {
+        f64::from(self.heading) / Deg1e5::DIVISOR
+    }
+}
+
+impl Message for LogRetrievePos {
+    const CLASS: u8 = 0x21;
+    const ID: u8 = 0x0B;
+    const LEN: usize = 40;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        dst.put_u32_le(self.entryIndex);
+        dst.put_i32_le(self.lon);
+        dst.put_i32_le(self.lat);
+        dst.put_i32_le(self.hMSL);
+        dst.put_u32_le(self.hAcc);
+        dst.put_u32_le(self.gSpeed);
+        dst.put_u32_le(self.heading);
+        dst.put_u8(self.version);
+        dst.put_u8(self.fixType);
+        dst.put_u16_le(self.year);
+        dst.put_u8(self.month);
+        dst.put_u8(self.day);
+        dst.put_u8(self.hour);
+        dst.put_u8(self.minute);
+        dst.put_u8(self.second);
+        // reserved1
+        dst.put_u8(0);
+        dst.put_u8(self.numSV);
+        // reserved2
+        dst.put_u8(0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let entryIndex = src.get_u32_le();
+        let lon = src.get_i32_le();
+        let lat = src.get_i32_le();
+        let hMSL = src.get_i32_le();
+        let hAcc = src.get_u32_le();
+        let gSpeed = src.get_u32_le();
+        let heading = src.get_u32_le();
+        let version = src.get_u8();
+        let fixType = src.get_u8();
+        let year = src.get_u16_le();
+        let month = src.get_u8();
+        let day = src.get_u8();
+        let hour = src.get_u8();
+        let minute = src.get_u8();
+        let second = src.get_u8();
+        // reserved1
+        src.advance(1);
+        let numSV = src.get_u8();
+        // reserved2
+        src.advance(1);
+
+        Ok(LogRetrievePos {
+            entryIndex,
+            lon,
+            lat,
+            hMSL,
+            hAcc,
+            gSpeed,
+            heading,
+            version,
+            fixType,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            numSV,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse() {
+        let bytes = [
+            0x07, 0x00, 0x00, 0x00, // entryIndex
+            0xa0, 0x94, 0xe3, 0xd3, // lon
+            0xc0, 0x47, 0x44, 0x18, // lat
+            0x10, 0x27, 0x00, 0x00, // hMSL
+            0xb8, 0x0b, 0x00, 0x00, // hAcc
+            0xdc, 0x05, 0x00, 0x00, // gSpeed
+            0x40, 0x4b, 0x4c, 0x00, // heading
+            0x00, 0x03, 0xe4, 0x07, 0x04, 0x11, 0x09, 0x1e, 0x0f, 0x00, 0x0c, 0x00,
+        ];
+        let msg = LogRetrievePos::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.entryIndex, 7);
+        assert_eq!(msg.lat().as_f64(), 40.7128);
+        assert_eq!(msg.lon().as_f64(), -74.006);
+        assert_eq!(msg.height_msl_m(), 10.0);
+        assert_eq!(msg.h_acc_m(), 3.0);
+        assert_eq!(msg.ground_speed_mps(), 1.5);
+        assert_eq!(msg.heading_deg(), 50.0);
+        assert_eq!(msg.fix_type(), FixType::Fix3D);
+        assert_eq!(
+            (msg.year, msg.month, msg.day, msg.hour, msg.minute, msg.second),
+            (2020, 4, 17, 9, 30, 15)
+        );
+        assert_eq!(msg.numSV, 12);
+
+        let mut buf = [0_u8; LogRetrievePos::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+}
@@ -0,0 +1,66 @@
+//! Multiple GNSS Assistance Messages: i.e. Assistance data for various
+//! GNSS.
+//!
+//! Messages in the MGA class are used for GNSS aiding information
+//! from and to the receiver.
+
+mod pos;
+mod time;
+pub use self::pos::*;
+pub use self::time::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec, parse, Message, ParseError};
+
+/// Multiple GNSS assistance messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mga {
+    IniTimeUtc(MgaIniTimeUtc),
+    IniPosLlh(MgaIniPosLlh),
+}
+
+impl Mga {
+    /// MGA class.
+    pub const CLASS: u8 = 0x13;
+
+    /// Parses an assistance message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    ///
+    /// MGA-INI messages share a message ID and are distinguished by
+    /// the first payload byte.
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match (frame.id, frame.message.first().copied()) {
+            (MgaIniTimeUtc::ID, Some(MgaIniTimeUtc::TYPE)) => parse(frame).map(Mga::IniTimeUtc),
+            (MgaIniPosLlh::ID, Some(MgaIniPosLlh::TYPE)) => parse(frame).map(Mga::IniPosLlh),
+            (id, _) => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Mga::IniTimeUtc(msg) => framed_vec(msg),
+            Mga::IniPosLlh(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Mga::IniTimeUtc(msg) => msg.class_id(),
+            Mga::IniPosLlh(msg) => msg.class_id(),
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Initial position assistance in WGS84 latitude, longitude, and
+/// altitude.
+///
+/// Supplying position assistance reduces the time to first fix of a
+/// cold start. The message type (`0x01`) and version (`0x00`) are
+/// implied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MgaIniPosLlh {
+    /// WGS84 Latitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lat: I4,
+
+    /// WGS84 Longitude.
+    ///
+    /// ### Unit
+    /// 1e-7 degree
+    pub lon: I4,
+
+    /// WGS84 Altitude.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub alt: I4,
+
+    /// Position accuracy (stddev).
+    ///
+    /// ### Unit
+    /// centimeter
+    pub posAcc: U4,
+}
+
+impl MgaIniPosLlh {
+    /// Message type identifying MGA-INI-POS_LLH among MGA-INI
+    /// messages.
+    pub const TYPE: U1 = 0x01;
+
+    /// Returns position assistance for latitude and longitude in
+    /// degrees, and altitude and accuracy in meters.
+    pub fn new(lat_deg: f64, lon_deg: f64, alt_m: f64, pos_acc_m: f64) -> Self {
+        MgaIniPosLlh {
+            lat: Deg1e7::from_f64(lat_deg).0,
+            lon: Deg1e7::from_f64(lon_deg).0,
+            alt: cm(alt_m) as I4,
+            posAcc: cm(pos_acc_m) as U4,
+        }
+    }
+
+    /// Returns latitude.
+    pub fn lat(&self) -> Deg1e7 {
+        Deg1e7(self.lat)
+    }
+
+    /// Returns longitude.
+    pub fn lon(&self) -> Deg1e7 {
+        Deg1e7(self.lon)
+    }
+}
+
+/// Returns `m` meters in centimeters, rounded to the nearest
+/// centimeter.
+fn cm(m: f64) -> f64 {
+    m * 100.0 + if m < 0.0 { -0.5 } else { 0.5 }
+}
+
+impl Message for MgaIniPosLlh {
+    const CLASS: u8 = 0x13;
+    const ID: u8 = 0x40;
+    const LEN: usize = 20;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &MgaIniPosLlh {
+            lat,
+            lon,
+            alt,
+            posAcc,
+        } = self;
+
+        dst.put_u8(Self::TYPE);
+        // version
+        dst.put_u8(0);
+        // reserved1
+        dst.put_u16_le(0);
+        dst.put_i32_le(lat);
+        dst.put_i32_le(lon);
+        dst.put_i32_le(alt);
+        dst.put_u32_le(posAcc);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        if src.get_u8() != Self::TYPE {
+            return Err(());
+        }
+        // version, reserved1
+        src.advance(3);
+        let lat = src.get_i32_le();
+        let lon = src.get_i32_le();
+        let alt = src.get_i32_le();
+        let posAcc = src.get_u32_le();
+
+        Ok(MgaIniPosLlh {
+            lat,
+            lon,
+            alt,
+            posAcc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_encode() {
+        let msg = MgaIniPosLlh::new(40.7128, -74.006, 10.25, 100.0);
+        assert_eq!(msg.lat, 407_128_000);
+        assert_eq!(msg.lon, -740_060_000);
+        assert_eq!(msg.alt, 1_025);
+        assert_eq!(msg.posAcc, 10_000);
+
+        let mut buf = [0_u8; MgaIniPosLlh::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(&buf[..4], &[0x01, 0x00, 0x00, 0x00]);
+        assert_eq!(&buf[4..8], &407_128_000_i32.to_le_bytes());
+        assert_eq!(msg, MgaIniPosLlh::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
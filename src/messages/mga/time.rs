@@ -0,0 +1,277 @@
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// Initial time assistance in UTC.
+///
+/// Supplying time assistance reduces the time to first fix of a cold
+/// start. The message type (`0x10`) and version (`0x00`) are implied.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MgaIniTimeUtc {
+    /// Reference to be used to set time.
+    pub timeRef: TimeRef,
+
+    /// Number of leap seconds since 1980 (or -128 if not known).
+    ///
+    /// ### Unit
+    /// second
+    pub leapSecs: I1,
+
+    /// Year.
+    pub year: U2,
+
+    /// Month, starting at 1.
+    pub month: U1,
+
+    /// Day, starting at 1.
+    pub day: U1,
+
+    /// Hour, from 0 to 23.
+    pub hour: U1,
+
+    /// Minute, from 0 to 59.
+    pub minute: U1,
+
+    /// Seconds, from 0 to 59.
+    pub second: U1,
+
+    /// Nanoseconds, from 0 to 999,999,999.
+    ///
+    /// ### Unit
+    /// nanosecond
+    pub ns: U4,
+
+    /// Seconds part of time accuracy.
+    ///
+    /// ### Unit
+    /// second
+    pub tAccS: U2,
+
+    /// Nanoseconds part of time accuracy, from 0 to 999,999,999.
+    ///
+    /// ### Unit
+    /// nanosecond
+    pub tAccNs: U4,
+}
+
+bitfield! {
+    /// Time reference of MGA-INI-TIME messages.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct TimeRef(X1);
+    impl Debug;
+    u8;
+    /// Use last external time pulse edge instead of the next one.
+    pub last, set_last: 5;
+    /// Use falling edge of external time pulse instead of rising edge.
+    pub fall, set_fall: 4;
+    /// Time reference source.
+    ///
+    /// - 0 none, i.e. on receipt of message (will be inaccurate!)
+    /// - 1 relative to pulse sent to EXTINT0
+    /// - 2 relative to pulse sent to EXTINT1
+    pub source, set_source: 3, 0;
+}
+
+impl MgaIniTimeUtc {
+    /// Message type identifying MGA-INI-TIME_UTC among MGA-INI
+    /// messages.
+    pub const TYPE: U1 = 0x10;
+
+    /// Returns time assistance for `dt`, referenced to receipt of the
+    /// message, with accuracy `t_acc`, or `None` if `dt`'s year is out
+    /// of range.
+    ///
+    /// The number of leap seconds is marked as unknown, but a `dt`
+    /// during a leap second is sent as second 60.
+    #[cfg(feature = "chrono")]
+    pub fn from_datetime(
+        dt: &chrono::DateTime<chrono::Utc>,
+        t_acc: core::time::Duration,
+    ) -> Option<Self> {
+        use chrono::{Datelike, Timelike};
+        use core::convert::TryFrom;
+        // chrono represents a leap second as second 59 with a
+        // nanosecond overflow.
+        let (second, ns) = match dt.nanosecond() {
+            ns if ns >= 1_000_000_000 => (60, ns - 1_000_000_000),
+            ns => (dt.second() as U1, ns),
+        };
+        Some(MgaIniTimeUtc {
+            timeRef: TimeRef(0),
+            leapSecs: -128,
+            year: U2::try_from(dt.year()).ok()?,
+            month: dt.month() as U1,
+            day: dt.day() as U1,
+            hour: dt.hour() as U1,
+            minute: dt.minute() as U1,
+            second,
+            ns,
+            tAccS: U2::try_from(t_acc.as_secs()).unwrap_or(U2::MAX),
+            tAccNs: t_acc.subsec_nanos(),
+        })
+    }
+}
+
+impl Message for MgaIniTimeUtc {
+    const CLASS: u8 = 0x13;
+    const ID: u8 = 0x40;
+    const LEN: usize = 24;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &MgaIniTimeUtc {
+            timeRef,
+            leapSecs,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            ns,
+            tAccS,
+            tAccNs,
+        } = self;
+
+        dst.put_u8(Self::TYPE);
+        // version
+        dst.put_u8(0);
+        dst.put_u8(timeRef.0);
+        dst.put_i8(leapSecs);
+        dst.put_u16_le(year);
+        dst.put_u8(month);
+        dst.put_u8(day);
+        dst.put_u8(hour);
+        dst.put_u8(minute);
+        dst.put_u8(second);
+        // reserved1
+        dst.put_u8(0);
+        dst.put_u32_le(ns);
+        dst.put_u16_le(tAccS);
+        // reserved2
+        dst.put_u16_le(0);
+        dst.put_u32_le(tAccNs);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        if src.get_u8() != Self::TYPE {
+            return Err(());
+        }
+        // version
+        src.advance(1);
+        let timeRef = TimeRef(src.get_u8());
+        let leapSecs = src.get_i8();
+        let year = src.get_u16_le();
+        let month = src.get_u8();
+        let day = src.get_u8();
+        let hour = src.get_u8();
+        let minute = src.get_u8();
+        let second = src.get_u8();
+        // reserved1
+        src.advance(1);
+        let ns = src.get_u32_le();
+        let tAccS = src.get_u16_le();
+        // reserved2
+        src.advance(2);
+        let tAccNs = src.get_u32_le();
+
+        Ok(MgaIniTimeUtc {
+            timeRef,
+            leapSecs,
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            ns,
+            tAccS,
+            tAccNs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg() -> MgaIniTimeUtc {
+        MgaIniTimeUtc {
+            timeRef: TimeRef(0),
+            leapSecs: 18,
+            year: 2020,
+            month: 4,
+            day: 17,
+            hour: 9,
+            minute: 30,
+            second: 15,
+            ns: 500_000_000,
+            tAccS: 2,
+            tAccNs: 0,
+        }
+    }
+
+    #[test]
+    fn test_byte_layout() {
+        let mut buf = [0_u8; MgaIniTimeUtc::LEN];
+        msg().serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x10, 0x00, 0x00, 0x12, 0xe4, 0x07, 0x04, 0x11, 0x09, 0x1e, 0x0f, 0x00, 0x00, 0x65,
+                0xcd, 0x1d, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00
+            ]
+        );
+        assert_eq!(
+            msg(),
+            MgaIniTimeUtc::deserialize(&mut buf.as_ref()).unwrap()
+        );
+        buf[0] = 0x00;
+        assert!(MgaIniTimeUtc::deserialize(&mut buf.as_ref()).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_from_datetime() {
+        use chrono::TimeZone;
+        let dt = chrono::Utc
+            .with_ymd_and_hms(2020, 4, 17, 9, 30, 15)
+            .unwrap()
+            + chrono::Duration::milliseconds(500);
+        let msg = MgaIniTimeUtc::from_datetime(&dt, core::time::Duration::from_secs(2));
+        assert_eq!(
+            msg,
+            Some(MgaIniTimeUtc {
+                leapSecs: -128,
+                ..self::msg()
+            })
+        );
+
+        // 2016-12-31T23:59:60.5Z
+        let leap = chrono::NaiveDate::from_ymd_opt(2016, 12, 31)
+            .unwrap()
+            .and_hms_nano_opt(23, 59, 59, 1_500_000_000)
+            .unwrap();
+        let msg = MgaIniTimeUtc::from_datetime(
+            &chrono::Utc.from_utc_datetime(&leap),
+            core::time::Duration::from_secs(2),
+        )
+        .unwrap();
+        assert_eq!((msg.minute, msg.second, msg.ns), (59, 60, 500_000_000));
+
+        let dt = chrono::Utc.with_ymd_and_hms(-1, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            MgaIniTimeUtc::from_datetime(&dt, core::time::Duration::from_secs(2)),
+            None
+        );
+    }
+}
@@ -1,12 +1,36 @@
 //! u-blox message types.
 pub mod ack;
 pub mod cfg;
+pub mod epoch;
+mod error;
+pub mod esf;
+mod fields;
+pub mod hnr;
+pub mod log;
+pub mod mga;
+pub mod mon;
+mod names;
 pub mod nav;
 pub mod primitive;
-use crate::framing::Frame;
+mod registry;
+pub mod rxm;
+pub mod upd;
+use crate::framing::{deframe, frame, frame_var, BytesFrame, Frame, FrameRef, FrameVec};
 use ack::AckNak;
 use cfg::Cfg;
+use esf::Esf;
+use hnr::Hnr;
+use log::Log;
+use mga::Mga;
+use mon::Mon;
 use nav::Nav;
+use rxm::Rxm;
+use upd::Upd;
+
+pub use error::ParseError;
+pub use fields::{DecodeFields, FieldValue};
+pub use names::{id_of, name_of};
+pub use registry::{Decoded, MsgRegistry};
 
 /// Top-level enum for valid u-blox messages.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -15,22 +39,181 @@ pub enum Msg {
     AckNak(AckNak),
     /// Configuration message.
     Cfg(Cfg),
+    /// External sensor fusion message.
+    Esf(Esf),
+    /// High rate navigation message.
+    Hnr(Hnr),
+    /// Logging message.
+    Log(Log),
+    /// Multiple GNSS assistance message.
+    Mga(Mga),
+    /// Monitoring message.
+    Mon(Mon),
     /// Navigation message.
     Nav(Nav),
+    /// Receiver manager message.
+    Rxm(Rxm),
+    /// Firmware update message.
+    Upd(Upd),
 }
 
 impl Msg {
-    /// Parses a u-blox message from a [`Frame`].
-    pub fn from_frame(frame: &Frame) -> Result<Self, ()> {
+    /// Parses a u-blox message from a borrowed frame, e.g. a `&Frame`
+    /// or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
         match frame.class {
             cfg::Cfg::CLASS => Ok(Msg::Cfg(Cfg::from_frame(frame)?)),
             nav::Nav::CLASS => Ok(Msg::Nav(Nav::from_frame(frame)?)),
+            mon::Mon::CLASS => Ok(Msg::Mon(Mon::from_frame(frame)?)),
+            mga::Mga::CLASS => Ok(Msg::Mga(Mga::from_frame(frame)?)),
+            esf::Esf::CLASS => Ok(Msg::Esf(Esf::from_frame(frame)?)),
+            hnr::Hnr::CLASS => Ok(Msg::Hnr(Hnr::from_frame(frame)?)),
+            log::Log::CLASS => Ok(Msg::Log(Log::from_frame(frame)?)),
             ack::AckNak::CLASS => Ok(Msg::AckNak(AckNak::from_frame(frame)?)),
-            _ => Err(()),
+            rxm::Rxm::CLASS => Ok(Msg::Rxm(Rxm::from_frame(frame)?)),
+            upd::Upd::CLASS => Ok(Msg::Upd(Upd::from_frame(frame)?)),
+            class => Err(ParseError::UnknownMessage {
+                class,
+                id: frame.id,
+            }),
+        }
+    }
+
+    /// Parses a u-blox message from `frame`, returning it together
+    /// with `frame` itself.
+    ///
+    /// Use this when the received bytes must be forwarded or logged
+    /// verbatim, e.g. in a gateway which decodes messages for display
+    /// but passes the original frames on.
+    pub fn from_frame_with_raw(frame: Frame) -> ParsedFrame {
+        let msg = Self::from_frame(&frame).ok();
+        ParsedFrame { frame, msg }
+    }
+
+    /// Deframes and parses a u-blox message from a buffer containing a
+    /// complete frame, including syncword and checksum.
+    ///
+    /// Any bytes preceding the syncword are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ublox::messages::{ack::AckNak, Msg};
+    /// let bytes = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+    /// match Msg::from_bytes(&bytes).unwrap() {
+    ///     Msg::AckNak(AckNak::Ack(ack)) => assert_eq!((ack.class, ack.id), (0x06, 0x01)),
+    ///     _ => panic!(),
+    /// }
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParseError> {
+        let frame = deframe(bytes.iter().copied()).ok_or(ParseError::Incomplete)?;
+        Self::from_frame(&frame)
+    }
+
+    /// Parses a u-blox message from a [`BytesFrame`] without copying
+    /// its payload.
+    pub fn from_bytes_frame(frame: &BytesFrame) -> Result<Self, ParseError> {
+        Self::from_frame(frame)
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ublox::messages::{name_of, Msg};
+    /// let bytes = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+    /// let (class, id) = Msg::from_bytes(&bytes).unwrap().class_id();
+    /// assert_eq!(name_of(class, id), Some("ACK-ACK"));
+    /// ```
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Msg::AckNak(msg) => msg.class_id(),
+            Msg::Cfg(msg) => msg.class_id(),
+            Msg::Esf(msg) => msg.class_id(),
+            Msg::Hnr(msg) => msg.class_id(),
+            Msg::Log(msg) => msg.class_id(),
+            Msg::Mga(msg) => msg.class_id(),
+            Msg::Mon(msg) => msg.class_id(),
+            Msg::Nav(msg) => msg.class_id(),
+            Msg::Rxm(msg) => msg.class_id(),
+            Msg::Upd(msg) => msg.class_id(),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, ready to be written to a receiver.
+    ///
+    /// Returns an error if the message cannot be serialized, e.g. if
+    /// it has more repeated blocks than its count field can hold.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Msg::AckNak(msg) => msg.to_framed_vec(),
+            Msg::Cfg(msg) => msg.to_framed_vec(),
+            Msg::Esf(msg) => msg.to_framed_vec(),
+            Msg::Hnr(msg) => msg.to_framed_vec(),
+            Msg::Log(msg) => msg.to_framed_vec(),
+            Msg::Mga(msg) => msg.to_framed_vec(),
+            Msg::Mon(msg) => msg.to_framed_vec(),
+            Msg::Nav(msg) => msg.to_framed_vec(),
+            Msg::Rxm(msg) => msg.to_framed_vec(),
+            Msg::Upd(msg) => msg.to_framed_vec(),
         }
     }
 }
 
+/// A received frame and the message parsed from it.
+///
+/// Returned by [`Msg::from_frame_with_raw`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParsedFrame {
+    /// The frame as received.
+    pub frame: Frame,
+    /// The message parsed from `frame`, or `None` if it is unknown or
+    /// malformed.
+    pub msg: Option<Msg>,
+}
+
+/// Parses a fixed-length message from `frame`'s payload.
+pub(crate) fn parse<M: Message>(frame: FrameRef<'_>) -> Result<M, ParseError> {
+    if frame.message.len() != M::LEN {
+        return Err(ParseError::UnexpectedLength {
+            class: frame.class,
+            id: frame.id,
+            len: frame.message.len(),
+        });
+    }
+    M::deserialize(&mut &frame.message[..]).map_err(|()| ParseError::InvalidPayload {
+        class: frame.class,
+        id: frame.id,
+    })
+}
+
+/// Parses a variable-length message from `frame`'s payload.
+pub(crate) fn parse_var<M: VarMessage>(frame: FrameRef<'_>) -> Result<M, ParseError> {
+    M::deserialize(&mut &frame.message[..]).map_err(|()| ParseError::InvalidPayload {
+        class: frame.class,
+        id: frame.id,
+    })
+}
+
+/// Returns `msg` serialized to a complete frame, or an error if it
+/// cannot be serialized.
+pub(crate) fn framed_vec<M: Message>(msg: &M) -> Result<FrameVec, ()> {
+    let mut buf = alloc::vec![0; M::LEN + 8];
+    frame(msg, &mut buf)?;
+    Ok(buf)
+}
+
+/// Returns variable-length `msg` serialized to a complete frame, or
+/// an error if it cannot be serialized.
+pub(crate) fn framed_vec_var<M: VarMessage>(msg: &M) -> Result<FrameVec, ()> {
+    let mut buf = alloc::vec![0; msg.payload_len() + 8];
+    frame_var(msg, &mut buf)?;
+    Ok(buf)
+}
+
 /// Represents any u-blox protocol message.
 pub trait Message: Sized {
     /// Message Class.
@@ -40,9 +223,216 @@ pub trait Message: Sized {
     /// Message length.
     const LEN: usize;
 
+    /// Returns the class and ID of `self`, i.e. `(Self::CLASS,
+    /// Self::ID)`.
+    fn class_id(&self) -> (u8, u8) {
+        (Self::CLASS, Self::ID)
+    }
+
     /// Serialize message bytes to a buffer.
     fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()>;
 
     /// Deserialize a message from buffer of a bytes.
     fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()>;
+
+    /// Deserialize a message from buffer of bytes into `self`, e.g. to
+    /// reuse one message across frames.
+    ///
+    /// On error, `self` is left unchanged.
+    fn deserialize_into<B: bytes::Buf>(&mut self, src: &mut B) -> Result<(), ()> {
+        *self = Self::deserialize(src)?;
+        Ok(())
+    }
+}
+
+/// Represents any u-blox protocol message whose payload length is not
+/// fixed.
+///
+/// The payload length of a variable-length message is determined by
+/// its contents, e.g. a repeated block count or an optional trailing
+/// section.
+pub trait VarMessage: Sized {
+    /// Message Class.
+    const CLASS: u8;
+    /// Message ID.
+    const ID: u8;
+
+    /// Returns the class and ID of `self`, i.e. `(Self::CLASS,
+    /// Self::ID)`.
+    fn class_id(&self) -> (u8, u8) {
+        (Self::CLASS, Self::ID)
+    }
+
+    /// Returns the length of `self`'s serialized payload.
+    fn payload_len(&self) -> usize;
+
+    /// Serialize message bytes to a buffer.
+    fn serialize<B: bytes::BufMut>(&self, dst: &mut B) -> Result<(), ()>;
+
+    /// Deserialize a message from all remaining bytes in `src`.
+    fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::nav::TimeGps;
+
+    #[test]
+    fn test_framed_vec_roundtrip() {
+        let msg = TimeGps {
+            iTOW: 403_200_000,
+            fTOW: -12_345,
+            week: 2_100,
+            leapS: 18,
+            valid: nav::TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        let mut bytes = [0_u8; TimeGps::LEN + 8];
+        frame(&msg, &mut bytes).unwrap();
+        let decoded = Msg::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded, Msg::Nav(nav::Nav::TimeGps(msg)));
+        assert_eq!(decoded.to_framed_vec().unwrap().as_slice(), &bytes[..]);
+    }
+
+    #[test]
+    fn test_to_framed_vec_error() {
+        let correction = nav::SlasCorrection {
+            gnssId: 0,
+            svId: 1,
+            prc: 0,
+        };
+        let mut slas = nav::Slas {
+            iTOW: 0,
+            version: 0,
+            gmsLon: 0,
+            gmsLat: 0,
+            gmsCode: 0,
+            qzssSvId: 0,
+            serviceFlags: nav::SlasServiceFlags(0),
+            corrections: alloc::vec![correction; 255],
+        };
+        assert!(Msg::Nav(nav::Nav::Slas(slas.clone()))
+            .to_framed_vec()
+            .is_ok());
+        // The count field holds at most 255 corrections.
+        slas.corrections.push(correction);
+        assert_eq!(Msg::Nav(nav::Nav::Slas(slas)).to_framed_vec(), Err(()));
+    }
+
+    #[test]
+    fn test_from_bytes_frame() {
+        let bytes = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let frame = deframe(bytes.iter().copied()).unwrap();
+        let expected = Msg::from_frame(&frame).unwrap();
+        let bytes_frame = BytesFrame::from(frame);
+        let payload = bytes_frame.message.as_ptr();
+        assert_eq!(Msg::from_bytes_frame(&bytes_frame), Ok(expected));
+        // The payload is shared, not copied.
+        assert_eq!(bytes_frame.clone().message.as_ptr(), payload);
+    }
+
+    #[test]
+    fn test_from_frame_with_raw() {
+        let bytes = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+        let parsed = Msg::from_frame_with_raw(deframe(bytes.iter().copied()).unwrap());
+        assert_eq!(parsed.msg, Some(Msg::from_bytes(&bytes).unwrap()));
+        assert_eq!(parsed.frame.into_framed_vec(), bytes);
+
+        let unknown = Frame {
+            class: 0xff,
+            id: 0x01,
+            message: alloc::vec![0x01, 0x02, 0x03],
+        };
+        let parsed = Msg::from_frame_with_raw(unknown.clone());
+        assert_eq!(parsed.msg, None);
+        assert_eq!(parsed.frame, unknown);
+    }
+
+    #[test]
+    fn test_class_id() {
+        let msg = TimeGps {
+            iTOW: 403_200_000,
+            fTOW: 0,
+            week: 2_100,
+            leapS: 18,
+            valid: nav::TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        assert_eq!(msg.class_id(), (0x01, 0x20));
+
+        let msgs = [
+            Msg::Nav(nav::Nav::TimeGps(msg)),
+            Msg::Cfg(Cfg::Prt(cfg::prt::Prt::Poll { port_id: 1 })),
+            Msg::AckNak(AckNak::Nak(ack::Nak {
+                class: 0x06,
+                id: 0x00,
+            })),
+        ];
+        let expected = [(0x01, 0x20), (0x06, 0x00), (0x05, 0x00)];
+        for (msg, &(class, id)) in msgs.iter().zip(expected.iter()) {
+            assert_eq!(msg.class_id(), (class, id));
+            let frame = deframe(msg.to_framed_vec().unwrap()).unwrap();
+            assert_eq!((frame.class, frame.id), (class, id));
+        }
+    }
+
+    #[test]
+    fn test_random_payloads() {
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut next = || {
+            // xorshift64
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed as u8
+        };
+        for class in 0..=u8::MAX {
+            for id in (0..=u8::MAX).filter(|&id| name_of(class, id).is_some()) {
+                for len in 0..=160 {
+                    for _ in 0..4 {
+                        let frame = Frame {
+                            class,
+                            id,
+                            message: (0..len).map(|_| next()).collect(),
+                        };
+                        // Parsing untrusted input must fail gracefully.
+                        if let Ok(msg) = Msg::from_frame(&frame) {
+                            assert_eq!(msg.class_id(), (class, id));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let unknown = Frame {
+            class: nav::Nav::CLASS,
+            id: 0xff,
+            message: alloc::vec![0; 4],
+        };
+        assert_eq!(
+            Msg::from_frame(&unknown),
+            Err(ParseError::UnknownMessage {
+                class: 0x01,
+                id: 0xff
+            })
+        );
+
+        let truncated = Frame {
+            class: TimeGps::CLASS,
+            id: TimeGps::ID,
+            message: alloc::vec![0; TimeGps::LEN - 1],
+        };
+        assert_eq!(
+            Msg::from_frame(&truncated),
+            Err(ParseError::UnexpectedLength {
+                class: 0x01,
+                id: 0x20,
+                len: 15
+            })
+        );
+    }
 }
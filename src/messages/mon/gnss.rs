@@ -0,0 +1,116 @@
+use crate::messages::{primitive::*, Message};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// Information about GNSS system configuration.
+///
+/// Reports the major GNSS selections supported, enabled, and by
+/// default enabled in the receiver.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MonGnss {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// A bit mask showing the major GNSS that can be supported by this
+    /// receiver.
+    pub supported: GnssMask,
+
+    /// A bit mask showing the default major GNSS selection.
+    pub defaultGnss: GnssMask,
+
+    /// A bit mask showing the current major GNSS selection enabled
+    /// for this receiver.
+    pub enabled: GnssMask,
+
+    /// Maximum number of concurrent major GNSS that can be supported
+    /// by this receiver.
+    pub simultaneous: U1,
+}
+
+bitfield! {
+    /// Bitfield of major GNSS selections.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct GnssMask(X1);
+    impl Debug;
+    /// Galileo
+    pub galileo, set_galileo: 3;
+    /// BeiDou
+    pub beidou, set_beidou: 2;
+    /// GLONASS
+    pub glonass, set_glonass: 1;
+    /// GPS
+    pub gps, set_gps: 0;
+}
+
+impl Message for MonGnss {
+    const CLASS: u8 = 0x0A;
+    const ID: u8 = 0x28;
+    const LEN: usize = 8;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &MonGnss {
+            version,
+            supported,
+            defaultGnss,
+            enabled,
+            simultaneous,
+        } = self;
+
+        dst.put_u8(version);
+        dst.put_u8(supported.0);
+        dst.put_u8(defaultGnss.0);
+        dst.put_u8(enabled.0);
+        dst.put_u8(simultaneous);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        let supported = GnssMask(src.get_u8());
+        let defaultGnss = GnssMask(src.get_u8());
+        let enabled = GnssMask(src.get_u8());
+        let simultaneous = src.get_u8();
+        // reserved1
+        src.advance(3);
+
+        Ok(MonGnss {
+            version,
+            supported,
+            defaultGnss,
+            enabled,
+            simultaneous,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse() {
+        let bytes = [0x00, 0x0f, 0x03, 0x0b, 0x03, 0x00, 0x00, 0x00];
+        let msg = MonGnss::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.simultaneous, 3);
+        assert!(msg.supported.gps());
+        assert!(msg.supported.glonass());
+        assert!(msg.supported.beidou());
+        assert!(msg.supported.galileo());
+        assert!(!msg.defaultGnss.beidou());
+        assert!(msg.enabled.gps());
+        assert!(msg.enabled.glonass());
+        assert!(!msg.enabled.beidou());
+        assert!(msg.enabled.galileo());
+    }
+}
@@ -0,0 +1,71 @@
+//! Monitoring Messages: i.e. Communication Status, CPU Load, Stack
+//! Usage, Task Status.
+//!
+//! Messages in the MON class are used to report the receiver status,
+//! such as hardware status or I/O subsystem statistics.
+
+mod gnss;
+mod rf;
+mod ver;
+pub use self::gnss::*;
+pub use self::rf::*;
+pub use self::ver::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{
+    framed_vec, framed_vec_var, parse, parse_var, Message, ParseError, VarMessage,
+};
+
+/// Monitoring messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Mon {
+    Gnss(MonGnss),
+    Rf(MonRf),
+    Ver(MonVer),
+}
+
+impl Mon {
+    /// MON class.
+    pub const CLASS: u8 = 0x0A;
+
+    /// Parses a monitoring message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            MonGnss::ID => parse(frame).map(Mon::Gnss),
+            MonRf::ID => parse_var(frame).map(Mon::Rf),
+            MonVer::ID => parse_var(frame).map(Mon::Ver),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Mon::Gnss(msg) => framed_vec(msg),
+            Mon::Rf(msg) => framed_vec_var(msg),
+            Mon::Ver(msg) => framed_vec_var(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Mon::Gnss(msg) => msg.class_id(),
+            Mon::Rf(msg) => msg.class_id(),
+            Mon::Ver(msg) => msg.class_id(),
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// RF information.
+///
+/// Reports the status of each RF block of the receiver. Supersedes
+/// the RF related fields of MON-HW on protocol versions 18 and later.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MonRf {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// Status of each RF block.
+    pub blocks: Vec<MonRfBlock>,
+}
+
+/// Status of a single RF block, decoded from one repeated block of a
+/// MON-RF message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonRfBlock {
+    /// RF block ID.
+    ///
+    /// - 0 L1 band
+    /// - 1 L2 or L5 band, depending on product configuration
+    pub blockId: U1,
+
+    /// Flags.
+    pub flags: MonRfFlags,
+
+    /// Status of the antenna supervisor state machine.
+    ///
+    /// - 0 INIT
+    /// - 1 DONTKNOW
+    /// - 2 OK
+    /// - 3 SHORT
+    /// - 4 OPEN
+    pub antStatus: U1,
+
+    /// Current power status of antenna.
+    ///
+    /// - 0 OFF
+    /// - 1 ON
+    /// - 2 DONTKNOW
+    pub antPower: U1,
+
+    /// POST status word.
+    pub postStatus: X4,
+
+    /// Noise level as measured by the GPS core.
+    pub noisePerMS: U2,
+
+    /// AGC monitor (counts SIGHI xor SIGLO, range 0 to 8191).
+    pub agcCnt: U2,
+
+    /// CW jamming indicator, scaled (0 = no CW jamming, 255 = strong
+    /// CW jamming).
+    pub jamInd: U1,
+
+    /// Imbalance of I-part of complex signal, scaled (-128 = max.
+    /// negative imbalance, 127 = max. positive imbalance).
+    pub ofsI: I1,
+
+    /// Magnitude of I-part of complex signal, scaled (0 = no signal,
+    /// 255 = max. magnitude).
+    pub magI: U1,
+
+    /// Imbalance of Q-part of complex signal, scaled (-128 = max.
+    /// negative imbalance, 127 = max. positive imbalance).
+    pub ofsQ: I1,
+
+    /// Magnitude of Q-part of complex signal, scaled (0 = no signal,
+    /// 255 = max. magnitude).
+    pub magQ: U1,
+}
+
+impl MonRf {
+    const LEN_HEADER: usize = 4;
+    const LEN_BLOCK: usize = 24;
+
+    /// Returns the CW jamming indicator of each RF block, in block
+    /// order.
+    pub fn jam_indicators(&self) -> impl Iterator<Item = U1> + '_ {
+        self.blocks.iter().map(|block| block.jamInd)
+    }
+}
+
+impl VarMessage for MonRf {
+    const CLASS: u8 = 0x0A;
+    const ID: u8 = 0x38;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.blocks.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let n_blocks = U1::try_from(self.blocks.len()).map_err(|_| ())?;
+
+        dst.put_u8(self.version);
+        dst.put_u8(n_blocks);
+        // reserved0
+        dst.put_u16_le(0);
+        for block in &self.blocks {
+            dst.put_u8(block.blockId);
+            dst.put_u8(block.flags.0);
+            dst.put_u8(block.antStatus);
+            dst.put_u8(block.antPower);
+            dst.put_u32_le(block.postStatus);
+            // reserved1
+            dst.put_u32_le(0);
+            dst.put_u16_le(block.noisePerMS);
+            dst.put_u16_le(block.agcCnt);
+            dst.put_u8(block.jamInd);
+            dst.put_i8(block.ofsI);
+            dst.put_u8(block.magI);
+            dst.put_i8(block.ofsQ);
+            dst.put_u8(block.magQ);
+            // reserved2
+            dst.put_slice([0_u8; 3].as_ref());
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        let n_blocks = usize::from(src.get_u8());
+        // reserved0
+        src.advance(2);
+        if src.remaining() != n_blocks * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let blocks = (0..n_blocks)
+            .map(|_| {
+                let blockId = src.get_u8();
+                let flags = MonRfFlags(src.get_u8());
+                let antStatus = src.get_u8();
+                let antPower = src.get_u8();
+                let postStatus = src.get_u32_le();
+                // reserved1
+                src.advance(4);
+                let block = MonRfBlock {
+                    blockId,
+                    flags,
+                    antStatus,
+                    antPower,
+                    postStatus,
+                    noisePerMS: src.get_u16_le(),
+                    agcCnt: src.get_u16_le(),
+                    jamInd: src.get_u8(),
+                    ofsI: src.get_i8(),
+                    magI: src.get_u8(),
+                    ofsQ: src.get_i8(),
+                    magQ: src.get_u8(),
+                };
+                // reserved2
+                src.advance(3);
+                block
+            })
+            .collect();
+
+        Ok(MonRf { version, blocks })
+    }
+}
+
+bitfield! {
+    /// Bitfield `flags`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct MonRfFlags(X1);
+    impl Debug;
+    /// Output from jamming/interference monitor
+    ///
+    /// - 0 unknown or feature disabled
+    /// - 1 ok, no significant jamming
+    /// - 2 warning, interference visible but fix OK
+    /// - 3 critical, interference visible and no fix
+    pub jammingState, _: 1, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_two_blocks() {
+        let bytes = [
+            0x00, 0x02, 0x00, 0x00, //
+            0x00, 0x01, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x52, 0x00,
+            0xd2, 0x0f, 0x0c, 0xfe, 0x8c, 0x03, 0x90, 0x00, 0x00, 0x00, //
+            0x01, 0x02, 0x02, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x44, 0x00,
+            0x10, 0x0e, 0x3c, 0x01, 0x80, 0xff, 0x7f, 0x00, 0x00, 0x00,
+        ];
+        let msg = MonRf::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.version, 0);
+        assert_eq!(msg.blocks.len(), 2);
+
+        let l1 = msg.blocks[0];
+        assert_eq!(l1.blockId, 0);
+        assert_eq!(l1.flags.jammingState(), 1);
+        assert_eq!(l1.antStatus, 2);
+        assert_eq!(l1.antPower, 1);
+        assert_eq!(l1.noisePerMS, 82);
+        assert_eq!(l1.agcCnt, 4050);
+        assert_eq!(l1.jamInd, 12);
+        assert_eq!(l1.ofsI, -2);
+        assert_eq!(l1.magI, 140);
+        assert_eq!(l1.ofsQ, 3);
+        assert_eq!(l1.magQ, 144);
+
+        let l2 = msg.blocks[1];
+        assert_eq!(l2.blockId, 1);
+        assert_eq!(l2.flags.jammingState(), 2);
+        assert_eq!(l2.ofsQ, -1);
+        assert_eq!(msg.jam_indicators().collect::<Vec<_>>(), [12, 60]);
+
+        let mut buf = [0_u8; 52];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(MonRf::deserialize(&mut bytes[..51].as_ref()).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::messages::{primitive::CH, VarMessage};
+use alloc::vec::Vec;
+use bytes::{Buf, BufMut};
+use core::str;
+
+/// Receiver and software version.
+///
+/// Poll this message with an empty payload to read the receiver's
+/// software, hardware and extension version strings.
+///
+/// Strings are kept in their NUL-padded wire format, so any received
+/// message re-encodes to the same bytes. Use the accessors to read
+/// them as `&str`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MonVer {
+    /// Software version string, NUL-padded.
+    pub swVersion: [CH; 30],
+
+    /// Hardware version string, NUL-padded.
+    pub hwVersion: [CH; 10],
+
+    /// Extended software information strings, NUL-padded, e.g.
+    /// `"PROTVER=18.00"`.
+    pub extensions: Vec<[CH; 30]>,
+}
+
+impl MonVer {
+    const LEN_SW_VERSION: usize = 30;
+    const LEN_HW_VERSION: usize = 10;
+    const LEN_EXTENSION: usize = 30;
+
+    /// Returns a message holding the given strings, each truncated to
+    /// fit its field.
+    pub fn new(sw_version: &str, hw_version: &str, extensions: &[&str]) -> Self {
+        MonVer {
+            swVersion: to_ch(sw_version),
+            hwVersion: to_ch(hw_version),
+            extensions: extensions.iter().map(|s| to_ch(s)).collect(),
+        }
+    }
+
+    /// Returns the software version string.
+    pub fn sw_version(&self) -> &str {
+        from_ch(&self.swVersion)
+    }
+
+    /// Returns the hardware version string.
+    pub fn hw_version(&self) -> &str {
+        from_ch(&self.hwVersion)
+    }
+
+    /// Returns an iterator over the extended software information
+    /// strings.
+    pub fn extensions(&self) -> impl Iterator<Item = &str> + '_ {
+        self.extensions.iter().map(|field| from_ch(field))
+    }
+}
+
+/// Returns `s` as a NUL-padded field, truncated to fit.
+fn to_ch<const N: usize>(s: &str) -> [CH; N] {
+    let mut field = [0; N];
+    let len = s.len().min(N);
+    field[..len].copy_from_slice(&s.as_bytes()[..len]);
+    field
+}
+
+/// Returns the string in a NUL-padded field, up to the first NUL or
+/// invalid UTF-8 sequence.
+fn from_ch(field: &[CH]) -> &str {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    match str::from_utf8(&field[..end]) {
+        Ok(s) => s,
+        Err(e) => str::from_utf8(&field[..e.valid_up_to()]).unwrap_or_default(),
+    }
+}
+
+impl VarMessage for MonVer {
+    const CLASS: u8 = 0x0A;
+    const ID: u8 = 0x04;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_SW_VERSION + Self::LEN_HW_VERSION + Self::LEN_EXTENSION * self.extensions.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+
+        dst.put_slice(&self.swVersion);
+        dst.put_slice(&self.hwVersion);
+        for extension in &self.extensions {
+            dst.put_slice(extension);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_SW_VERSION + Self::LEN_HW_VERSION {
+            return Err(());
+        }
+
+        let mut swVersion = [0; 30];
+        src.copy_to_slice(&mut swVersion);
+        let mut hwVersion = [0; 10];
+        src.copy_to_slice(&mut hwVersion);
+        let mut extensions = Vec::new();
+        while src.remaining() >= Self::LEN_EXTENSION {
+            let mut extension = [0; 30];
+            src.copy_to_slice(&mut extension);
+            extensions.push(extension);
+        }
+        if src.has_remaining() {
+            return Err(());
+        }
+
+        Ok(MonVer {
+            swVersion,
+            hwVersion,
+            extensions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = MonVer::new(
+            "ROM CORE 3.01 (107888)",
+            "00080000",
+            &["FWVER=SPG 3.01", "PROTVER=18.00"],
+        );
+        let mut buf = [0xff_u8; 100];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(&buf[..4], b"ROM ");
+        assert_eq!(buf[29], 0);
+        assert_eq!(&buf[30..38], b"00080000");
+        let decoded = MonVer::deserialize(&mut buf.as_ref()).unwrap();
+        assert_eq!(decoded.sw_version(), "ROM CORE 3.01 (107888)");
+        assert_eq!(decoded.hw_version(), "00080000");
+        assert!(decoded
+            .extensions()
+            .eq(["FWVER=SPG 3.01", "PROTVER=18.00"].iter().copied()));
+        assert_eq!(decoded, msg);
+        assert!(MonVer::deserialize(&mut buf[..99].as_ref()).is_err());
+    }
+
+    #[test]
+    fn test_full_and_invalid_fields() {
+        // A software version filling its whole field, and a hardware
+        // version which is not valid UTF-8.
+        let mut payload = [b'A'; 40];
+        payload[30..34].copy_from_slice(&[b'0', 0xff, b'1', 0x00]);
+        let msg = MonVer::deserialize(&mut payload.as_ref()).unwrap();
+        assert_eq!(msg.sw_version().len(), 30);
+        assert_eq!(msg.hw_version(), "0");
+
+        let mut buf = [0_u8; 40];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, payload);
+        let mon = crate::messages::mon::Mon::Ver(msg);
+        assert_eq!(&mon.to_framed_vec().unwrap()[6..46], &payload[..]);
+
+        assert_eq!(MonVer::new(&"x".repeat(40), "", &[]).sw_version().len(), 30);
+    }
+}
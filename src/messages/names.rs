@@ -0,0 +1,112 @@
+//! Message name lookup.
+
+use crate::messages::{
+    ack::{Ack, Nak},
+    cfg::{
+        ant::CfgAnt, batch::CfgBatch, dgnss::CfgDgnss, hnr::CfgHnr, inf::CfgInf,
+        logfilter::CfgLogFilter, navx5::NavX5, pms::CfgPms, prt::Prt, rinv::CfgRinv,
+        tmode3::CfgTmode3, SetMsgRates,
+    },
+    esf::{EsfAlg, EsfIns, EsfStatus},
+    hnr::HnrPvt,
+    log::LogRetrievePos,
+    mga::MgaIniTimeUtc,
+    mon::{MonGnss, MonRf, MonVer},
+    nav::{Dop, Eoe, Geofence, Odo, PosEcef, Pvt, Sat, Sig, Slas, Status, Svin, TimeGps, VelEcef},
+    rxm::{MeasX, PmReq},
+    upd::UpdSos,
+    Message, VarMessage,
+};
+
+/// `(class, id, name)` of every message modeled by this crate.
+///
+/// MGA-INI messages share a class and ID, and are listed once.
+static NAMES: &[(u8, u8, &str)] = &[
+    (Ack::CLASS, Ack::ID, "ACK-ACK"),
+    (Nak::CLASS, Nak::ID, "ACK-NAK"),
+    (Prt::CLASS, Prt::ID, "CFG-PRT"),
+    (SetMsgRates::CLASS, SetMsgRates::ID, "CFG-MSG"),
+    (CfgInf::CLASS, CfgInf::ID, "CFG-INF"),
+    (CfgAnt::CLASS, CfgAnt::ID, "CFG-ANT"),
+    (NavX5::CLASS, NavX5::ID, "CFG-NAVX5"),
+    (CfgRinv::CLASS, CfgRinv::ID, "CFG-RINV"),
+    (CfgLogFilter::CLASS, CfgLogFilter::ID, "CFG-LOGFILTER"),
+    (CfgBatch::CLASS, CfgBatch::ID, "CFG-BATCH"),
+    (CfgDgnss::CLASS, CfgDgnss::ID, "CFG-DGNSS"),
+    (CfgTmode3::CLASS, CfgTmode3::ID, "CFG-TMODE3"),
+    (CfgPms::CLASS, CfgPms::ID, "CFG-PMS"),
+    (CfgHnr::CLASS, CfgHnr::ID, "CFG-HNR"),
+    (EsfStatus::CLASS, EsfStatus::ID, "ESF-STATUS"),
+    (EsfAlg::CLASS, EsfAlg::ID, "ESF-ALG"),
+    (EsfIns::CLASS, EsfIns::ID, "ESF-INS"),
+    (HnrPvt::CLASS, HnrPvt::ID, "HNR-PVT"),
+    (LogRetrievePos::CLASS, LogRetrievePos::ID, "LOG-RETRIEVEPOS"),
+    (MgaIniTimeUtc::CLASS, MgaIniTimeUtc::ID, "MGA-INI"),
+    (MonVer::CLASS, MonVer::ID, "MON-VER"),
+    (MonGnss::CLASS, MonGnss::ID, "MON-GNSS"),
+    (MonRf::CLASS, MonRf::ID, "MON-RF"),
+    (PosEcef::CLASS, PosEcef::ID, "NAV-POSECEF"),
+    (Dop::CLASS, Dop::ID, "NAV-DOP"),
+    (Pvt::CLASS, Pvt::ID, "NAV-PVT"),
+    (Odo::CLASS, Odo::ID, "NAV-ODO"),
+    (VelEcef::CLASS, VelEcef::ID, "NAV-VELECEF"),
+    (TimeGps::CLASS, TimeGps::ID, "NAV-TIMEGPS"),
+    (Sat::CLASS, Sat::ID, "NAV-SAT"),
+    (Geofence::CLASS, Geofence::ID, "NAV-GEOFENCE"),
+    (Svin::CLASS, Svin::ID, "NAV-SVIN"),
+    (Slas::CLASS, Slas::ID, "NAV-SLAS"),
+    (Status::CLASS, Status::ID, "NAV-STATUS"),
+    (Sig::CLASS, Sig::ID, "NAV-SIG"),
+    (Eoe::CLASS, Eoe::ID, "NAV-EOE"),
+    (MeasX::CLASS, MeasX::ID, "RXM-MEASX"),
+    (PmReq::CLASS, PmReq::ID, "RXM-PMREQ"),
+    (UpdSos::CLASS, UpdSos::ID, "UPD-SOS"),
+];
+
+/// Returns the name of the message identified by `class` and `id`,
+/// e.g. `"NAV-PVT"`, or `None` if it is not modeled by this crate.
+pub const fn name_of(class: u8, id: u8) -> Option<&'static str> {
+    let mut i = 0;
+    while i < NAMES.len() {
+        let (c, d, name) = NAMES[i];
+        if c == class && d == id {
+            return Some(name);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns the `(class, id)` of the message named `name`, e.g.
+/// `"NAV-PVT"`, or `None` if it is not modeled by this crate.
+///
+/// Names are matched case-insensitively.
+pub fn id_of(name: &str) -> Option<(u8, u8)> {
+    NAMES
+        .iter()
+        .find(|(_, _, n)| n.eq_ignore_ascii_case(name))
+        .map(|&(class, id, _)| (class, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(name_of(0x01, 0x07), Some("NAV-PVT"));
+        assert_eq!(name_of(0x05, 0x00), Some("ACK-NAK"));
+        assert_eq!(name_of(0x01, 0xff), None);
+        assert_eq!(id_of("CFG-PRT"), Some((0x06, 0x00)));
+        assert_eq!(id_of("nav-sat"), Some((0x01, 0x35)));
+        assert_eq!(id_of("NAV-BOGUS"), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for &(class, id, name) in NAMES {
+            assert_eq!(name_of(class, id), Some(name));
+            assert_eq!(id_of(name), Some((class, id)));
+        }
+    }
+}
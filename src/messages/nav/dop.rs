@@ -0,0 +1,114 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Dilution of precision.
+///
+/// DOP values are dimensionless. All DOP values are scaled by a
+/// factor of 100.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dop {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Geometric DOP.
+    pub gDOP: U2,
+
+    /// Position DOP.
+    pub pDOP: U2,
+
+    /// Time DOP.
+    pub tDOP: U2,
+
+    /// Vertical DOP.
+    pub vDOP: U2,
+
+    /// Horizontal DOP.
+    pub hDOP: U2,
+
+    /// Northing DOP.
+    pub nDOP: U2,
+
+    /// Easting DOP.
+    pub eDOP: U2,
+}
+
+impl Dop {
+    /// Returns geometric DOP.
+    pub fn g_dop(&self) -> DopScaled {
+        DopScaled(self.gDOP)
+    }
+
+    /// Returns position DOP.
+    pub fn p_dop(&self) -> DopScaled {
+        DopScaled(self.pDOP)
+    }
+
+    /// Returns time DOP.
+    pub fn t_dop(&self) -> DopScaled {
+        DopScaled(self.tDOP)
+    }
+
+    /// Returns vertical DOP.
+    pub fn v_dop(&self) -> DopScaled {
+        DopScaled(self.vDOP)
+    }
+
+    /// Returns horizontal DOP.
+    pub fn h_dop(&self) -> DopScaled {
+        DopScaled(self.hDOP)
+    }
+}
+
+impl Message for Dop {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x04;
+    const LEN: usize = 18;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &Dop {
+            iTOW,
+            gDOP,
+            pDOP,
+            tDOP,
+            vDOP,
+            hDOP,
+            nDOP,
+            eDOP,
+        } = self;
+
+        dst.put_u32_le(iTOW);
+        dst.put_u16_le(gDOP);
+        dst.put_u16_le(pDOP);
+        dst.put_u16_le(tDOP);
+        dst.put_u16_le(vDOP);
+        dst.put_u16_le(hDOP);
+        dst.put_u16_le(nDOP);
+        dst.put_u16_le(eDOP);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        Ok(Dop {
+            iTOW: src.get_u32_le(),
+            gDOP: src.get_u16_le(),
+            pDOP: src.get_u16_le(),
+            tDOP: src.get_u16_le(),
+            vDOP: src.get_u16_le(),
+            hDOP: src.get_u16_le(),
+            nDOP: src.get_u16_le(),
+            eDOP: src.get_u16_le(),
+        })
+    }
+}
@@ -0,0 +1,40 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// End of epoch.
+///
+/// This message is intended to be used as a marker to collect all
+/// navigation messages of an epoch. It is output after all enabled
+/// NAV class messages (except UBX-NAV-HNR) and after all enabled NMEA
+/// messages.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Eoe {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+}
+
+impl Message for Eoe {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x61;
+    const LEN: usize = 4;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+        dst.put_u32_le(self.iTOW);
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+        Ok(Eoe {
+            iTOW: src.get_u32_le(),
+        })
+    }
+}
@@ -0,0 +1,157 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// Geofencing status.
+///
+/// Reports the receiver's position relative to each configured
+/// geofence, as well as the combined state of all geofences.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Geofence {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// Geofencing status.
+    ///
+    /// - 0 Geofencing not available or not reliable
+    /// - 1 Geofencing active
+    pub status: U1,
+
+    /// Combined (logical OR) state of all geofences.
+    pub combState: GeofenceState,
+
+    /// State of each geofence.
+    pub fences: Vec<GeofenceState>,
+}
+
+/// Position relative to a geofence.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum GeofenceState {
+    /// Unknown.
+    Unknown,
+    /// Inside the geofence.
+    Inside,
+    /// Outside the geofence.
+    Outside,
+    /// Undocumented value.
+    Reserved(u8),
+}
+
+impl From<u8> for GeofenceState {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => GeofenceState::Unknown,
+            1 => GeofenceState::Inside,
+            2 => GeofenceState::Outside,
+            other => GeofenceState::Reserved(other),
+        }
+    }
+}
+
+impl From<GeofenceState> for u8 {
+    fn from(state: GeofenceState) -> Self {
+        match state {
+            GeofenceState::Unknown => 0,
+            GeofenceState::Inside => 1,
+            GeofenceState::Outside => 2,
+            GeofenceState::Reserved(other) => other,
+        }
+    }
+}
+
+impl Geofence {
+    const LEN_HEADER: usize = 8;
+    const LEN_BLOCK: usize = 2;
+}
+
+impl VarMessage for Geofence {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x39;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.fences.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let num_fences = U1::try_from(self.fences.len()).map_err(|_| ())?;
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        dst.put_u8(self.status);
+        dst.put_u8(num_fences);
+        dst.put_u8(self.combState.into());
+        for &state in &self.fences {
+            dst.put_u8(state.into());
+            // reserved1
+            dst.put_u8(0);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        let status = src.get_u8();
+        let num_fences = usize::from(src.get_u8());
+        let combState = GeofenceState::from(src.get_u8());
+        if src.remaining() != num_fences * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let fences = (0..num_fences)
+            .map(|_| {
+                let state = GeofenceState::from(src.get_u8());
+                // reserved1
+                src.advance(1);
+                state
+            })
+            .collect();
+
+        Ok(Geofence {
+            iTOW,
+            version,
+            status,
+            combState,
+            fences,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_inside() {
+        let bytes = [
+            0xe8, 0x03, 0x00, 0x00, 0x00, 0x01, 0x02, 0x01, //
+            0x02, 0x00, //
+            0x01, 0x00,
+        ];
+        let msg = Geofence::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 1000);
+        assert_eq!(msg.status, 1);
+        assert_eq!(msg.combState, GeofenceState::Inside);
+        assert_eq!(msg.fences, [GeofenceState::Outside, GeofenceState::Inside]);
+
+        let mut buf = [0_u8; 12];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(Geofence::deserialize(&mut bytes[..10].as_ref()).is_err());
+    }
+}
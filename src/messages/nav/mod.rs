@@ -1,11 +1,35 @@
 //! Navigation messages.
 
+mod dop;
+mod eoe;
+mod geofence;
+mod odo;
+mod posecef;
 mod pvt;
+mod sat;
+mod sig;
+mod slas;
+mod status;
+mod svin;
 mod timegps;
+mod velecef;
+pub use self::dop::*;
+pub use self::eoe::*;
+pub use self::geofence::*;
+pub use self::odo::*;
+pub use self::posecef::*;
 pub use self::pvt::*;
+pub use self::sat::*;
+pub use self::sig::*;
+pub use self::slas::*;
+pub use self::status::*;
+pub use self::svin::*;
 pub use self::timegps::*;
-use crate::framing::Frame;
-use crate::messages::Message;
+pub use self::velecef::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{
+    framed_vec, framed_vec_var, parse, parse_var, Message, ParseError, VarMessage,
+};
 
 /// Navigation Results Messages
 ///
@@ -22,26 +46,237 @@ use crate::messages::Message;
 pub enum Nav {
     TimeGps(TimeGps),
     Pvt(Pvt),
+    PosEcef(PosEcef),
+    VelEcef(VelEcef),
+    Sat(Sat),
+    Odo(Odo),
+    Dop(Dop),
+    Eoe(Eoe),
+    Geofence(Geofence),
+    Sig(Sig),
+    Svin(Svin),
+    Slas(Slas),
+    Status(Status),
 }
 
 impl Nav {
     /// NAV class.
     pub const CLASS: u8 = 0x01;
 
-    /// Parses a navigation message from a [`Frame`].
-    pub fn from_frame(frame: &Frame) -> Result<Self, ()> {
+    /// Parses a navigation message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
         if frame.class != Self::CLASS {
-            return Err(());
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
         };
 
-        match (frame.class, frame.id, frame.message.len()) {
-            (TimeGps::CLASS, TimeGps::ID, TimeGps::LEN) => Ok(Nav::TimeGps(TimeGps::deserialize(
-                &mut frame.message.as_slice(),
-            )?)),
-            (Pvt::CLASS, Pvt::ID, Pvt::LEN) => {
-                Ok(Nav::Pvt(Pvt::deserialize(&mut frame.message.as_slice())?))
+        match frame.id {
+            TimeGps::ID => parse(frame).map(Nav::TimeGps),
+            Pvt::ID if frame.message.len() == Pvt::LEN_LEGACY => {
+                Pvt::deserialize(&mut &frame.message[..])
+                    .map(Nav::Pvt)
+                    .map_err(|()| ParseError::InvalidPayload {
+                        class: frame.class,
+                        id: frame.id,
+                    })
             }
-            _ => Err(()),
+            Pvt::ID => parse(frame).map(Nav::Pvt),
+            PosEcef::ID => parse(frame).map(Nav::PosEcef),
+            VelEcef::ID => parse(frame).map(Nav::VelEcef),
+            Sat::ID => parse_var(frame).map(Nav::Sat),
+            Odo::ID => parse(frame).map(Nav::Odo),
+            Dop::ID => parse(frame).map(Nav::Dop),
+            Eoe::ID => parse(frame).map(Nav::Eoe),
+            Geofence::ID => parse_var(frame).map(Nav::Geofence),
+            Sig::ID => parse_var(frame).map(Nav::Sig),
+            Svin::ID => parse(frame).map(Nav::Svin),
+            Slas::ID => parse_var(frame).map(Nav::Slas),
+            Status::ID => parse(frame).map(Nav::Status),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
         }
     }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Nav::TimeGps(msg) => framed_vec(msg),
+            Nav::Pvt(msg) => framed_vec(msg),
+            Nav::PosEcef(msg) => framed_vec(msg),
+            Nav::VelEcef(msg) => framed_vec(msg),
+            Nav::Sat(msg) => framed_vec_var(msg),
+            Nav::Odo(msg) => framed_vec(msg),
+            Nav::Dop(msg) => framed_vec(msg),
+            Nav::Eoe(msg) => framed_vec(msg),
+            Nav::Geofence(msg) => framed_vec_var(msg),
+            Nav::Sig(msg) => framed_vec_var(msg),
+            Nav::Svin(msg) => framed_vec(msg),
+            Nav::Slas(msg) => framed_vec_var(msg),
+            Nav::Status(msg) => framed_vec(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Nav::TimeGps(msg) => msg.class_id(),
+            Nav::Pvt(msg) => msg.class_id(),
+            Nav::PosEcef(msg) => msg.class_id(),
+            Nav::VelEcef(msg) => msg.class_id(),
+            Nav::Sat(msg) => msg.class_id(),
+            Nav::Odo(msg) => msg.class_id(),
+            Nav::Dop(msg) => msg.class_id(),
+            Nav::Eoe(msg) => msg.class_id(),
+            Nav::Geofence(msg) => msg.class_id(),
+            Nav::Sig(msg) => msg.class_id(),
+            Nav::Svin(msg) => msg.class_id(),
+            Nav::Slas(msg) => msg.class_id(),
+            Nav::Status(msg) => msg.class_id(),
+        }
+    }
+
+    /// Returns the GPS time of week of the navigation epoch `self`
+    /// belongs to, if it has one.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub fn itow(&self) -> Option<u32> {
+        match self {
+            Nav::TimeGps(msg) => Some(msg.iTOW),
+            Nav::Pvt(msg) => Some(msg.itow()),
+            Nav::PosEcef(msg) => Some(msg.iTOW),
+            Nav::VelEcef(msg) => Some(msg.iTOW),
+            Nav::Sat(msg) => Some(msg.iTOW),
+            Nav::Odo(msg) => Some(msg.iTOW),
+            Nav::Dop(msg) => Some(msg.iTOW),
+            Nav::Eoe(msg) => Some(msg.iTOW),
+            Nav::Geofence(msg) => Some(msg.iTOW),
+            Nav::Sig(msg) => Some(msg.iTOW),
+            Nav::Svin(msg) => Some(msg.iTOW),
+            Nav::Slas(msg) => Some(msg.iTOW),
+            Nav::Status(msg) => Some(msg.iTOW),
+        }
+    }
+}
+
+/// GNSS fix type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixType {
+    /// No fix.
+    NoFix,
+    /// Dead reckoning only.
+    DeadReckoning,
+    /// 2D fix.
+    Fix2D,
+    /// 3D fix.
+    Fix3D,
+    /// GNSS + dead reckoning combined.
+    GnssDeadReckoning,
+    /// Time only fix.
+    TimeOnly,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for FixType {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => FixType::NoFix,
+            1 => FixType::DeadReckoning,
+            2 => FixType::Fix2D,
+            3 => FixType::Fix3D,
+            4 => FixType::GnssDeadReckoning,
+            5 => FixType::TimeOnly,
+            other => FixType::Unknown(other),
+        }
+    }
+}
+
+impl From<FixType> for u8 {
+    fn from(fix_type: FixType) -> Self {
+        match fix_type {
+            FixType::NoFix => 0,
+            FixType::DeadReckoning => 1,
+            FixType::Fix2D => 2,
+            FixType::Fix3D => 3,
+            FixType::GnssDeadReckoning => 4,
+            FixType::TimeOnly => 5,
+            FixType::Unknown(other) => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::Frame;
+
+    #[test]
+    fn test_fix_type() {
+        for raw in 0..=u8::MAX {
+            assert_eq!(u8::from(FixType::from(raw)), raw);
+        }
+        assert_eq!(FixType::from(3), FixType::Fix3D);
+        assert_eq!(FixType::from(6), FixType::Unknown(6));
+    }
+
+    #[test]
+    fn test_itow() {
+        let mut payload = [0_u8; Pvt::LEN];
+        payload[..4].copy_from_slice(&403_200_000_u32.to_le_bytes());
+        let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+        assert_eq!(Nav::Pvt(pvt).itow(), Some(403_200_000));
+
+        let time_gps = TimeGps {
+            iTOW: 403_201_000,
+            fTOW: 0,
+            week: 2_100,
+            leapS: 18,
+            valid: TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        assert_eq!(Nav::TimeGps(time_gps).itow(), Some(403_201_000));
+    }
+
+    #[test]
+    fn test_pvt_lengths() {
+        let mut message: FrameVec = (0..Pvt::LEN as u8).collect();
+        let frame = Frame {
+            class: Pvt::CLASS,
+            id: Pvt::ID,
+            message: message.clone(),
+        };
+        let pvt = match Nav::from_frame(&frame).unwrap() {
+            Nav::Pvt(pvt) => pvt,
+            other => panic!("expected PVT, got {:?}", other),
+        };
+        assert!(pvt.has_mag_declination());
+
+        message.truncate(Pvt::LEN_LEGACY);
+        let frame = Frame {
+            class: Pvt::CLASS,
+            id: Pvt::ID,
+            message,
+        };
+        let legacy = match Nav::from_frame(&frame).unwrap() {
+            Nav::Pvt(pvt) => pvt,
+            other => panic!("expected PVT, got {:?}", other),
+        };
+        assert!(!legacy.has_mag_declination());
+        assert_eq!(legacy.lat(), pvt.lat());
+
+        // Serializing a legacy PVT zero-fills reserved and absent
+        // fields.
+        let mut buf = [0xff_u8; Pvt::LEN];
+        legacy.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(&buf[..79], &frame.message[..79]);
+        assert_eq!(&buf[79..], &[0; 13]);
+    }
 }
@@ -0,0 +1,128 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Odometer solution.
+///
+/// Reports the traveled ground distance since the last reset of the
+/// odometer, as well as the total cumulative ground distance.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Odo {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Ground distance since last reset.
+    ///
+    /// ### Unit
+    /// meter
+    pub distance: U4,
+
+    /// Total cumulative ground distance.
+    ///
+    /// ### Unit
+    /// meter
+    pub totalDistance: U4,
+
+    /// Ground distance accuracy (1-sigma).
+    ///
+    /// ### Unit
+    /// meter
+    pub distanceStd: U4,
+}
+
+impl Odo {
+    /// Returns ground distance since last reset in meters.
+    pub fn distance_m(&self) -> f64 {
+        f64::from(self.distance)
+    }
+
+    /// Returns total cumulative ground distance in meters.
+    pub fn total_distance_m(&self) -> f64 {
+        f64::from(self.totalDistance)
+    }
+
+    /// Returns ground distance accuracy (1-sigma) in meters.
+    pub fn distance_std_m(&self) -> f64 {
+        f64::from(self.distanceStd)
+    }
+}
+
+impl Message for Odo {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x09;
+    const LEN: usize = 20;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &Odo {
+            version,
+            iTOW,
+            distance,
+            totalDistance,
+            distanceStd,
+        } = self;
+
+        dst.put_u8(version);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+        dst.put_u32_le(iTOW);
+        dst.put_u32_le(distance);
+        dst.put_u32_le(totalDistance);
+        dst.put_u32_le(distanceStd);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        // reserved1
+        src.advance(3);
+        let iTOW = src.get_u32_le();
+        let distance = src.get_u32_le();
+        let totalDistance = src.get_u32_le();
+        let distanceStd = src.get_u32_le();
+
+        Ok(Odo {
+            version,
+            iTOW,
+            distance,
+            totalDistance,
+            distanceStd,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = Odo {
+            version: 0,
+            iTOW: 403_200_000,
+            distance: 1_234,
+            totalDistance: 98_765_432,
+            distanceStd: 12,
+        };
+        let mut buf = [0_u8; Odo::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        let decoded = Odo::deserialize(&mut buf.as_ref()).unwrap();
+        assert_eq!(msg, decoded);
+        assert_eq!(decoded.distance_m(), 1_234.0);
+        assert_eq!(decoded.total_distance_m(), 98_765_432.0);
+        assert_eq!(decoded.distance_std_m(), 12.0);
+    }
+}
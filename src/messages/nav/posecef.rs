@@ -0,0 +1,130 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Position solution in ECEF.
+///
+/// See important comments concerning validity of position given in
+/// section Navigation Output Filters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PosEcef {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// ECEF X coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub ecefX: I4,
+
+    /// ECEF Y coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub ecefY: I4,
+
+    /// ECEF Z coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub ecefZ: I4,
+
+    /// Position Accuracy Estimate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub pAcc: U4,
+}
+
+impl PosEcef {
+    /// Returns ECEF X coordinate in meters.
+    pub fn ecef_x_m(&self) -> f64 {
+        f64::from(self.ecefX) * 1e-2
+    }
+
+    /// Returns ECEF Y coordinate in meters.
+    pub fn ecef_y_m(&self) -> f64 {
+        f64::from(self.ecefY) * 1e-2
+    }
+
+    /// Returns ECEF Z coordinate in meters.
+    pub fn ecef_z_m(&self) -> f64 {
+        f64::from(self.ecefZ) * 1e-2
+    }
+
+    /// Returns position accuracy estimate in meters.
+    pub fn p_acc_m(&self) -> f64 {
+        f64::from(self.pAcc) * 1e-2
+    }
+}
+
+impl Message for PosEcef {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x01;
+    const LEN: usize = 20;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &PosEcef {
+            iTOW,
+            ecefX,
+            ecefY,
+            ecefZ,
+            pAcc,
+        } = self;
+
+        dst.put_u32_le(iTOW);
+        dst.put_i32_le(ecefX);
+        dst.put_i32_le(ecefY);
+        dst.put_i32_le(ecefZ);
+        dst.put_u32_le(pAcc);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let ecefX = src.get_i32_le();
+        let ecefY = src.get_i32_le();
+        let ecefZ = src.get_i32_le();
+        let pAcc = src.get_u32_le();
+
+        Ok(PosEcef {
+            iTOW,
+            ecefX,
+            ecefY,
+            ecefZ,
+            pAcc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = PosEcef {
+            iTOW: 403_200_000,
+            ecefX: -274_298_471,
+            ecefY: -446_373_203,
+            ecefZ: 395_049_927,
+            pAcc: 152,
+        };
+        let mut buf = [0_u8; PosEcef::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(msg, PosEcef::deserialize(&mut buf.as_ref()).unwrap());
+        assert_eq!(msg.ecef_x_m(), -2_742_984.71);
+        assert_eq!(msg.p_acc_m(), 1.52);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::messages::{primitive::*, Message};
+use crate::messages::{nav::FixType, primitive::*, DecodeFields, FieldValue, Message};
+use alloc::vec::Vec;
 use bitfield::bitfield;
 
 /// This message combines position, velocity and time solution,
@@ -180,7 +181,7 @@ pub struct Pvt {
     ///
     /// ### Unit
     /// -
-    flags3: X1,
+    flags3: Flags3,
 
     // Reserved
     // ### Unit
@@ -203,6 +204,10 @@ pub struct Pvt {
     /// ### Unit
     /// deg
     macAcc: U2,
+
+    // Set if decoded from the legacy 84-byte payload, in which case
+    // `headVeh`, `magDec`, and `macAcc` are absent and set to zero.
+    legacy: bool,
 }
 
 bitfield! {
@@ -237,7 +242,7 @@ bitfield! {
     /// heading of vehicle is valid, only set if the receiver is in
     /// sensor fusion mode
     pub headVehValid, _: 5;
-    /// Undocumented
+    /// Power save mode state, see [`PowerSaveState`]
     pub psmState, _: 4, 2;
     /// differential corrections were applied
     pub diffSoln, _: 1;
@@ -256,13 +261,394 @@ bitfield! {
     ///
     /// This flag is only supported in Protocol Versions 19.00, 19.10,
     /// 20.10, 20.20, 20.30, 22.00, 23.00, 23.01, 27 and 28.
-    pub confirmedAvai, _: 7;
+    pub confirmedAvai, _: 5;
     /// UTC Date validity could be confirmed (see Time Validity
     /// section for details)
     pub confirmedDate, _: 6;
     /// UTC Time of Day could be confirmed (see Time Validity section
     /// for details)
-    pub confirmedTime, _: 5;
+    pub confirmedTime, _: 7;
+}
+
+bitfield! {
+    /// Bitfield `flags3`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct Flags3(X1);
+    impl Debug;
+    u8;
+    /// Age of the most recently received differential correction.
+    ///
+    /// - 0 Not available
+    /// - 1 Age between 0 and 1 second
+    /// - 2 Age between 1 (inclusive) and 2 seconds
+    /// - 3 Age between 2 (inclusive) and 5 seconds
+    /// - 4 Age between 5 (inclusive) and 10 seconds
+    /// - 5 Age between 10 (inclusive) and 15 seconds
+    /// - 6 Age between 15 (inclusive) and 20 seconds
+    /// - 7 Age between 20 (inclusive) and 30 seconds
+    /// - 8 Age between 30 (inclusive) and 45 seconds
+    /// - 9 Age between 45 (inclusive) and 60 seconds
+    /// - 10 Age between 60 (inclusive) and 90 seconds
+    /// - 11 Age between 90 (inclusive) and 120 seconds
+    /// - >=12 Age greater or equal than 120 seconds
+    ///
+    /// Only supported in protocol versions 27.20 and later.
+    pub last_correction_age, _: 4, 1;
+    /// Invalid lon, lat, height and hMSL.
+    pub invalid_llh, _: 0;
+}
+
+/// Carrier phase range solution status.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CarrierSolution {
+    /// No carrier phase range solution.
+    None,
+    /// Carrier phase range solution with floating ambiguities.
+    Float,
+    /// Carrier phase range solution with fixed ambiguities.
+    Fixed,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for CarrierSolution {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => CarrierSolution::None,
+            1 => CarrierSolution::Float,
+            2 => CarrierSolution::Fixed,
+            other => CarrierSolution::Unknown(other),
+        }
+    }
+}
+
+impl From<CarrierSolution> for u8 {
+    fn from(carr_soln: CarrierSolution) -> Self {
+        match carr_soln {
+            CarrierSolution::None => 0,
+            CarrierSolution::Float => 1,
+            CarrierSolution::Fixed => 2,
+            CarrierSolution::Unknown(other) => other,
+        }
+    }
+}
+
+/// Power save mode state.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PowerSaveState {
+    /// Power save mode is not active.
+    Disabled,
+    /// Enabled, an intermediate state before acquisition.
+    Enabled,
+    /// Acquisition.
+    Acquisition,
+    /// Tracking.
+    Tracking,
+    /// Power optimized tracking.
+    PowerOptimizedTracking,
+    /// Inactive.
+    Inactive,
+    /// Undocumented value.
+    Unknown(u8),
+}
+
+impl From<u8> for PowerSaveState {
+    fn from(raw: u8) -> Self {
+        match raw {
+            0 => PowerSaveState::Disabled,
+            1 => PowerSaveState::Enabled,
+            2 => PowerSaveState::Acquisition,
+            3 => PowerSaveState::Tracking,
+            4 => PowerSaveState::PowerOptimizedTracking,
+            5 => PowerSaveState::Inactive,
+            other => PowerSaveState::Unknown(other),
+        }
+    }
+}
+
+impl From<PowerSaveState> for u8 {
+    fn from(psm_state: PowerSaveState) -> Self {
+        match psm_state {
+            PowerSaveState::Disabled => 0,
+            PowerSaveState::Enabled => 1,
+            PowerSaveState::Acquisition => 2,
+            PowerSaveState::Tracking => 3,
+            PowerSaveState::PowerOptimizedTracking => 4,
+            PowerSaveState::Inactive => 5,
+            PowerSaveState::Unknown(other) => other,
+        }
+    }
+}
+
+impl Pvt {
+    /// Payload length of the legacy form, which lacks the trailing
+    /// `headVeh`, `magDec`, and `magAcc` fields.
+    pub const LEN_LEGACY: usize = 84;
+
+    /// Returns a builder for a `Pvt` with all fields zeroed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ublox::messages::{nav::{FixType, Pvt}, primitive::Deg1e7};
+    /// let pvt = Pvt::builder()
+    ///     .itow(403_200_000)
+    ///     .date(2020, 4, 17)
+    ///     .time(9, 30, 15)
+    ///     .fix_type(FixType::Fix3D)
+    ///     .num_sv(12)
+    ///     .lat(Deg1e7::from_f64(40.7128))
+    ///     .lon(Deg1e7::from_f64(-74.006))
+    ///     .height(10_000)
+    ///     .build();
+    /// assert_eq!(pvt.fix_type(), FixType::Fix3D);
+    /// assert_eq!(pvt.lat().as_f64(), 40.7128);
+    /// ```
+    pub fn builder() -> PvtBuilder {
+        PvtBuilder(Pvt {
+            TOW: 0,
+            year: 0,
+            month: 0,
+            day: 0,
+            hour: 0,
+            min: 0,
+            sec: 0,
+            valid: Valid(0),
+            tAcc: 0,
+            nano: 0,
+            fxType: 0,
+            flags: Flags(0),
+            flags2: Flags2(0),
+            numSV: 0,
+            lon: 0,
+            lat: 0,
+            height: 0,
+            hMSL: 0,
+            hAcc: 0,
+            vAcc: 0,
+            velN: 0,
+            velE: 0,
+            velD: 0,
+            gSpeed: 0,
+            headMot: 0,
+            sAcc: 0,
+            headAcc: 0,
+            pDOP: 0,
+            flags3: Flags3(0),
+            headVeh: 0,
+            magDec: 0,
+            macAcc: 0,
+            legacy: false,
+        })
+    }
+
+    /// Returns `true` if `self` was decoded from a payload containing
+    /// the vehicle heading and magnetic declination fields.
+    ///
+    /// When `false`, those fields are set to zero.
+    pub fn has_mag_declination(&self) -> bool {
+        !self.legacy
+    }
+
+    /// Returns GPS time of week of the navigation epoch in
+    /// milliseconds.
+    pub fn itow(&self) -> U4 {
+        self.TOW
+    }
+
+    /// Returns GNSS fix type.
+    pub fn fix_type(&self) -> FixType {
+        FixType::from(self.fxType)
+    }
+
+    /// Returns carrier phase range solution status.
+    pub fn carrier_solution(&self) -> CarrierSolution {
+        CarrierSolution::from(self.flags.carrSoln())
+    }
+
+    /// Returns power save mode state.
+    pub fn power_save_state(&self) -> PowerSaveState {
+        PowerSaveState::from(self.flags.psmState())
+    }
+
+    /// Returns latitude.
+    pub fn lat(&self) -> Deg1e7 {
+        Deg1e7(self.lat)
+    }
+
+    /// Returns longitude.
+    pub fn lon(&self) -> Deg1e7 {
+        Deg1e7(self.lon)
+    }
+
+    /// Returns heading of motion (2-D).
+    pub fn head_mot(&self) -> Deg1e5 {
+        Deg1e5(self.headMot)
+    }
+
+    /// Returns additional flags.
+    pub fn flags3(&self) -> Flags3 {
+        self.flags3
+    }
+
+    /// Returns position DOP.
+    pub fn p_dop(&self) -> DopScaled {
+        DopScaled(self.pDOP)
+    }
+
+    /// Returns NED velocity, `(north, east, down)`, in meters per
+    /// second.
+    pub fn velocity_ned_mps(&self) -> (f64, f64, f64) {
+        (
+            f64::from(self.velN) * 1e-3,
+            f64::from(self.velE) * 1e-3,
+            f64::from(self.velD) * 1e-3,
+        )
+    }
+
+    /// Returns ground speed (2-D) in meters per second.
+    pub fn ground_speed_mps(&self) -> f64 {
+        f64::from(self.gSpeed) * 1e-3
+    }
+
+    /// Returns heading of motion (2-D) in degrees.
+    ///
+    /// Heading of motion is only meaningful while moving, i.e. when
+    /// [`ground_speed_mps()`](#method.ground_speed_mps) is nonzero.
+    pub fn heading_of_motion_deg(&self) -> f64 {
+        self.head_mot().as_f64()
+    }
+
+    /// Returns speed accuracy estimate in meters per second.
+    pub fn speed_accuracy_mps(&self) -> f64 {
+        f64::from(self.sAcc) * 1e-3
+    }
+}
+
+/// Builds a [`Pvt`], e.g. as a test fixture.
+///
+/// Fields which are not set are zero, except that setting the date
+/// or time also sets the corresponding validity flag, and setting a
+/// 2D, 3D, or combined fix type also sets `gnssFixOK`.
+#[derive(Clone, Debug)]
+pub struct PvtBuilder(Pvt);
+
+impl PvtBuilder {
+    /// Sets GPS time of week of the navigation epoch, in milliseconds.
+    pub fn itow(mut self, itow: U4) -> Self {
+        self.0.TOW = itow;
+        self
+    }
+
+    /// Sets UTC date and marks it valid.
+    pub fn date(mut self, year: U2, month: U1, day: U1) -> Self {
+        self.0.year = year;
+        self.0.month = month;
+        self.0.day = day;
+        self.0.valid.0 |= 1 << 0;
+        self
+    }
+
+    /// Sets UTC time of day and marks it valid.
+    pub fn time(mut self, hour: U1, min: U1, sec: U1) -> Self {
+        self.0.hour = hour;
+        self.0.min = min;
+        self.0.sec = sec;
+        self.0.valid.0 |= 1 << 1;
+        self
+    }
+
+    /// Sets GNSS fix type.
+    pub fn fix_type(mut self, fix_type: FixType) -> Self {
+        self.0.fxType = fix_type.into();
+        let fix_ok = matches!(
+            fix_type,
+            FixType::Fix2D | FixType::Fix3D | FixType::GnssDeadReckoning
+        );
+        self.0.flags.0 = (self.0.flags.0 & !1) | u8::from(fix_ok);
+        self
+    }
+
+    /// Sets number of satellites used in the navigation solution.
+    pub fn num_sv(mut self, num_sv: U1) -> Self {
+        self.0.numSV = num_sv;
+        self
+    }
+
+    /// Sets latitude.
+    pub fn lat(mut self, lat: Deg1e7) -> Self {
+        self.0.lat = lat.0;
+        self
+    }
+
+    /// Sets longitude.
+    pub fn lon(mut self, lon: Deg1e7) -> Self {
+        self.0.lon = lon.0;
+        self
+    }
+
+    /// Sets height above ellipsoid, in millimeters.
+    pub fn height(mut self, height: I4) -> Self {
+        self.0.height = height;
+        self
+    }
+
+    /// Sets height above mean sea level, in millimeters.
+    pub fn height_msl(mut self, height_msl: I4) -> Self {
+        self.0.hMSL = height_msl;
+        self
+    }
+
+    /// Returns the built [`Pvt`].
+    pub fn build(self) -> Pvt {
+        self.0
+    }
+}
+
+impl DecodeFields for Pvt {
+    /// `headVeh`, `magDec`, and `magAcc` are omitted when decoded
+    /// from the legacy payload.
+    fn fields(&self) -> Vec<(&'static str, FieldValue)> {
+        let mut fields = alloc::vec![
+            ("iTOW", FieldValue::U4(self.TOW)),
+            ("year", FieldValue::U2(self.year)),
+            ("month", FieldValue::U1(self.month)),
+            ("day", FieldValue::U1(self.day)),
+            ("hour", FieldValue::U1(self.hour)),
+            ("min", FieldValue::U1(self.min)),
+            ("sec", FieldValue::U1(self.sec)),
+            ("valid", FieldValue::X1(self.valid.0)),
+            ("tAcc", FieldValue::U4(self.tAcc)),
+            ("nano", FieldValue::I4(self.nano)),
+            ("fixType", FieldValue::U1(self.fxType)),
+            ("flags", FieldValue::X1(self.flags.0)),
+            ("flags2", FieldValue::X1(self.flags2.0)),
+            ("numSV", FieldValue::U1(self.numSV)),
+            ("lon", FieldValue::I4(self.lon)),
+            ("lat", FieldValue::I4(self.lat)),
+            ("height", FieldValue::I4(self.height)),
+            ("hMSL", FieldValue::I4(self.hMSL)),
+            ("hAcc", FieldValue::U4(self.hAcc)),
+            ("vAcc", FieldValue::U4(self.vAcc)),
+            ("velN", FieldValue::I4(self.velN)),
+            ("velE", FieldValue::I4(self.velE)),
+            ("velD", FieldValue::I4(self.velD)),
+            ("gSpeed", FieldValue::I4(self.gSpeed)),
+            ("headMot", FieldValue::I4(self.headMot)),
+            ("sAcc", FieldValue::U4(self.sAcc)),
+            ("headAcc", FieldValue::U4(self.headAcc)),
+            ("pDOP", FieldValue::U2(self.pDOP)),
+            ("flags3", FieldValue::X1(self.flags3.0)),
+        ];
+        if !self.legacy {
+            fields.extend_from_slice(&[
+                ("headVeh", FieldValue::I4(self.headVeh)),
+                ("magDec", FieldValue::I2(self.magDec)),
+                ("magAcc", FieldValue::U2(self.macAcc)),
+            ]);
+        }
+        fields
+    }
 }
 
 impl Message for Pvt {
@@ -308,6 +694,7 @@ impl Message for Pvt {
             headVeh,
             magDec,
             macAcc,
+            legacy: _,
         } = self;
 
         dst.put_u32_le(TOW);
@@ -338,7 +725,7 @@ impl Message for Pvt {
         dst.put_u32_le(sAcc);
         dst.put_u32_le(headAcc);
         dst.put_u16_le(pDOP);
-        dst.put_u8(flags3);
+        dst.put_u8(flags3.0);
         // reserved1
         dst.put_slice([0_u8; 5].as_ref());
         dst.put_i32_le(headVeh);
@@ -348,10 +735,14 @@ impl Message for Pvt {
         Ok(())
     }
 
+    /// Deserializes either the current 92-byte payload or the legacy
+    /// 84-byte payload.
     fn deserialize<B: bytes::Buf>(src: &mut B) -> Result<Self, ()> {
-        if src.remaining() < Self::LEN {
-            return Err(());
-        }
+        let legacy = match src.remaining() {
+            n if n >= Self::LEN => false,
+            Self::LEN_LEGACY => true,
+            _ => return Err(()),
+        };
 
         let TOW = src.get_u32_le();
         let year = src.get_u16_le();
@@ -381,12 +772,14 @@ impl Message for Pvt {
         let sAcc = src.get_u32_le();
         let headAcc = src.get_u32_le();
         let pDOP = src.get_u16_le();
-        let flags3 = src.get_u8();
+        let flags3 = Flags3(src.get_u8());
         // reserved1
         src.advance(5);
-        let headVeh = src.get_i32_le();
-        let magDec = src.get_i16_le();
-        let macAcc = src.get_u16_le();
+        let (headVeh, magDec, macAcc) = if legacy {
+            (0, 0, 0)
+        } else {
+            (src.get_i32_le(), src.get_i16_le(), src.get_u16_le())
+        };
 
         Ok(Self {
             TOW,
@@ -421,6 +814,199 @@ impl Message for Pvt {
             headVeh,
             magDec,
             macAcc,
+            legacy,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_roundtrip() {
+        let payload: Vec<u8> = (0..Pvt::LEN_LEGACY as u8).collect();
+        let legacy = Pvt::deserialize(&mut payload.as_slice()).unwrap();
+        assert!(!legacy.has_mag_declination());
+
+        let mut buf = [0xff_u8; Pvt::LEN];
+        legacy.serialize(&mut buf.as_mut()).unwrap();
+        // The 92-byte form carries the same fields, plus zeroed
+        // `headVeh`, `magDec`, and `magAcc`.
+        let current = Pvt::deserialize(&mut buf.as_ref()).unwrap();
+        assert!(current.has_mag_declination());
+        let (fields, legacy_fields) = (current.fields(), legacy.fields());
+        let (common, mag) = fields.split_at(legacy_fields.len());
+        assert_eq!(common, legacy_fields.as_slice());
+        assert_eq!(
+            mag,
+            &[
+                ("headVeh", FieldValue::I4(0)),
+                ("magDec", FieldValue::I2(0)),
+                ("magAcc", FieldValue::U2(0)),
+            ]
+        );
+        let mut reserialized = [0xff_u8; Pvt::LEN];
+        current.serialize(&mut reserialized.as_mut()).unwrap();
+        assert_eq!(reserialized, buf);
+    }
+
+    #[test]
+    fn test_si_accessors() {
+        let mut payload = [0_u8; Pvt::LEN];
+        payload[48..52].copy_from_slice(&1_500_i32.to_le_bytes());
+        payload[52..56].copy_from_slice(&(-250_i32).to_le_bytes());
+        payload[56..60].copy_from_slice(&10_i32.to_le_bytes());
+        payload[60..64].copy_from_slice(&1_520_i32.to_le_bytes());
+        payload[64..68].copy_from_slice(&35_512_345_i32.to_le_bytes());
+        payload[68..72].copy_from_slice(&120_u32.to_le_bytes());
+        let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+
+        assert_eq!(pvt.velocity_ned_mps(), (1.5, -0.25, 0.01));
+        assert_eq!(pvt.ground_speed_mps(), 1.52);
+        assert_eq!(pvt.heading_of_motion_deg(), 355.12345);
+        assert_eq!(pvt.speed_accuracy_mps(), 0.12);
+    }
+
+    #[test]
+    fn test_builder() {
+        let pvt = Pvt::builder()
+            .date(2020, 4, 17)
+            .time(9, 30, 15)
+            .fix_type(FixType::Fix3D)
+            .build();
+        assert!(pvt.valid.validDate());
+        assert!(pvt.valid.validTime());
+        assert!(pvt.flags.gnssFixOK());
+
+        let mut buf = [0_u8; Pvt::LEN];
+        pvt.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(pvt, Pvt::deserialize(&mut buf.as_ref()).unwrap());
+
+        let pvt = PvtBuilder(pvt).fix_type(FixType::NoFix).build();
+        assert!(!pvt.flags.gnssFixOK());
+    }
+
+    #[test]
+    fn test_flags3() {
+        let mut payload = [0_u8; Pvt::LEN];
+        payload[78] = 0b0000_1011;
+        let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+        assert!(pvt.flags3().invalid_llh());
+        assert_eq!(pvt.flags3().last_correction_age(), 5);
+        assert_eq!(pvt.flags3().0, 0b0000_1011);
+
+        payload[78] = 0b0000_1010;
+        let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+        assert!(!pvt.flags3().invalid_llh());
+    }
+
+    #[test]
+    fn test_deserialize_into() {
+        let pvt = Pvt::builder()
+            .itow(403_200_000)
+            .date(2020, 4, 17)
+            .fix_type(FixType::Fix3D)
+            .lat(Deg1e7::from_f64(40.7128))
+            .build();
+        let mut payload = [0_u8; Pvt::LEN];
+        pvt.serialize(&mut payload.as_mut()).unwrap();
+
+        let mut reused = Pvt::builder().itow(1).num_sv(4).build();
+        reused.deserialize_into(&mut payload.as_ref()).unwrap();
+        assert_eq!(reused, Pvt::deserialize(&mut payload.as_ref()).unwrap());
+        assert_eq!(reused, pvt);
+
+        assert!(reused.deserialize_into(&mut &payload[..10]).is_err());
+        assert_eq!(reused, pvt);
+    }
+
+    #[test]
+    fn test_bitfields() {
+        assert!(Valid(0x01).validDate() && !Valid(0x0e).validDate());
+        assert!(Valid(0x02).validTime() && !Valid(0x0d).validTime());
+        assert!(Valid(0x04).fullyResolved() && !Valid(0x0b).fullyResolved());
+        assert!(Valid(0x08).validMag() && !Valid(0x07).validMag());
+
+        assert!(Flags(0x01).gnssFixOK() && !Flags(0xfe).gnssFixOK());
+        assert!(Flags(0x02).diffSoln() && !Flags(0xfd).diffSoln());
+        assert_eq!(Flags(0x1c).psmState(), 7);
+        assert_eq!(Flags(0x04).psmState(), 1);
+        assert_eq!(Flags(0xe3).psmState(), 0);
+        assert!(Flags(0x20).headVehValid() && !Flags(0xdf).headVehValid());
+        assert_eq!(Flags(0x40).carrSoln(), 1);
+        assert_eq!(Flags(0x80).carrSoln(), 2);
+        assert_eq!(Flags(0x3f).carrSoln(), 0);
+
+        assert!(Flags2(0x20).confirmedAvai() && !Flags2(0xdf).confirmedAvai());
+        assert!(Flags2(0x40).confirmedDate() && !Flags2(0xbf).confirmedDate());
+        assert!(Flags2(0x80).confirmedTime() && !Flags2(0x7f).confirmedTime());
+
+        assert!(Flags3(0x01).invalid_llh() && !Flags3(0x1e).invalid_llh());
+        assert_eq!(Flags3(0x02).last_correction_age(), 1);
+        assert_eq!(Flags3(0x18).last_correction_age(), 12);
+        assert_eq!(Flags3(0xe1).last_correction_age(), 0);
+    }
+
+    #[test]
+    fn test_carrier_solution() {
+        let cases = [
+            (0b0000_0000, CarrierSolution::None),
+            (0b0100_0000, CarrierSolution::Float),
+            (0b1000_0000, CarrierSolution::Fixed),
+            (0b1100_0000, CarrierSolution::Unknown(3)),
+        ];
+        let mut payload = [0_u8; Pvt::LEN];
+        for &(flags, expected) in cases.iter() {
+            payload[21] = flags | 0x01;
+            let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+            assert_eq!(pvt.carrier_solution(), expected);
+            assert_eq!(u8::from(expected), flags >> 6);
+        }
+    }
+
+    #[test]
+    fn test_power_save_state() {
+        let cases = [
+            (0, PowerSaveState::Disabled),
+            (1, PowerSaveState::Enabled),
+            (2, PowerSaveState::Acquisition),
+            (3, PowerSaveState::Tracking),
+            (4, PowerSaveState::PowerOptimizedTracking),
+            (5, PowerSaveState::Inactive),
+            (6, PowerSaveState::Unknown(6)),
+            (7, PowerSaveState::Unknown(7)),
+        ];
+        let mut payload = [0_u8; Pvt::LEN];
+        for &(raw, expected) in cases.iter() {
+            payload[21] = (raw << 2) | 0xe3;
+            let pvt = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+            assert_eq!(pvt.power_save_state(), expected);
+            assert_eq!(u8::from(expected), raw);
+        }
+    }
+
+    #[test]
+    fn test_fields() {
+        let pvt = Pvt::builder()
+            .itow(403_200_000)
+            .fix_type(FixType::Fix3D)
+            .num_sv(12)
+            .lat(Deg1e7::from_f64(40.7128))
+            .build();
+        let fields = pvt.fields();
+        assert_eq!(fields.len(), 32);
+        assert_eq!(fields[0], ("iTOW", FieldValue::U4(403_200_000)));
+        assert_eq!(fields[10], ("fixType", FieldValue::U1(3)));
+        assert_eq!(fields[11], ("flags", FieldValue::X1(0x01)));
+        assert_eq!(fields[13], ("numSV", FieldValue::U1(12)));
+        assert_eq!(fields[15], ("lat", FieldValue::I4(407_128_000)));
+        assert_eq!(fields[31], ("magAcc", FieldValue::U2(0)));
+
+        let payload = [0_u8; Pvt::LEN_LEGACY];
+        let legacy = Pvt::deserialize(&mut payload.as_ref()).unwrap();
+        let fields = legacy.fields();
+        assert_eq!(fields.len(), 29);
+        assert_eq!(fields[28].0, "flags3");
+    }
+}
@@ -0,0 +1,284 @@
+use crate::framing::FrameVec;
+use crate::messages::{primitive::*, VarMessage};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::{convert::TryFrom, slice::ChunksExact};
+
+/// Satellite information.
+///
+/// Displays information about SVs that are either known to be
+/// visible or currently tracked by the receiver. Per-satellite
+/// records are kept in their wire format and decoded on demand with
+/// [`Sat::iter`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sat {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x01 for this version).
+    pub version: U1,
+
+    /// Repeated per-satellite blocks, `SatIter::BLOCK_LEN` bytes each.
+    blocks: FrameVec,
+}
+
+/// Information about a single satellite, decoded from one repeated
+/// block of a NAV-SAT message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SatInfo {
+    /// GNSS identifier, see [`GnssId`].
+    pub gnssId: U1,
+
+    /// Satellite identifier.
+    pub svId: U1,
+
+    /// Carrier to noise ratio (signal strength).
+    ///
+    /// ### Unit
+    /// dBHz
+    pub cno: U1,
+
+    /// Elevation (range: +/-90), unknown if out of range.
+    ///
+    /// ### Unit
+    /// degree
+    pub elev: I1,
+
+    /// Azimuth (range 0-360), unknown if elevation is out of range.
+    ///
+    /// ### Unit
+    /// degree
+    pub azim: I2,
+
+    /// Pseudorange residual.
+    ///
+    /// ### Unit
+    /// 0.1 meter
+    pub prRes: I2,
+
+    /// Bitmask.
+    pub flags: SatFlags,
+}
+
+impl SatInfo {
+    /// Returns the GNSS this satellite belongs to, or the raw identifier if
+    /// it is not a known GNSS.
+    pub fn gnss_id(&self) -> Result<GnssId, U1> {
+        GnssId::try_from(self.gnssId).map_err(|()| self.gnssId)
+    }
+}
+
+bitfield! {
+    /// NAV-SAT per-satellite flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct SatFlags(X4);
+    impl Debug;
+    u8;
+    /// Carrier range corrections have been used for this SV.
+    pub cr_corr_used, _: 21;
+    /// Pseudorange corrections have been used for this SV.
+    pub pr_corr_used, _: 20;
+    /// Orbit information is available for this SV.
+    pub orbit_source, _: 10, 8;
+    /// Differential correction data is available for this SV.
+    pub diff_corr, _: 6;
+    /// SV health.
+    ///
+    /// - 0 unknown
+    /// - 1 healthy
+    /// - 2 unhealthy
+    pub health, _: 5, 4;
+    /// Signal in the subset specified in Signal Identifiers is
+    /// currently being used for navigation.
+    pub sv_used, _: 3;
+    /// Signal quality indicator.
+    pub quality_ind, _: 2, 0;
+}
+
+impl Sat {
+    const LEN_HEADER: usize = 8;
+
+    /// Returns the number of satellites in this message.
+    pub fn num_svs(&self) -> usize {
+        self.blocks.len() / SatIter::BLOCK_LEN
+    }
+
+    /// Returns an iterator over the per-satellite records.
+    pub fn iter(&self) -> SatIter<'_> {
+        SatIter::new(&self.blocks)
+    }
+}
+
+impl<'a> IntoIterator for &'a Sat {
+    type Item = SatInfo;
+    type IntoIter = SatIter<'a>;
+
+    fn into_iter(self) -> SatIter<'a> {
+        self.iter()
+    }
+}
+
+/// Iterator over the per-satellite records of a NAV-SAT message.
+///
+/// `SatIter` borrows the repeated blocks and decodes each [`SatInfo`]
+/// as it is yielded, so walking every satellite in an epoch does not
+/// allocate. Use [`SatIter::from_payload`] to iterate directly over a
+/// received frame's payload without building a [`Sat`] at all.
+#[derive(Clone, Debug)]
+pub struct SatIter<'a> {
+    blocks: ChunksExact<'a, u8>,
+}
+
+impl<'a> SatIter<'a> {
+    /// Length of each repeated block.
+    pub const BLOCK_LEN: usize = 12;
+
+    fn new(blocks: &'a [u8]) -> Self {
+        SatIter {
+            blocks: blocks.chunks_exact(Self::BLOCK_LEN),
+        }
+    }
+
+    /// Returns an iterator over the per-satellite records in a
+    /// complete NAV-SAT payload.
+    ///
+    /// Returns an error if the payload length does not match its
+    /// declared number of satellites.
+    pub fn from_payload(payload: &'a [u8]) -> Result<Self, ()> {
+        let blocks = payload.get(Sat::LEN_HEADER..).ok_or(())?;
+        let num_svs = usize::from(payload[5]);
+        if blocks.len() != num_svs * Self::BLOCK_LEN {
+            return Err(());
+        }
+        Ok(Self::new(blocks))
+    }
+}
+
+impl<'a> Iterator for SatIter<'a> {
+    type Item = SatInfo;
+
+    fn next(&mut self) -> Option<SatInfo> {
+        let mut block = self.blocks.next()?;
+        Some(SatInfo {
+            gnssId: block.get_u8(),
+            svId: block.get_u8(),
+            cno: block.get_u8(),
+            elev: block.get_i8(),
+            azim: block.get_i16_le(),
+            prRes: block.get_i16_le(),
+            flags: SatFlags(block.get_u32_le()),
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.blocks.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for SatIter<'a> {}
+
+impl VarMessage for Sat {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x35;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + self.blocks.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let num_svs = U1::try_from(self.num_svs()).map_err(|_| ())?;
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        dst.put_u8(num_svs);
+        // reserved1
+        dst.put_u16_le(0);
+        dst.put_slice(&self.blocks);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        let num_svs = usize::from(src.get_u8());
+        // reserved1
+        src.advance(2);
+        if src.remaining() != num_svs * SatIter::BLOCK_LEN {
+            return Err(());
+        }
+        let mut blocks = alloc::vec![0; src.remaining()];
+        src.copy_to_slice(&mut blocks);
+
+        Ok(Sat {
+            iTOW,
+            version,
+            blocks,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    const PAYLOAD: [u8; 32] = [
+        0x10, 0x27, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, //
+        0x00, 0x0c, 0x2a, 0x2d, 0x3c, 0x00, 0xf6, 0xff, 0x1f, 0x00, 0x00, 0x00, //
+        0x06, 0x05, 0x00, 0xa6, 0x00, 0x00, 0x00, 0x00, 0x20, 0x00, 0x00, 0x00,
+    ];
+
+    #[test]
+    fn test_can_parse() {
+        let msg = Sat::deserialize(&mut PAYLOAD.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 10_000);
+        assert_eq!(msg.num_svs(), 2);
+
+        let svs: Vec<SatInfo> = msg.iter().collect();
+        assert_eq!(svs[0].gnssId, 0);
+        assert_eq!(svs[0].gnss_id(), Ok(GnssId::Gps));
+        assert_eq!(svs[0].svId, 12);
+        assert_eq!(svs[0].cno, 42);
+        assert_eq!(svs[0].elev, 45);
+        assert_eq!(svs[0].azim, 60);
+        assert_eq!(svs[0].prRes, -10);
+        assert_eq!(svs[0].flags.quality_ind(), 7);
+        assert!(svs[0].flags.sv_used());
+        assert_eq!(svs[0].flags.health(), 1);
+        assert_eq!(svs[1].gnss_id(), Ok(GnssId::Glonass));
+        assert_eq!(
+            SatInfo {
+                gnssId: 7,
+                ..svs[1]
+            }
+            .gnss_id(),
+            Err(7)
+        );
+        assert_eq!(svs[1].elev, -90);
+        assert_eq!(svs[1].flags.health(), 2);
+
+        let mut buf = [0_u8; 32];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, PAYLOAD);
+    }
+
+    #[test]
+    fn test_iter_from_payload() {
+        let iter = SatIter::from_payload(&PAYLOAD).unwrap();
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.map(|sv| sv.svId).collect::<Vec<_>>(), [12, 5]);
+        assert!(SatIter::from_payload(&PAYLOAD[..31]).is_err());
+        assert!(Sat::deserialize(&mut PAYLOAD[..20].as_ref()).is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// Signal information.
+///
+/// Displays information about signals currently tracked by the
+/// receiver. Supported by M9 and F9 receivers.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Sig {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// Information about each signal.
+    pub sigs: Vec<SigInfo>,
+}
+
+/// Information about a single signal, decoded from one repeated block
+/// of a NAV-SIG message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SigInfo {
+    /// GNSS identifier, see [`GnssId`].
+    pub gnssId: U1,
+
+    /// Satellite identifier.
+    pub svId: U1,
+
+    /// Signal identifier, unique within `gnssId`.
+    pub sigId: U1,
+
+    /// GLONASS frequency slot + 7 (range from 0 to 13).
+    pub freqId: U1,
+
+    /// Pseudorange residual.
+    ///
+    /// ### Unit
+    /// 0.1 meter
+    pub prRes: I2,
+
+    /// Carrier-to-noise density ratio (signal strength).
+    ///
+    /// ### Unit
+    /// dBHz
+    pub cno: U1,
+
+    /// Signal quality indicator.
+    ///
+    /// - 0 no signal
+    /// - 1 searching signal
+    /// - 2 signal acquired
+    /// - 3 signal detected but unusable
+    /// - 4 code locked and time synchronized
+    /// - 5, 6, 7 code and carrier locked and time synchronized
+    pub qualityInd: U1,
+
+    /// Correction source.
+    ///
+    /// - 0 no corrections
+    /// - 1 SBAS corrections
+    /// - 2 BeiDou corrections
+    /// - 3 RTCM2 corrections
+    /// - 4 RTCM3 OSR corrections
+    /// - 5 RTCM3 SSR corrections
+    /// - 6 QZSS SLAS corrections
+    /// - 7 SPARTN corrections
+    pub corrSource: U1,
+
+    /// Ionospheric model used.
+    ///
+    /// - 0 no model
+    /// - 1 Klobuchar model transmitted by GPS
+    /// - 2 SBAS model
+    /// - 3 Klobuchar model transmitted by BeiDou
+    /// - 8 Iono delay derived from dual frequency observations
+    pub ionoModel: U1,
+
+    /// Signal related flags.
+    pub sigFlags: SigFlags,
+}
+
+impl SigInfo {
+    /// Returns the GNSS this signal belongs to, or the raw identifier if
+    /// it is not a known GNSS.
+    pub fn gnss_id(&self) -> Result<GnssId, U1> {
+        GnssId::try_from(self.gnssId).map_err(|()| self.gnssId)
+    }
+}
+
+impl Sig {
+    const LEN_HEADER: usize = 8;
+    const LEN_BLOCK: usize = 16;
+}
+
+impl VarMessage for Sig {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x43;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.sigs.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let num_sigs = U1::try_from(self.sigs.len()).map_err(|_| ())?;
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        dst.put_u8(num_sigs);
+        // reserved0
+        dst.put_u16_le(0);
+        for sig in &self.sigs {
+            dst.put_u8(sig.gnssId);
+            dst.put_u8(sig.svId);
+            dst.put_u8(sig.sigId);
+            dst.put_u8(sig.freqId);
+            dst.put_i16_le(sig.prRes);
+            dst.put_u8(sig.cno);
+            dst.put_u8(sig.qualityInd);
+            dst.put_u8(sig.corrSource);
+            dst.put_u8(sig.ionoModel);
+            dst.put_u16_le(sig.sigFlags.0);
+            // reserved1
+            dst.put_u32_le(0);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        let num_sigs = usize::from(src.get_u8());
+        // reserved0
+        src.advance(2);
+        if src.remaining() != num_sigs * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let sigs = (0..num_sigs)
+            .map(|_| {
+                let sig = SigInfo {
+                    gnssId: src.get_u8(),
+                    svId: src.get_u8(),
+                    sigId: src.get_u8(),
+                    freqId: src.get_u8(),
+                    prRes: src.get_i16_le(),
+                    cno: src.get_u8(),
+                    qualityInd: src.get_u8(),
+                    corrSource: src.get_u8(),
+                    ionoModel: src.get_u8(),
+                    sigFlags: SigFlags(src.get_u16_le()),
+                };
+                // reserved1
+                src.advance(4);
+                sig
+            })
+            .collect();
+
+        Ok(Sig {
+            iTOW,
+            version,
+            sigs,
+        })
+    }
+}
+
+bitfield! {
+    /// NAV-SIG per-signal flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct SigFlags(X2);
+    impl Debug;
+    u8;
+    /// Doppler corrections have been used for this signal.
+    pub do_corr_used, _: 8;
+    /// Carrier range corrections have been used for this signal.
+    pub cr_corr_used, _: 7;
+    /// Pseudorange corrections have been used for this signal.
+    pub pr_corr_used, _: 6;
+    /// Doppler is used for navigation.
+    pub do_used, _: 5;
+    /// Carrier range is used for navigation.
+    pub cr_used, _: 4;
+    /// Pseudorange is used for navigation.
+    pub pr_used, _: 3;
+    /// Pseudorange has been smoothed.
+    pub pr_smoothed, _: 2;
+    /// Signal health.
+    ///
+    /// - 0 unknown
+    /// - 1 healthy
+    /// - 2 unhealthy
+    pub health, _: 1, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_two_signals() {
+        let bytes = [
+            0x10, 0x27, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, //
+            0x00, 0x0c, 0x00, 0x00, 0xf6, 0xff, 0x2a, 0x07, 0x00, 0x01, 0x39, 0x00, 0x00, 0x00,
+            0x00, 0x00, //
+            0x02, 0x05, 0x06, 0x00, 0x0f, 0x00, 0x1e, 0x04, 0x04, 0x08, 0x4a, 0x00, 0x00, 0x00,
+            0x00, 0x00,
+        ];
+        let msg = Sig::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 10_000);
+        assert_eq!(msg.version, 0);
+        assert_eq!(msg.sigs.len(), 2);
+
+        let l1 = msg.sigs[0];
+        assert_eq!(l1.gnssId, 0);
+        assert_eq!(l1.svId, 12);
+        assert_eq!(l1.sigId, 0);
+        assert_eq!(l1.prRes, -10);
+        assert_eq!(l1.cno, 42);
+        assert_eq!(l1.qualityInd, 7);
+        assert_eq!(l1.ionoModel, 1);
+        assert_eq!(l1.sigFlags.health(), 1);
+        assert!(l1.sigFlags.pr_used());
+        assert!(l1.sigFlags.cr_used());
+        assert!(!l1.sigFlags.pr_corr_used());
+
+        let e5b = msg.sigs[1];
+        assert_eq!(e5b.gnssId, 2);
+        assert_eq!(e5b.gnss_id(), Ok(GnssId::Galileo));
+        assert_eq!(e5b.svId, 5);
+        assert_eq!(e5b.sigId, 6);
+        assert_eq!(e5b.prRes, 15);
+        assert_eq!(e5b.corrSource, 4);
+        assert_eq!(e5b.ionoModel, 8);
+        assert_eq!(e5b.sigFlags.health(), 2);
+        assert!(e5b.sigFlags.pr_corr_used());
+
+        let mut buf = [0_u8; 40];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(Sig::deserialize(&mut bytes[..39].as_ref()).is_err());
+    }
+}
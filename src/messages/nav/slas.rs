@@ -0,0 +1,241 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// QZSS L1S SLAS status data.
+///
+/// Reports the state of the QZSS sub-meter level augmentation service
+/// (SLAS) and the pseudorange corrections applied from it. Only
+/// output by receivers with SLAS support.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slas {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// Longitude of the used ground monitoring station.
+    ///
+    /// ### Unit
+    /// 1e-3 degree
+    pub gmsLon: I4,
+
+    /// Latitude of the used ground monitoring station.
+    ///
+    /// ### Unit
+    /// 1e-3 degree
+    pub gmsLat: I4,
+
+    /// Code of the used ground monitoring station according to the
+    /// QZSS SLAS Interface Specification.
+    pub gmsCode: U1,
+
+    /// Satellite identifier of the QZS/GEO whose correction data is
+    /// used.
+    pub qzssSvId: U1,
+
+    /// Service flags.
+    pub serviceFlags: SlasServiceFlags,
+
+    /// Corrections applied to each satellite.
+    pub corrections: Vec<SlasCorrection>,
+}
+
+/// Correction applied to a single satellite, decoded from one
+/// repeated block of a NAV-SLAS message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SlasCorrection {
+    /// GNSS identifier, see [`GnssId`].
+    pub gnssId: U1,
+
+    /// Satellite identifier.
+    pub svId: U1,
+
+    /// Pseudorange correction.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub prc: I2,
+}
+
+impl SlasCorrection {
+    /// Returns the GNSS this satellite belongs to, or the raw identifier if
+    /// it is not a known GNSS.
+    pub fn gnss_id(&self) -> Result<GnssId, U1> {
+        GnssId::try_from(self.gnssId).map_err(|()| self.gnssId)
+    }
+}
+
+bitfield! {
+    /// NAV-SLAS service flags.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct SlasServiceFlags(X1);
+    impl Debug;
+    /// Receiver is operating in SLAS test mode.
+    pub test_mode, _: 2;
+    /// A ground monitoring station has been selected.
+    pub gm_selected, _: 1;
+    /// Ground monitoring station available.
+    pub gm_available, _: 0;
+}
+
+impl Slas {
+    const LEN_HEADER: usize = 20;
+    const LEN_BLOCK: usize = 8;
+
+    /// Returns the ground monitoring station's longitude in degrees.
+    pub fn gms_lon_deg(&self) -> f64 {
+        f64::from(self.gmsLon) * 1e-3
+    }
+
+    /// Returns the ground monitoring station's latitude in degrees.
+    pub fn gms_lat_deg(&self) -> f64 {
+        f64::from(self.gmsLat) * 1e-3
+    }
+}
+
+impl VarMessage for Slas {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x42;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.corrections.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let cnt = U1::try_from(self.corrections.len()).map_err(|_| ())?;
+
+        dst.put_u32_le(self.iTOW);
+        dst.put_u8(self.version);
+        // reserved1
+        dst.put_slice(&[0; 3]);
+        dst.put_i32_le(self.gmsLon);
+        dst.put_i32_le(self.gmsLat);
+        dst.put_u8(self.gmsCode);
+        dst.put_u8(self.qzssSvId);
+        dst.put_u8(self.serviceFlags.0);
+        dst.put_u8(cnt);
+        for corr in &self.corrections {
+            dst.put_u8(corr.gnssId);
+            dst.put_u8(corr.svId);
+            // reserved2, reserved3
+            dst.put_u32_le(0);
+            dst.put_i16_le(corr.prc);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let version = src.get_u8();
+        // reserved1
+        src.advance(3);
+        let gmsLon = src.get_i32_le();
+        let gmsLat = src.get_i32_le();
+        let gmsCode = src.get_u8();
+        let qzssSvId = src.get_u8();
+        let serviceFlags = SlasServiceFlags(src.get_u8());
+        let cnt = usize::from(src.get_u8());
+        if src.remaining() != cnt * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let corrections = (0..cnt)
+            .map(|_| {
+                let gnssId = src.get_u8();
+                let svId = src.get_u8();
+                // reserved2, reserved3
+                src.advance(4);
+                SlasCorrection {
+                    gnssId,
+                    svId,
+                    prc: src.get_i16_le(),
+                }
+            })
+            .collect();
+
+        Ok(Slas {
+            iTOW,
+            version,
+            gmsLon,
+            gmsLat,
+            gmsCode,
+            qzssSvId,
+            serviceFlags,
+            corrections,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::framing::Frame;
+    use crate::messages::nav::Nav;
+
+    #[test]
+    fn test_can_parse() {
+        let bytes = [
+            0x10, 0x27, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xfc, 0x2b, 0x02, 0x00, 0xde, 0x8b,
+            0x00, 0x00, 0x03, 0xc3, 0x07, 0x02, //
+            0x00, 0x0c, 0x00, 0x00, 0x00, 0x00, 0x7b, 0x00, //
+            0x05, 0x01, 0x00, 0x00, 0x00, 0x00, 0xf6, 0xff,
+        ];
+        let msg = Slas::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 10_000);
+        assert_eq!(msg.version, 0);
+        assert_eq!(msg.gmsLon, 142_332);
+        assert!((msg.gms_lon_deg() - 142.332).abs() < 1e-9);
+        assert_eq!(msg.gmsLat, 35_806);
+        assert!((msg.gms_lat_deg() - 35.806).abs() < 1e-9);
+        assert_eq!(msg.gmsCode, 3);
+        assert_eq!(msg.qzssSvId, 195);
+        assert!(msg.serviceFlags.gm_available());
+        assert!(msg.serviceFlags.gm_selected());
+        assert!(msg.serviceFlags.test_mode());
+        assert_eq!(msg.corrections[0].gnss_id(), Ok(GnssId::Gps));
+        assert_eq!(msg.corrections[1].gnss_id(), Ok(GnssId::Qzss));
+        assert_eq!(
+            msg.corrections,
+            [
+                SlasCorrection {
+                    gnssId: 0,
+                    svId: 12,
+                    prc: 123
+                },
+                SlasCorrection {
+                    gnssId: 5,
+                    svId: 1,
+                    prc: -10
+                },
+            ]
+        );
+
+        let mut buf = [0_u8; 36];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(Slas::deserialize(&mut bytes[..35].as_ref()).is_err());
+        assert!(Slas::deserialize(&mut bytes[..19].as_ref()).is_err());
+
+        let frame = Frame {
+            class: 0x01,
+            id: 0x42,
+            message: bytes.to_vec(),
+        };
+        assert_eq!(Nav::from_frame(&frame), Ok(Nav::Slas(msg)));
+    }
+}
@@ -0,0 +1,168 @@
+use super::FixType;
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Receiver navigation status.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Status {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// GPSfix type, see [`FixType`]
+    pub gpsFix: U1,
+
+    /// Navigation status flags
+    ///
+    /// - bit 0: `gpsFixOk`, position and velocity valid and within
+    ///   DOP and ACC masks
+    /// - bit 1: `diffSoln`, differential corrections were applied
+    /// - bit 2: `wknSet`, valid GPS week number
+    /// - bit 3: `towSet`, valid GPS time of week
+    pub flags: X1,
+
+    /// Fix status information
+    pub fixStat: X1,
+
+    /// Further information about navigation output
+    pub flags2: X1,
+
+    /// Time to first fix.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub ttff: U4,
+
+    /// Milliseconds since startup or reset.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub msss: U4,
+}
+
+impl Status {
+    /// Returns GPSfix type.
+    pub fn gps_fix(&self) -> FixType {
+        FixType::from(self.gpsFix)
+    }
+
+    /// Returns `true` if position and velocity are valid and within
+    /// DOP and ACC masks.
+    pub fn gps_fix_ok(&self) -> bool {
+        self.flags & 0b0001 != 0
+    }
+
+    /// Returns `true` if differential corrections were applied.
+    pub fn diff_soln(&self) -> bool {
+        self.flags & 0b0010 != 0
+    }
+
+    /// Returns `true` if the GPS week number is valid.
+    pub fn wkn_set(&self) -> bool {
+        self.flags & 0b0100 != 0
+    }
+
+    /// Returns `true` if the GPS time of week is valid.
+    pub fn tow_set(&self) -> bool {
+        self.flags & 0b1000 != 0
+    }
+}
+
+impl Message for Status {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x03;
+    const LEN: usize = 16;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &Status {
+            iTOW,
+            gpsFix,
+            flags,
+            fixStat,
+            flags2,
+            ttff,
+            msss,
+        } = self;
+
+        dst.put_u32_le(iTOW);
+        dst.put_u8(gpsFix);
+        dst.put_u8(flags);
+        dst.put_u8(fixStat);
+        dst.put_u8(flags2);
+        dst.put_u32_le(ttff);
+        dst.put_u32_le(msss);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let gpsFix = src.get_u8();
+        let flags = src.get_u8();
+        let fixStat = src.get_u8();
+        let flags2 = src.get_u8();
+        let ttff = src.get_u32_le();
+        let msss = src.get_u32_le();
+
+        Ok(Status {
+            iTOW,
+            gpsFix,
+            flags,
+            fixStat,
+            flags2,
+            ttff,
+            msss,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = Status {
+            iTOW: 403_200_000,
+            gpsFix: 3,
+            flags: 0b1101,
+            fixStat: 0,
+            flags2: 0,
+            ttff: 25_000,
+            msss: 60_000,
+        };
+        let mut buf = [0_u8; Status::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        let decoded = Status::deserialize(&mut buf.as_ref()).unwrap();
+        assert_eq!(msg, decoded);
+        assert_eq!(decoded.gps_fix(), FixType::Fix3D);
+        assert!(decoded.gps_fix_ok());
+        assert!(!decoded.diff_soln());
+        assert!(decoded.wkn_set());
+        assert!(decoded.tow_set());
+    }
+
+    #[test]
+    fn test_unknown_fix_type() {
+        let msg = Status {
+            iTOW: 0,
+            gpsFix: 9,
+            flags: 0,
+            fixStat: 0,
+            flags2: 0,
+            ttff: 0,
+            msss: 0,
+        };
+        assert_eq!(msg.gps_fix(), FixType::Unknown(9));
+    }
+}
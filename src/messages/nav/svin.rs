@@ -0,0 +1,215 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Survey-in data.
+///
+/// Reports the progress of a survey-in started with CFG-TMODE3, and
+/// the mean position so far.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Svin {
+    /// Message version (0x00 for this version).
+    pub version: U1,
+
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// Passed survey-in observation time.
+    ///
+    /// ### Unit
+    /// second
+    pub dur: U4,
+
+    /// Current survey-in mean position ECEF X coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub meanX: I4,
+
+    /// Current survey-in mean position ECEF Y coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub meanY: I4,
+
+    /// Current survey-in mean position ECEF Z coordinate.
+    ///
+    /// ### Unit
+    /// centimeter
+    pub meanZ: I4,
+
+    /// High-precision part of `meanX`, range -99..99.
+    ///
+    /// ### Unit
+    /// 0.1 millimeter
+    pub meanXHP: I1,
+
+    /// High-precision part of `meanY`, range -99..99.
+    ///
+    /// ### Unit
+    /// 0.1 millimeter
+    pub meanYHP: I1,
+
+    /// High-precision part of `meanZ`, range -99..99.
+    ///
+    /// ### Unit
+    /// 0.1 millimeter
+    pub meanZHP: I1,
+
+    /// Current survey-in mean position accuracy.
+    ///
+    /// ### Unit
+    /// 0.1 millimeter
+    pub meanAcc: U4,
+
+    /// Number of position observations used during survey-in.
+    pub obs: U4,
+
+    /// Survey-in position validity flag, 1 = valid, otherwise 0.
+    pub valid: U1,
+
+    /// Survey-in in progress flag, 1 = in-progress, otherwise 0.
+    pub active: U1,
+}
+
+impl Svin {
+    /// Returns `true` if the survey-in position is valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid == 1
+    }
+
+    /// Returns `true` if survey-in is in progress.
+    pub fn is_active(&self) -> bool {
+        self.active == 1
+    }
+
+    /// Returns the survey-in mean ECEF position, `(x, y, z)`, in
+    /// meters, including the high-precision parts.
+    pub fn mean_ecef_m(&self) -> (f64, f64, f64) {
+        let m = |cm: I4, hp: I1| f64::from(cm) * 1e-2 + f64::from(hp) * 1e-4;
+        (
+            m(self.meanX, self.meanXHP),
+            m(self.meanY, self.meanYHP),
+            m(self.meanZ, self.meanZHP),
+        )
+    }
+
+    /// Returns survey-in mean position accuracy in meters.
+    pub fn mean_acc_m(&self) -> f64 {
+        f64::from(self.meanAcc) * 1e-4
+    }
+}
+
+impl Message for Svin {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x3B;
+    const LEN: usize = 40;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        dst.put_u8(self.version);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+        dst.put_u32_le(self.iTOW);
+        dst.put_u32_le(self.dur);
+        dst.put_i32_le(self.meanX);
+        dst.put_i32_le(self.meanY);
+        dst.put_i32_le(self.meanZ);
+        dst.put_i8(self.meanXHP);
+        dst.put_i8(self.meanYHP);
+        dst.put_i8(self.meanZHP);
+        // reserved2
+        dst.put_u8(0);
+        dst.put_u32_le(self.meanAcc);
+        dst.put_u32_le(self.obs);
+        dst.put_u8(self.valid);
+        dst.put_u8(self.active);
+        // reserved3
+        dst.put_u16_le(0);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        // reserved1
+        src.advance(3);
+        let iTOW = src.get_u32_le();
+        let dur = src.get_u32_le();
+        let meanX = src.get_i32_le();
+        let meanY = src.get_i32_le();
+        let meanZ = src.get_i32_le();
+        let meanXHP = src.get_i8();
+        let meanYHP = src.get_i8();
+        let meanZHP = src.get_i8();
+        // reserved2
+        src.advance(1);
+        let meanAcc = src.get_u32_le();
+        let obs = src.get_u32_le();
+        let valid = src.get_u8();
+        let active = src.get_u8();
+        // reserved3
+        src.advance(2);
+
+        Ok(Svin {
+            version,
+            iTOW,
+            dur,
+            meanX,
+            meanY,
+            meanZ,
+            meanXHP,
+            meanYHP,
+            meanZHP,
+            meanAcc,
+            obs,
+            valid,
+            active,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_progress() {
+        let mut bytes = [0_u8; Svin::LEN];
+        bytes[4..8].copy_from_slice(&403_200_000_u32.to_le_bytes());
+        bytes[8..12].copy_from_slice(&120_u32.to_le_bytes());
+        bytes[12..16].copy_from_slice(&133_417_712_i32.to_le_bytes());
+        bytes[16..20].copy_from_slice(&(-465_412_345_i32).to_le_bytes());
+        bytes[20..24].copy_from_slice(&413_612_350_i32.to_le_bytes());
+        bytes[24..27].copy_from_slice(&[25, 0xe7, 0]);
+        bytes[28..32].copy_from_slice(&35_000_u32.to_le_bytes());
+        bytes[32..36].copy_from_slice(&121_u32.to_le_bytes());
+        bytes[37] = 1;
+
+        let msg = Svin::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.iTOW, 403_200_000);
+        assert_eq!(msg.dur, 120);
+        assert_eq!(msg.obs, 121);
+        assert!(!msg.is_valid());
+        assert!(msg.is_active());
+        assert_eq!(msg.meanYHP, -25);
+        let (x, y, z) = msg.mean_ecef_m();
+        assert!((x - 1_334_177.122_5).abs() < 1e-6);
+        assert!((y - -4_654_123.452_5).abs() < 1e-6);
+        assert!((z - 4_136_123.5).abs() < 1e-6);
+        assert_eq!(msg.mean_acc_m(), 3.5);
+
+        let mut buf = [0xff_u8; Svin::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+    }
+}
@@ -1,4 +1,6 @@
-use crate::messages::{primitive::*, Message};
+use crate::messages::{primitive::*, DecodeFields, FieldValue, Message};
+use alloc::vec::Vec;
+use bitfield::bitfield;
 use bytes::{Buf, BufMut};
 
 /// This message reports the precise GPS time of the most recent
@@ -34,7 +36,7 @@ pub struct TimeGps {
     pub leapS: I1,
 
     /// Validity Flags.
-    pub valid: X1,
+    pub valid: TimeGpsValid,
 
     /// Time Accuracy Estimate.
     ///
@@ -43,6 +45,60 @@ pub struct TimeGps {
     pub tAcc: U4,
 }
 
+impl TimeGps {
+    /// Returns the precise GPS time of week of the navigation epoch,
+    /// combining `iTOW` and `fTOW`.
+    ///
+    /// ### Unit
+    /// second
+    pub fn tow_seconds(&self) -> f64 {
+        f64::from(self.iTOW) * 1e-3 + f64::from(self.fTOW) * 1e-9
+    }
+
+    /// Returns `true` if both the time of week and week number are
+    /// valid.
+    pub fn is_valid(&self) -> bool {
+        self.valid.towValid() && self.valid.weekValid()
+    }
+
+    /// Returns the GPS-UTC leap second offset, if `leapS` is valid.
+    ///
+    /// ### Unit
+    /// second
+    pub fn utc_offset_seconds(&self) -> Option<i8> {
+        if self.valid.leapSValid() {
+            Some(self.leapS)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the UTC time of the navigation epoch, if the time of
+    /// week, week number, and leap seconds are all valid.
+    ///
+    /// `week` is taken to count weeks since the GPS epoch
+    /// (1980-01-06), i.e. the receiver has already resolved the
+    /// 1024-week rollover. Receivers do so using the rollover week
+    /// configured with CFG-NAVX5 (`wkn_rollover`), and report dates
+    /// wrong by a multiple of 1024 weeks outside of that window.
+    #[cfg(feature = "chrono")]
+    pub fn to_utc(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        use chrono::{Duration, TimeZone, Utc};
+        if !self.is_valid() || self.week < 0 {
+            return None;
+        }
+        let leap_s = self.utc_offset_seconds()?;
+        let gps_epoch = Utc.with_ymd_and_hms(1980, 1, 6, 0, 0, 0).single()?;
+        Some(
+            gps_epoch
+                + Duration::weeks(i64::from(self.week))
+                + Duration::milliseconds(i64::from(self.iTOW))
+                + Duration::nanoseconds(i64::from(self.fTOW))
+                - Duration::seconds(i64::from(leap_s)),
+        )
+    }
+}
+
 impl Message for TimeGps {
     const CLASS: u8 = 0x01;
     const ID: u8 = 0x20;
@@ -66,7 +122,7 @@ impl Message for TimeGps {
         dst.put_i32_le(fTOW);
         dst.put_i16_le(week);
         dst.put_i8(leapS);
-        dst.put_u8(valid);
+        dst.put_u8(valid.0);
         dst.put_u32_le(tAcc);
 
         Ok(())
@@ -81,7 +137,7 @@ impl Message for TimeGps {
         let fTOW = src.get_i32_le();
         let week = src.get_i16_le();
         let leapS = src.get_i8();
-        let valid = src.get_u8();
+        let valid = TimeGpsValid(src.get_u8());
         let tAcc = src.get_u32_le();
 
         Ok(TimeGps {
@@ -94,3 +150,124 @@ impl Message for TimeGps {
         })
     }
 }
+
+impl DecodeFields for TimeGps {
+    fn fields(&self) -> Vec<(&'static str, FieldValue)> {
+        alloc::vec![
+            ("iTOW", FieldValue::U4(self.iTOW)),
+            ("fTOW", FieldValue::I4(self.fTOW)),
+            ("week", FieldValue::I2(self.week)),
+            ("leapS", FieldValue::I1(self.leapS)),
+            ("valid", FieldValue::X1(self.valid.0)),
+            ("tAcc", FieldValue::U4(self.tAcc)),
+        ]
+    }
+}
+
+bitfield! {
+    /// Bitfield `valid`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct TimeGpsValid(X1);
+    impl Debug;
+    /// Valid GPS leap seconds (leapS)
+    pub leapSValid, _: 2;
+    /// Valid GPS week number (see Time Validity section for details)
+    pub weekValid, _: 1;
+    /// Valid GPS time of week (iTOW & fTOW, see Time Validity section
+    /// for details)
+    pub towValid, _: 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tow_seconds() {
+        let mut msg = TimeGps {
+            iTOW: 403_200_001,
+            fTOW: -250_000,
+            week: 2_100,
+            leapS: 18,
+            valid: TimeGpsValid(0x03),
+            tAcc: 20,
+        };
+        assert!((msg.tow_seconds() - 403_200.000_75).abs() < 1e-9);
+        assert!(msg.is_valid());
+        msg.valid = TimeGpsValid(0x01);
+        assert!(!msg.is_valid());
+    }
+
+    #[test]
+    fn test_utc_offset_seconds() {
+        let mut msg = TimeGps {
+            iTOW: 403_200_000,
+            fTOW: 0,
+            week: 2_100,
+            leapS: 18,
+            valid: TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        assert_eq!(msg.utc_offset_seconds(), Some(18));
+        msg.valid = TimeGpsValid(0x03);
+        assert_eq!(msg.utc_offset_seconds(), None);
+    }
+
+    #[test]
+    fn test_fields() {
+        let msg = TimeGps {
+            iTOW: 403_200_001,
+            fTOW: -250_000,
+            week: 2_100,
+            leapS: 18,
+            valid: TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        assert_eq!(
+            msg.fields(),
+            [
+                ("iTOW", FieldValue::U4(403_200_001)),
+                ("fTOW", FieldValue::I4(-250_000)),
+                ("week", FieldValue::I2(2_100)),
+                ("leapS", FieldValue::I1(18)),
+                ("valid", FieldValue::X1(0x07)),
+                ("tAcc", FieldValue::U4(20)),
+            ]
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_to_utc() {
+        use chrono::TimeZone;
+        let mut msg = TimeGps {
+            iTOW: 403_200_000,
+            fTOW: 250_000,
+            week: 2_100,
+            leapS: 18,
+            valid: TimeGpsValid(0x07),
+            tAcc: 20,
+        };
+        let expected = chrono::Utc
+            .with_ymd_and_hms(2020, 4, 9, 15, 59, 42)
+            .unwrap()
+            + chrono::Duration::microseconds(250);
+        assert_eq!(msg.to_utc(), Some(expected));
+        msg.valid = TimeGpsValid(0x03);
+        assert_eq!(msg.to_utc(), None);
+    }
+
+    #[test]
+    fn test_valid() {
+        let valid = TimeGpsValid(0x07);
+        assert!(valid.towValid());
+        assert!(valid.weekValid());
+        assert!(valid.leapSValid());
+        assert_eq!(valid.0, 0x07);
+
+        let valid = TimeGpsValid(0x04);
+        assert!(!valid.towValid());
+        assert!(!valid.weekValid());
+        assert!(valid.leapSValid());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::messages::{primitive::*, Message};
+use bytes::{Buf, BufMut};
+
+/// Velocity solution in ECEF.
+///
+/// See important comments concerning validity of velocity given in
+/// section Navigation Output Filters.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VelEcef {
+    /// GPS time of week of the navigation epoch.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub iTOW: U4,
+
+    /// ECEF X velocity.
+    ///
+    /// ### Unit
+    /// centimeter/second
+    pub ecefVX: I4,
+
+    /// ECEF Y velocity.
+    ///
+    /// ### Unit
+    /// centimeter/second
+    pub ecefVY: I4,
+
+    /// ECEF Z velocity.
+    ///
+    /// ### Unit
+    /// centimeter/second
+    pub ecefVZ: I4,
+
+    /// Speed accuracy estimate.
+    ///
+    /// ### Unit
+    /// centimeter/second
+    pub sAcc: U4,
+}
+
+impl VelEcef {
+    /// Returns ECEF X velocity in meters/second.
+    pub fn ecef_vx_mps(&self) -> f64 {
+        f64::from(self.ecefVX) * 1e-2
+    }
+
+    /// Returns ECEF Y velocity in meters/second.
+    pub fn ecef_vy_mps(&self) -> f64 {
+        f64::from(self.ecefVY) * 1e-2
+    }
+
+    /// Returns ECEF Z velocity in meters/second.
+    pub fn ecef_vz_mps(&self) -> f64 {
+        f64::from(self.ecefVZ) * 1e-2
+    }
+
+    /// Returns speed accuracy estimate in meters/second.
+    pub fn s_acc_mps(&self) -> f64 {
+        f64::from(self.sAcc) * 1e-2
+    }
+}
+
+impl Message for VelEcef {
+    const CLASS: u8 = 0x01;
+    const ID: u8 = 0x11;
+    const LEN: usize = 20;
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < Self::LEN {
+            return Err(());
+        }
+
+        let &VelEcef {
+            iTOW,
+            ecefVX,
+            ecefVY,
+            ecefVZ,
+            sAcc,
+        } = self;
+
+        dst.put_u32_le(iTOW);
+        dst.put_i32_le(ecefVX);
+        dst.put_i32_le(ecefVY);
+        dst.put_i32_le(ecefVZ);
+        dst.put_u32_le(sAcc);
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN {
+            return Err(());
+        }
+
+        let iTOW = src.get_u32_le();
+        let ecefVX = src.get_i32_le();
+        let ecefVY = src.get_i32_le();
+        let ecefVZ = src.get_i32_le();
+        let sAcc = src.get_u32_le();
+
+        Ok(VelEcef {
+            iTOW,
+            ecefVX,
+            ecefVY,
+            ecefVZ,
+            sAcc,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let msg = VelEcef {
+            iTOW: 403_200_000,
+            ecefVX: -12,
+            ecefVY: 250,
+            ecefVZ: 3,
+            sAcc: 40,
+        };
+        let mut buf = [0_u8; VelEcef::LEN];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(msg, VelEcef::deserialize(&mut buf.as_ref()).unwrap());
+        assert_eq!(msg.ecef_vy_mps(), 2.5);
+        assert_eq!(msg.s_acc_mps(), 0.4);
+    }
+}
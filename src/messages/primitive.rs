@@ -1,6 +1,8 @@
 //! u-blox number formats.
 #![allow(missing_docs)]
 
+use core::{convert::TryFrom, fmt};
+
 pub type CH = u8;
 pub type I1 = i8;
 pub type I2 = i16;
@@ -13,3 +15,146 @@ pub type U4 = u32;
 pub type X1 = u8;
 pub type X2 = u16;
 pub type X4 = u32;
+
+macro_rules! scaled {
+    ($(#[$attr:meta])* $name:ident($raw:ty), $divisor:expr) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name(pub $raw);
+
+        impl $name {
+            /// Raw value is divided by this to get the scaled value.
+            pub const DIVISOR: f64 = $divisor;
+
+            /// Returns the scaled value.
+            pub fn as_f64(self) -> f64 {
+                f64::from(self.0) / Self::DIVISOR
+            }
+
+            /// Returns the raw representation of `value`, rounded to
+            /// the nearest step and saturated to the raw type's range.
+            pub fn from_f64(value: f64) -> Self {
+                let half = if value < 0.0 { -0.5 } else { 0.5 };
+                $name((value * Self::DIVISOR + half) as $raw)
+            }
+        }
+
+        impl From<$raw> for $name {
+            fn from(raw: $raw) -> Self {
+                $name(raw)
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(scaled: $name) -> Self {
+                scaled.0
+            }
+        }
+    };
+}
+
+scaled!(
+    /// Degrees scaled by 1e-7, as used for latitude and longitude.
+    Deg1e7(I4),
+    1e7
+);
+
+scaled!(
+    /// Degrees scaled by 1e-5, as used for headings.
+    Deg1e5(I4),
+    1e5
+);
+
+scaled!(
+    /// Dilution of precision scaled by 0.01.
+    DopScaled(U2),
+    1e2
+);
+
+/// GNSS identifier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum GnssId {
+    Gps,
+    Sbas,
+    Galileo,
+    BeiDou,
+    Imes,
+    Qzss,
+    Glonass,
+}
+
+impl TryFrom<U1> for GnssId {
+    type Error = ();
+
+    fn try_from(raw: U1) -> Result<Self, ()> {
+        match raw {
+            0 => Ok(GnssId::Gps),
+            1 => Ok(GnssId::Sbas),
+            2 => Ok(GnssId::Galileo),
+            3 => Ok(GnssId::BeiDou),
+            4 => Ok(GnssId::Imes),
+            5 => Ok(GnssId::Qzss),
+            6 => Ok(GnssId::Glonass),
+            _ => Err(()),
+        }
+    }
+}
+
+impl From<GnssId> for U1 {
+    fn from(gnss_id: GnssId) -> Self {
+        match gnss_id {
+            GnssId::Gps => 0,
+            GnssId::Sbas => 1,
+            GnssId::Galileo => 2,
+            GnssId::BeiDou => 3,
+            GnssId::Imes => 4,
+            GnssId::Qzss => 5,
+            GnssId::Glonass => 6,
+        }
+    }
+}
+
+impl fmt::Display for GnssId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            GnssId::Gps => "GPS",
+            GnssId::Sbas => "SBAS",
+            GnssId::Galileo => "Galileo",
+            GnssId::BeiDou => "BeiDou",
+            GnssId::Imes => "IMES",
+            GnssId::Qzss => "QZSS",
+            GnssId::Glonass => "GLONASS",
+        };
+        f.write_str(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_scaled() {
+        assert_eq!(Deg1e7(407_128_000).as_f64(), 40.7128);
+        assert_eq!(Deg1e5(-9_000_000).as_f64(), -90.0);
+        assert_eq!(DopScaled(150).as_f64(), 1.5);
+        assert_eq!(I4::from(Deg1e7::from(-1)), -1);
+        assert_eq!(Deg1e7::from_f64(40.7128), Deg1e7(407_128_000));
+        assert_eq!(Deg1e7::from_f64(-74.006), Deg1e7(-740_060_000));
+        assert_eq!(DopScaled::from_f64(-1.0), DopScaled(0));
+    }
+
+    #[test]
+    fn test_gnss_id() {
+        let names = [
+            "GPS", "SBAS", "Galileo", "BeiDou", "IMES", "QZSS", "GLONASS",
+        ];
+        for (raw, name) in names.iter().enumerate() {
+            let gnss_id = GnssId::try_from(raw as U1).unwrap();
+            assert_eq!(U1::from(gnss_id), raw as U1);
+            assert_eq!(gnss_id.to_string(), *name);
+        }
+        assert_eq!(GnssId::try_from(7), Err(()));
+    }
+}
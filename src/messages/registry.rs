@@ -0,0 +1,137 @@
+use crate::framing::Frame;
+use crate::messages::{Msg, ParseError};
+use alloc::{boxed::Box, collections::BTreeMap};
+use core::{any::Any, fmt};
+
+/// A decoder for a message this crate does not model.
+type Decoder = Box<dyn Fn(&Frame) -> Box<dyn Any>>;
+
+/// A message decoded by a [`MsgRegistry`].
+#[derive(Debug)]
+pub enum Decoded {
+    /// A message decoded by [`Msg::from_frame()`].
+    Builtin(Msg),
+    /// A message decoded by a registered decoder, to be downcast to
+    /// the decoder's concrete type.
+    Custom(Box<dyn Any>),
+}
+
+/// Dispatches frames to user-registered decoders, falling back to
+/// [`Msg::from_frame()`].
+///
+/// This makes it possible to decode proprietary or otherwise
+/// unmodeled messages without modifying [`Msg`].
+///
+/// # Example
+///
+/// ```
+/// # use ublox::{framing::Frame, messages::{Decoded, MsgRegistry}};
+/// struct Custom(u8);
+///
+/// let mut registry = MsgRegistry::new();
+/// registry.register(0xf1, 0x00, |frame| Box::new(Custom(frame.message[0])));
+///
+/// let frame = Frame { class: 0xf1, id: 0x00, message: vec![42] };
+/// match registry.decode(&frame) {
+///     Ok(Decoded::Custom(msg)) => assert_eq!(msg.downcast_ref::<Custom>().unwrap().0, 42),
+///     other => panic!("unexpected {:?}", other),
+/// }
+/// ```
+#[derive(Default)]
+pub struct MsgRegistry {
+    decoders: BTreeMap<(u8, u8), Decoder>,
+}
+
+impl MsgRegistry {
+    /// Returns a new registry without any registered decoders.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `decoder` for frames with `class` and `id`, replacing
+    /// any decoder previously registered for them.
+    ///
+    /// Registered decoders take precedence over built-in parsers.
+    pub fn register<F>(&mut self, class: u8, id: u8, decoder: F)
+    where
+        F: Fn(&Frame) -> Box<dyn Any> + 'static,
+    {
+        self.decoders.insert((class, id), Box::new(decoder));
+    }
+
+    /// Decodes `frame` with the decoder registered for its class and
+    /// ID, or with [`Msg::from_frame()`] if there is none.
+    pub fn decode(&self, frame: &Frame) -> Result<Decoded, ParseError> {
+        match self.decoders.get(&(frame.class, frame.id)) {
+            Some(decoder) => Ok(Decoded::Custom(decoder(frame))),
+            None => Msg::from_frame(frame).map(Decoded::Builtin),
+        }
+    }
+}
+
+impl fmt::Debug for MsgRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.decoders.keys()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ack::{Ack, AckNak};
+
+    #[derive(Debug, PartialEq)]
+    struct Proprietary {
+        value: u16,
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let mut registry = MsgRegistry::new();
+        registry.register(0xf1, 0x42, |frame| {
+            Box::new(Proprietary {
+                value: u16::from_le_bytes([frame.message[0], frame.message[1]]),
+            })
+        });
+
+        let frame = Frame {
+            class: 0xf1,
+            id: 0x42,
+            message: alloc::vec![0x34, 0x12],
+        };
+        match registry.decode(&frame).unwrap() {
+            Decoded::Custom(msg) => {
+                assert_eq!(
+                    msg.downcast_ref::<Proprietary>(),
+                    Some(&Proprietary { value: 0x1234 })
+                );
+            }
+            other => panic!("expected custom message, got {:?}", other),
+        }
+
+        let ack = Frame {
+            class: 0x05,
+            id: 0x01,
+            message: alloc::vec![0x06, 0x01],
+        };
+        match registry.decode(&ack).unwrap() {
+            Decoded::Builtin(Msg::AckNak(AckNak::Ack(Ack { class, id }))) => {
+                assert_eq!((class, id), (0x06, 0x01))
+            }
+            other => panic!("expected ACK-ACK, got {:?}", other),
+        }
+
+        let unknown = Frame {
+            class: 0xf1,
+            id: 0x43,
+            message: alloc::vec![],
+        };
+        assert_eq!(
+            registry.decode(&unknown).unwrap_err(),
+            ParseError::UnknownMessage {
+                class: 0xf1,
+                id: 0x43
+            }
+        );
+    }
+}
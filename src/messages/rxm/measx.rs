@@ -0,0 +1,315 @@
+use crate::messages::{primitive::*, VarMessage};
+use alloc::vec::Vec;
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+use core::convert::TryFrom;
+
+/// Satellite measurements for RRLP.
+///
+/// The message contains the information needed to produce an RRLP
+/// (radio resource LCS protocol) measurement report.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MeasX {
+    /// Message version (0x01 for this version).
+    pub version: U1,
+
+    /// GPS measurement reference time.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub gpsTOW: U4,
+
+    /// GLONASS measurement reference time.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub gloTOW: U4,
+
+    /// BeiDou measurement reference time.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub bdsTOW: U4,
+
+    /// QZSS measurement reference time.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub qzssTOW: U4,
+
+    /// GPS measurement reference time accuracy.
+    ///
+    /// ### Unit
+    /// 2^-4 millisecond
+    pub gpsTOWacc: U2,
+
+    /// GLONASS measurement reference time accuracy.
+    ///
+    /// ### Unit
+    /// 2^-4 millisecond
+    pub gloTOWacc: U2,
+
+    /// BeiDou measurement reference time accuracy.
+    ///
+    /// ### Unit
+    /// 2^-4 millisecond
+    pub bdsTOWacc: U2,
+
+    /// QZSS measurement reference time accuracy.
+    ///
+    /// ### Unit
+    /// 2^-4 millisecond
+    pub qzssTOWacc: U2,
+
+    /// Flags.
+    pub flags: MeasXFlags,
+
+    /// Measurements of each satellite.
+    pub svs: Vec<MeasXSv>,
+}
+
+/// Measurements of a single satellite, decoded from one repeated
+/// block of an RXM-MEASX message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MeasXSv {
+    /// GNSS identifier, see [`GnssId`].
+    pub gnssId: U1,
+
+    /// Satellite identifier.
+    pub svId: U1,
+
+    /// Carrier noise ratio (0..63).
+    pub cNo: U1,
+
+    /// Multipath index.
+    ///
+    /// - 0 not measured
+    /// - 1 low
+    /// - 2 medium
+    /// - 3 high
+    pub mpathIndic: U1,
+
+    /// Doppler measurement.
+    ///
+    /// ### Unit
+    /// 0.04 meter/second
+    pub dopplerMS: I4,
+
+    /// Doppler measurement.
+    ///
+    /// ### Unit
+    /// 0.2 hertz
+    pub dopplerHz: I4,
+
+    /// Whole value of the code phase measurement (0..1022 for GPS).
+    ///
+    /// ### Unit
+    /// chip
+    pub wholeChips: U2,
+
+    /// Fractional value of the code phase measurement (0..1023).
+    pub fracChips: U2,
+
+    /// Code phase.
+    ///
+    /// ### Unit
+    /// 2^-21 millisecond
+    pub codePhase: U4,
+
+    /// Integer (part of) the code phase.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub intCodePhase: U1,
+
+    /// Pseudorange RMS error index.
+    pub pseuRangeRMSErr: U1,
+}
+
+impl MeasXSv {
+    /// Returns the GNSS this satellite belongs to, or the raw identifier if
+    /// it is not a known GNSS.
+    pub fn gnss_id(&self) -> Result<GnssId, U1> {
+        GnssId::try_from(self.gnssId).map_err(|()| self.gnssId)
+    }
+}
+
+impl MeasX {
+    const LEN_HEADER: usize = 44;
+    const LEN_BLOCK: usize = 24;
+}
+
+impl VarMessage for MeasX {
+    const CLASS: u8 = 0x02;
+    const ID: u8 = 0x14;
+
+    fn payload_len(&self) -> usize {
+        Self::LEN_HEADER + Self::LEN_BLOCK * self.svs.len()
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+        let num_sv = U1::try_from(self.svs.len()).map_err(|_| ())?;
+
+        dst.put_u8(self.version);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+        dst.put_u32_le(self.gpsTOW);
+        dst.put_u32_le(self.gloTOW);
+        dst.put_u32_le(self.bdsTOW);
+        // reserved2
+        dst.put_u32_le(0);
+        dst.put_u32_le(self.qzssTOW);
+        dst.put_u16_le(self.gpsTOWacc);
+        dst.put_u16_le(self.gloTOWacc);
+        dst.put_u16_le(self.bdsTOWacc);
+        // reserved3
+        dst.put_u16_le(0);
+        dst.put_u16_le(self.qzssTOWacc);
+        dst.put_u8(num_sv);
+        dst.put_u8(self.flags.0);
+        // reserved4
+        dst.put_u64_le(0);
+        for sv in &self.svs {
+            dst.put_u8(sv.gnssId);
+            dst.put_u8(sv.svId);
+            dst.put_u8(sv.cNo);
+            dst.put_u8(sv.mpathIndic);
+            dst.put_i32_le(sv.dopplerMS);
+            dst.put_i32_le(sv.dopplerHz);
+            dst.put_u16_le(sv.wholeChips);
+            dst.put_u16_le(sv.fracChips);
+            dst.put_u32_le(sv.codePhase);
+            dst.put_u8(sv.intCodePhase);
+            dst.put_u8(sv.pseuRangeRMSErr);
+            // reserved5
+            dst.put_u16_le(0);
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        if src.remaining() < Self::LEN_HEADER {
+            return Err(());
+        }
+
+        let version = src.get_u8();
+        // reserved1
+        src.advance(3);
+        let gpsTOW = src.get_u32_le();
+        let gloTOW = src.get_u32_le();
+        let bdsTOW = src.get_u32_le();
+        // reserved2
+        src.advance(4);
+        let qzssTOW = src.get_u32_le();
+        let gpsTOWacc = src.get_u16_le();
+        let gloTOWacc = src.get_u16_le();
+        let bdsTOWacc = src.get_u16_le();
+        // reserved3
+        src.advance(2);
+        let qzssTOWacc = src.get_u16_le();
+        let num_sv = usize::from(src.get_u8());
+        let flags = MeasXFlags(src.get_u8());
+        // reserved4
+        src.advance(8);
+        if src.remaining() != num_sv * Self::LEN_BLOCK {
+            return Err(());
+        }
+        let svs = (0..num_sv)
+            .map(|_| {
+                let sv = MeasXSv {
+                    gnssId: src.get_u8(),
+                    svId: src.get_u8(),
+                    cNo: src.get_u8(),
+                    mpathIndic: src.get_u8(),
+                    dopplerMS: src.get_i32_le(),
+                    dopplerHz: src.get_i32_le(),
+                    wholeChips: src.get_u16_le(),
+                    fracChips: src.get_u16_le(),
+                    codePhase: src.get_u32_le(),
+                    intCodePhase: src.get_u8(),
+                    pseuRangeRMSErr: src.get_u8(),
+                };
+                // reserved5
+                src.advance(2);
+                sv
+            })
+            .collect();
+
+        Ok(MeasX {
+            version,
+            gpsTOW,
+            gloTOW,
+            bdsTOW,
+            qzssTOW,
+            gpsTOWacc,
+            gloTOWacc,
+            bdsTOWacc,
+            qzssTOWacc,
+            flags,
+            svs,
+        })
+    }
+}
+
+bitfield! {
+    /// Bitfield `flags`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct MeasXFlags(X1);
+    impl Debug;
+    /// TOW set
+    ///
+    /// - 0 no
+    /// - 1 or 2 yes
+    pub towSet, _: 1, 0;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_parse_one_sv() {
+        let bytes = [
+            0x01, 0x00, 0x00, 0x00, 0x10, 0x27, 0x00, 0x00, 0x20, 0x4e, 0x00, 0x00, 0x30, 0x75,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40, 0x9c, 0x00, 0x00, 0x10, 0x00, 0x20, 0x00,
+            0x30, 0x00, 0x00, 0x00, 0x40, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, //
+            0x00, 0x0c, 0x2a, 0x01, 0x9c, 0xff, 0xff, 0xff, 0x2c, 0x01, 0x00, 0x00, 0xfe, 0x03,
+            0x00, 0x02, 0x00, 0x00, 0x10, 0x00, 0x05, 0x03, 0x00, 0x00,
+        ];
+        let msg = MeasX::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.version, 1);
+        assert_eq!(msg.gpsTOW, 10_000);
+        assert_eq!(msg.gloTOW, 20_000);
+        assert_eq!(msg.bdsTOW, 30_000);
+        assert_eq!(msg.qzssTOW, 40_000);
+        assert_eq!(msg.gpsTOWacc, 0x10);
+        assert_eq!(msg.qzssTOWacc, 0x40);
+        assert_eq!(msg.flags.towSet(), 2);
+        assert_eq!(msg.svs.len(), 1);
+
+        let sv = msg.svs[0];
+        assert_eq!(sv.gnssId, 0);
+        assert_eq!(sv.gnss_id(), Ok(GnssId::Gps));
+        assert_eq!(sv.svId, 12);
+        assert_eq!(sv.cNo, 42);
+        assert_eq!(sv.mpathIndic, 1);
+        assert_eq!(sv.dopplerMS, -100);
+        assert_eq!(sv.dopplerHz, 300);
+        assert_eq!(sv.wholeChips, 1022);
+        assert_eq!(sv.fracChips, 512);
+        assert_eq!(sv.codePhase, 0x0010_0000);
+        assert_eq!(sv.intCodePhase, 5);
+        assert_eq!(sv.pseuRangeRMSErr, 3);
+
+        let mut buf = [0_u8; 68];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(MeasX::deserialize(&mut bytes[..67].as_ref()).is_err());
+    }
+}
@@ -0,0 +1,64 @@
+//! Receiver Manager Messages: i.e. Satellite Status, RTC Status.
+//!
+//! Messages in the RXM class are used to output status and result
+//! data from the Receiver Manager. The output rate is not bound to the
+//! navigation/measurement rate and messages can also be generated on
+//! events.
+
+mod measx;
+mod pmreq;
+pub use self::measx::*;
+pub use self::pmreq::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec_var, parse_var, ParseError, VarMessage};
+
+/// Receiver manager messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Rxm {
+    PmReq(PmReq),
+    MeasX(MeasX),
+}
+
+impl Rxm {
+    /// RXM class.
+    pub const CLASS: u8 = 0x02;
+
+    /// Parses a receiver manager message from a borrowed frame,
+    /// e.g. a `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            PmReq::ID => parse_var(frame).map(Rxm::PmReq),
+            MeasX::ID => parse_var(frame).map(Rxm::MeasX),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Rxm::PmReq(msg) => framed_vec_var(msg),
+            Rxm::MeasX(msg) => framed_vec_var(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Rxm::PmReq(msg) => msg.class_id(),
+            Rxm::MeasX(msg) => msg.class_id(),
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use crate::messages::{primitive::*, VarMessage};
+use bitfield::bitfield;
+use bytes::{Buf, BufMut};
+
+/// Requests a power management task.
+///
+/// Two versions of this message exist: the legacy 8-byte form
+/// containing only `duration` and `flags`, and the 16-byte version 0
+/// form which adds `wakeupSources`. The form used is selected by
+/// whether `wakeupSources` is present.
+///
+/// The receiver goes into backup mode for a time period defined by
+/// `duration`, provided that it is not connected to USB.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PmReq {
+    /// Duration of the requested task, set to zero for infinite
+    /// duration. The maximum supported time is 12 days.
+    ///
+    /// ### Unit
+    /// millisecond
+    pub duration: U4,
+
+    /// Task flags.
+    pub flags: PmReqFlags,
+
+    /// Configure pins to wake up the receiver.
+    ///
+    /// `None` selects the legacy 8-byte form of this message.
+    pub wakeupSources: Option<WakeupSources>,
+}
+
+impl PmReq {
+    /// Payload length of the legacy form.
+    pub const LEN_LEGACY: usize = 8;
+    /// Payload length of the version 0 form.
+    pub const LEN_V0: usize = 16;
+
+    /// Returns a request to enter backup mode for `duration_ms`
+    /// milliseconds, or indefinitely if `duration_ms` is zero.
+    pub fn backup(duration_ms: U4) -> Self {
+        let mut flags = PmReqFlags(0);
+        flags.set_backup(true);
+        Self {
+            duration: duration_ms,
+            flags,
+            wakeupSources: None,
+        }
+    }
+
+    /// Returns `self` with activity on the UART RX pin added as a
+    /// wake-up source.
+    ///
+    /// This switches `self` to the version 0 form.
+    pub fn wake_on_uart(mut self) -> Self {
+        let mut sources = self.wakeupSources.unwrap_or(WakeupSources(0));
+        sources.set_uartrx(true);
+        self.wakeupSources = Some(sources);
+        self
+    }
+}
+
+bitfield! {
+    /// Bitfield `flags`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct PmReqFlags(X4);
+    impl Debug;
+    /// Force receiver backup while USB is connected. USB interface
+    /// will be disabled.
+    pub force, set_force: 2;
+    /// The receiver goes into backup mode for a time period defined
+    /// by duration.
+    pub backup, set_backup: 1;
+}
+
+bitfield! {
+    /// Bitfield `wakeupSources`.
+    #[derive(Clone, Copy, Eq, PartialEq)]
+    pub struct WakeupSources(X4);
+    impl Debug;
+    /// Wake up the receiver if there is an edge on the SPI CS pin.
+    pub spics, set_spics: 7;
+    /// Wake up the receiver if there is an edge on the EXTINT1 pin.
+    pub extint1, set_extint1: 6;
+    /// Wake up the receiver if there is an edge on the EXTINT0 pin.
+    pub extint0, set_extint0: 5;
+    /// Wake up the receiver if there is an edge on the UART RX pin.
+    pub uartrx, set_uartrx: 3;
+}
+
+impl VarMessage for PmReq {
+    const CLASS: u8 = 0x02;
+    const ID: u8 = 0x41;
+
+    fn payload_len(&self) -> usize {
+        match self.wakeupSources {
+            None => Self::LEN_LEGACY,
+            Some(_) => Self::LEN_V0,
+        }
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+
+        let &PmReq {
+            duration,
+            flags,
+            wakeupSources,
+        } = self;
+
+        match wakeupSources {
+            None => {
+                dst.put_u32_le(duration);
+                dst.put_u32_le(flags.0);
+            }
+            Some(wakeupSources) => {
+                // version
+                dst.put_u8(0);
+                // reserved1
+                dst.put_slice([0_u8; 3].as_ref());
+                dst.put_u32_le(duration);
+                dst.put_u32_le(flags.0);
+                dst.put_u32_le(wakeupSources.0);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        match src.remaining() {
+            Self::LEN_LEGACY => {
+                let duration = src.get_u32_le();
+                let flags = PmReqFlags(src.get_u32_le());
+                Ok(PmReq {
+                    duration,
+                    flags,
+                    wakeupSources: None,
+                })
+            }
+            Self::LEN_V0 => {
+                // version
+                if src.get_u8() != 0 {
+                    return Err(());
+                }
+                // reserved1
+                src.advance(3);
+                let duration = src.get_u32_le();
+                let flags = PmReqFlags(src.get_u32_le());
+                let wakeupSources = WakeupSources(src.get_u32_le());
+                Ok(PmReq {
+                    duration,
+                    flags,
+                    wakeupSources: Some(wakeupSources),
+                })
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backup_sets_flag() {
+        let msg = PmReq::backup(5_000);
+        let mut buf = [0_u8; PmReq::LEN_LEGACY];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x88, 0x13, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00]);
+        assert_eq!(msg, PmReq::deserialize(&mut buf.as_ref()).unwrap());
+    }
+
+    #[test]
+    fn test_wake_on_uart() {
+        let msg = PmReq::backup(0).wake_on_uart();
+        assert_eq!(msg.payload_len(), PmReq::LEN_V0);
+        let mut buf = [0_u8; PmReq::LEN_V0];
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(
+            buf,
+            [
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00,
+                0x00, 0x00
+            ]
+        );
+        assert_eq!(msg, PmReq::deserialize(&mut buf.as_ref()).unwrap());
+    }
+}
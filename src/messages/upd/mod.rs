@@ -0,0 +1,58 @@
+//! Firmware Update Messages: i.e. Memory/Flash erase/download/upload,
+//! backup.
+//!
+//! Messages in the UPD class are used to update the firmware and
+//! identify any attached flash device, and to create and restore
+//! backups of the receiver state in flash.
+
+mod sos;
+pub use self::sos::*;
+use crate::framing::{FrameRef, FrameVec};
+use crate::messages::{framed_vec_var, parse_var, ParseError, VarMessage};
+
+/// Firmware update messages.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Upd {
+    Sos(UpdSos),
+}
+
+impl Upd {
+    /// UPD class.
+    pub const CLASS: u8 = 0x09;
+
+    /// Parses a firmware update message from a borrowed frame, e.g. a
+    /// `&Frame` or [`FrameRef`].
+    pub fn from_frame<'a, F: Into<FrameRef<'a>>>(frame: F) -> Result<Self, ParseError> {
+        let frame = frame.into();
+        if frame.class != Self::CLASS {
+            return Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id: frame.id,
+            });
+        };
+
+        match frame.id {
+            UpdSos::ID => parse_var(frame).map(Upd::Sos),
+            id => Err(ParseError::UnknownMessage {
+                class: frame.class,
+                id,
+            }),
+        }
+    }
+
+    /// Serializes `self` to a complete frame, including syncword and
+    /// checksum, or returns an error if it cannot be serialized.
+    pub fn to_framed_vec(&self) -> Result<FrameVec, ()> {
+        match self {
+            Upd::Sos(msg) => framed_vec_var(msg),
+        }
+    }
+
+    /// Returns the class and ID of the message `self` holds.
+    pub fn class_id(&self) -> (u8, u8) {
+        match self {
+            Upd::Sos(msg) => msg.class_id(),
+        }
+    }
+}
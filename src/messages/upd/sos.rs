@@ -0,0 +1,149 @@
+use crate::messages::{primitive::*, VarMessage};
+use bytes::{Buf, BufMut};
+
+/// Backup file creation and restoration.
+///
+/// Two forms of this message exist: the 4-byte command form sent to
+/// the receiver to create or clear a backup in flash, and the 8-byte
+/// response form reporting the outcome of a backup creation or a
+/// restoration at startup. The form used is selected by whether
+/// `response` is present.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdSos {
+    /// Command, see the `CMD_*` constants.
+    pub cmd: U1,
+
+    /// Response to a backup creation (`CMD_CREATE_ACK`) or
+    /// restoration (`CMD_RESTORED`).
+    ///
+    /// For `CMD_CREATE_ACK`:
+    /// - 0 Not acknowledged
+    /// - 1 Acknowledged
+    ///
+    /// For `CMD_RESTORED`:
+    /// - 0 Unknown
+    /// - 1 Failed restoring from backup file
+    /// - 2 Restored from backup file
+    /// - 3 Not restored (no backup)
+    ///
+    /// `None` selects the 4-byte command form of this message.
+    pub response: Option<U1>,
+}
+
+impl UpdSos {
+    /// Payload length of the command form.
+    pub const LEN_COMMAND: usize = 4;
+    /// Payload length of the response form.
+    pub const LEN_RESPONSE: usize = 8;
+
+    /// Create backup in flash.
+    pub const CMD_CREATE: U1 = 0;
+    /// Clear backup in flash.
+    pub const CMD_CLEAR: U1 = 1;
+    /// Backup file creation acknowledge.
+    pub const CMD_CREATE_ACK: U1 = 2;
+    /// System restored from backup.
+    pub const CMD_RESTORED: U1 = 3;
+
+    /// Returns a command to create a backup in flash.
+    ///
+    /// The receiver must be stopped, e.g. with CFG-RST, before the
+    /// backup is created.
+    pub fn create_backup() -> Self {
+        Self {
+            cmd: Self::CMD_CREATE,
+            response: None,
+        }
+    }
+
+    /// Returns a command to clear the backup in flash.
+    pub fn clear_backup() -> Self {
+        Self {
+            cmd: Self::CMD_CLEAR,
+            response: None,
+        }
+    }
+}
+
+impl VarMessage for UpdSos {
+    const CLASS: u8 = 0x09;
+    const ID: u8 = 0x14;
+
+    fn payload_len(&self) -> usize {
+        match self.response {
+            None => Self::LEN_COMMAND,
+            Some(_) => Self::LEN_RESPONSE,
+        }
+    }
+
+    fn serialize<B: BufMut>(&self, dst: &mut B) -> Result<(), ()> {
+        if dst.remaining_mut() < self.payload_len() {
+            return Err(());
+        }
+
+        dst.put_u8(self.cmd);
+        // reserved1
+        dst.put_slice([0_u8; 3].as_ref());
+        if let Some(response) = self.response {
+            dst.put_u8(response);
+            // reserved2
+            dst.put_slice([0_u8; 3].as_ref());
+        }
+
+        Ok(())
+    }
+
+    fn deserialize<B: Buf>(src: &mut B) -> Result<Self, ()> {
+        let len = src.remaining();
+        if len != Self::LEN_COMMAND && len != Self::LEN_RESPONSE {
+            return Err(());
+        }
+
+        let cmd = src.get_u8();
+        // reserved1
+        src.advance(3);
+        let response = if len == Self::LEN_RESPONSE {
+            let response = src.get_u8();
+            // reserved2
+            src.advance(3);
+            Some(response)
+        } else {
+            None
+        };
+
+        Ok(UpdSos { cmd, response })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_form() {
+        let mut buf = [0xff_u8; UpdSos::LEN_COMMAND];
+        let msg = UpdSos::create_backup();
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x00, 0x00, 0x00, 0x00]);
+
+        let msg = UpdSos::clear_backup();
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, [0x01, 0x00, 0x00, 0x00]);
+        assert_eq!(UpdSos::deserialize(&mut buf.as_ref()), Ok(msg));
+    }
+
+    #[test]
+    fn test_response_form() {
+        let bytes = [0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00];
+        let msg = UpdSos::deserialize(&mut bytes.as_ref()).unwrap();
+        assert_eq!(msg.cmd, UpdSos::CMD_RESTORED);
+        assert_eq!(msg.response, Some(2));
+
+        let mut buf = [0xff_u8; UpdSos::LEN_RESPONSE];
+        assert_eq!(msg.payload_len(), buf.len());
+        msg.serialize(&mut buf.as_mut()).unwrap();
+        assert_eq!(buf, bytes);
+        assert!(UpdSos::deserialize(&mut bytes[..6].as_ref()).is_err());
+    }
+}
@@ -0,0 +1,360 @@
+//! Minimal parser for common NMEA 0183 sentences.
+//!
+//! u-blox receivers output NMEA by default, so this makes it possible
+//! to make sense of an unconfigured receiver. Only the `GGA` and `RMC`
+//! sentences are supported, from any talker (e.g. `$GPGGA` or
+//! `$GNGGA`).
+//!
+//! Sentences are typically obtained from a
+//! [`Deframer`](crate::framing::Deframer) as
+//! [`StreamItem::Nmea`](crate::framing::StreamItem::Nmea).
+
+use core::{fmt, str::FromStr};
+
+/// The error type returned by [`parse()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NmeaError {
+    /// The sentence's checksum is missing or does not match its
+    /// contents.
+    Checksum,
+    /// The sentence or one of its fields is malformed.
+    Format,
+    /// The sentence is well-formed but of a type this parser does not
+    /// support.
+    Unsupported,
+}
+
+impl fmt::Display for NmeaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NmeaError::Checksum => f.write_str("NMEA checksum mismatch"),
+            NmeaError::Format => f.write_str("malformed NMEA sentence"),
+            NmeaError::Unsupported => f.write_str("unsupported NMEA sentence"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NmeaError {}
+
+/// A parsed NMEA sentence.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sentence {
+    Gga(Gga),
+    Rmc(Rmc),
+}
+
+/// UTC time of day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Time {
+    /// Hour of day, range 0..23.
+    pub hour: u8,
+    /// Minute of hour, range 0..59.
+    pub min: u8,
+    /// Seconds of minute, including fraction, range 0..60.
+    pub sec: f64,
+}
+
+/// UTC date.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Date {
+    /// Day of month, range 1..31.
+    pub day: u8,
+    /// Month, range 1..12.
+    pub month: u8,
+    /// Two digit year.
+    pub year: u8,
+}
+
+/// Global positioning system fix data.
+///
+/// Empty fields, e.g. the position before the first fix, are `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gga {
+    /// UTC time of the position fix.
+    pub time: Option<Time>,
+    /// Latitude in degrees, negative south of the equator.
+    pub lat: Option<f64>,
+    /// Longitude in degrees, negative west of the prime meridian.
+    pub lon: Option<f64>,
+    /// Fix quality indicator.
+    ///
+    /// - 0 no fix
+    /// - 1 autonomous GNSS fix
+    /// - 2 differential GNSS fix
+    /// - 4 RTK fixed
+    /// - 5 RTK float
+    /// - 6 dead reckoning fix
+    pub quality: u8,
+    /// Number of satellites used.
+    pub num_sv: Option<u8>,
+    /// Horizontal dilution of precision.
+    pub hdop: Option<f64>,
+    /// Altitude above mean sea level in meters.
+    pub alt_msl: Option<f64>,
+}
+
+impl Gga {
+    /// Returns `true` if the receiver has a position fix.
+    pub fn has_fix(&self) -> bool {
+        self.quality != 0
+    }
+}
+
+/// Recommended minimum data.
+///
+/// Empty fields, e.g. the position before the first fix, are `None`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rmc {
+    /// UTC time of the position fix.
+    pub time: Option<Time>,
+    /// `true` if the data is valid, i.e. status is `A`.
+    pub valid: bool,
+    /// Latitude in degrees, negative south of the equator.
+    pub lat: Option<f64>,
+    /// Longitude in degrees, negative west of the prime meridian.
+    pub lon: Option<f64>,
+    /// Speed over ground in knots.
+    pub speed_knots: Option<f64>,
+    /// Course over ground in degrees.
+    pub course: Option<f64>,
+    /// UTC date of the position fix.
+    pub date: Option<Date>,
+}
+
+/// Parses an NMEA sentence, including the leading `$` and the
+/// checksum.
+///
+/// A trailing `<CR><LF>` is ignored.
+pub fn parse(sentence: &str) -> Result<Sentence, NmeaError> {
+    let body = checked_body(sentence)?;
+    let mut fields = body.split(',');
+    let address = fields.next().ok_or(NmeaError::Format)?;
+    if address.len() != 5 || !address.is_ascii() {
+        return Err(NmeaError::Format);
+    }
+    match &address[2..] {
+        "GGA" => parse_gga(fields).map(Sentence::Gga),
+        "RMC" => parse_rmc(fields).map(Sentence::Rmc),
+        _ => Err(NmeaError::Unsupported),
+    }
+}
+
+/// Returns the sentence between `$` and `*`, after validating the
+/// checksum.
+fn checked_body(sentence: &str) -> Result<&str, NmeaError> {
+    let sentence = sentence.trim_end_matches("\r\n");
+    let sentence = sentence.strip_prefix('$').ok_or(NmeaError::Format)?;
+    let (body, cksum) = sentence.rsplit_once('*').ok_or(NmeaError::Checksum)?;
+    if cksum.len() != 2 {
+        return Err(NmeaError::Checksum);
+    }
+    let cksum = u8::from_str_radix(cksum, 16).map_err(|_| NmeaError::Checksum)?;
+    if body.bytes().fold(0, |acc, b| acc ^ b) != cksum {
+        return Err(NmeaError::Checksum);
+    }
+    Ok(body)
+}
+
+fn parse_gga<'a, I: Iterator<Item = &'a str>>(mut fields: I) -> Result<Gga, NmeaError> {
+    let time = time(fields.next())?;
+    let lat = coord(fields.next(), fields.next(), 'N', 'S')?;
+    let lon = coord(fields.next(), fields.next(), 'E', 'W')?;
+    let quality = number(fields.next())?.unwrap_or(0);
+    let num_sv = number(fields.next())?;
+    let hdop = number(fields.next())?;
+    let alt_msl = number(fields.next())?;
+    Ok(Gga {
+        time,
+        lat,
+        lon,
+        quality,
+        num_sv,
+        hdop,
+        alt_msl,
+    })
+}
+
+fn parse_rmc<'a, I: Iterator<Item = &'a str>>(mut fields: I) -> Result<Rmc, NmeaError> {
+    let time = time(fields.next())?;
+    let valid = match fields.next() {
+        Some("A") => true,
+        Some("V") | Some("") => false,
+        _ => return Err(NmeaError::Format),
+    };
+    let lat = coord(fields.next(), fields.next(), 'N', 'S')?;
+    let lon = coord(fields.next(), fields.next(), 'E', 'W')?;
+    let speed_knots = number(fields.next())?;
+    let course = number(fields.next())?;
+    let date = date(fields.next())?;
+    Ok(Rmc {
+        time,
+        valid,
+        lat,
+        lon,
+        speed_knots,
+        course,
+        date,
+    })
+}
+
+/// Parses a numeric field, which is `None` if empty.
+fn number<T: FromStr>(field: Option<&str>) -> Result<Option<T>, NmeaError> {
+    match field.ok_or(NmeaError::Format)? {
+        "" => Ok(None),
+        field => field.parse().map(Some).map_err(|_| NmeaError::Format),
+    }
+}
+
+/// Parses a `(d)ddmm.mmmm` coordinate and its hemisphere into signed
+/// degrees.
+fn coord(
+    field: Option<&str>,
+    hemisphere: Option<&str>,
+    positive: char,
+    negative: char,
+) -> Result<Option<f64>, NmeaError> {
+    let field = field.ok_or(NmeaError::Format)?;
+    let hemisphere = hemisphere.ok_or(NmeaError::Format)?;
+    if field.is_empty() {
+        return Ok(None);
+    }
+    let minutes_at = field.find('.').unwrap_or(field.len());
+    if minutes_at < 2 || !field.is_ascii() {
+        return Err(NmeaError::Format);
+    }
+    let (degrees, minutes) = field.split_at(minutes_at - 2);
+    let degrees: u8 = degrees.parse().map_err(|_| NmeaError::Format)?;
+    let minutes: f64 = minutes.parse().map_err(|_| NmeaError::Format)?;
+    let value = f64::from(degrees) + minutes / 60.0;
+    match hemisphere.chars().next() {
+        Some(c) if c == positive => Ok(Some(value)),
+        Some(c) if c == negative => Ok(Some(-value)),
+        _ => Err(NmeaError::Format),
+    }
+}
+
+/// Parses an `hhmmss(.ss)` time field.
+fn time(field: Option<&str>) -> Result<Option<Time>, NmeaError> {
+    let field = field.ok_or(NmeaError::Format)?;
+    if field.is_empty() {
+        return Ok(None);
+    }
+    if field.len() < 6 || !field.is_ascii() {
+        return Err(NmeaError::Format);
+    }
+    let parse_err = |_| NmeaError::Format;
+    Ok(Some(Time {
+        hour: field[..2].parse().map_err(parse_err)?,
+        min: field[2..4].parse().map_err(parse_err)?,
+        sec: field[4..].parse().map_err(|_| NmeaError::Format)?,
+    }))
+}
+
+/// Parses a `ddmmyy` date field.
+fn date(field: Option<&str>) -> Result<Option<Date>, NmeaError> {
+    let field = field.ok_or(NmeaError::Format)?;
+    if field.is_empty() {
+        return Ok(None);
+    }
+    if field.len() != 6 || !field.is_ascii() {
+        return Err(NmeaError::Format);
+    }
+    let parse_err = |_| NmeaError::Format;
+    Ok(Some(Date {
+        day: field[..2].parse().map_err(parse_err)?,
+        month: field[2..4].parse().map_err(parse_err)?,
+        year: field[4..].parse().map_err(parse_err)?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: Option<f64>, expected: f64) {
+        let actual = actual.unwrap();
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{} != {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_gga() {
+        let sentence = "$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*47";
+        let gga = match parse(sentence).unwrap() {
+            Sentence::Gga(gga) => gga,
+            other => panic!("expected GGA, got {:?}", other),
+        };
+        assert_eq!(
+            gga.time,
+            Some(Time {
+                hour: 12,
+                min: 35,
+                sec: 19.0
+            })
+        );
+        assert_close(gga.lat, 48.0 + 7.038 / 60.0);
+        assert_close(gga.lon, 11.0 + 31.0 / 60.0);
+        assert!(gga.has_fix());
+        assert_eq!(gga.num_sv, Some(8));
+        assert_eq!(gga.hdop, Some(0.9));
+        assert_eq!(gga.alt_msl, Some(545.4));
+        assert_eq!(parse(&[sentence, "\r\n"].concat()), Ok(Sentence::Gga(gga)));
+
+        let no_fix = match parse("$GNGGA,,,,,,0,00,99.99,,,,,,*56").unwrap() {
+            Sentence::Gga(gga) => gga,
+            other => panic!("expected GGA, got {:?}", other),
+        };
+        assert!(!no_fix.has_fix());
+        assert_eq!((no_fix.time, no_fix.lat, no_fix.lon), (None, None, None));
+    }
+
+    #[test]
+    fn test_rmc() {
+        let sentence = "$GPRMC,123519,A,4807.038,N,01131.000,E,022.4,084.4,230394,003.1,W*6A";
+        let rmc = match parse(sentence).unwrap() {
+            Sentence::Rmc(rmc) => rmc,
+            other => panic!("expected RMC, got {:?}", other),
+        };
+        assert!(rmc.valid);
+        assert_close(rmc.lat, 48.0 + 7.038 / 60.0);
+        assert_close(rmc.lon, 11.0 + 31.0 / 60.0);
+        assert_eq!(rmc.speed_knots, Some(22.4));
+        assert_eq!(rmc.course, Some(84.4));
+        assert_eq!(
+            rmc.date,
+            Some(Date {
+                day: 23,
+                month: 3,
+                year: 94
+            })
+        );
+
+        let rmc = match parse("$GNRMC,,V,,,,,,,,,,N*4D").unwrap() {
+            Sentence::Rmc(rmc) => rmc,
+            other => panic!("expected RMC, got {:?}", other),
+        };
+        assert!(!rmc.valid);
+        assert_eq!((rmc.lat, rmc.date), (None, None));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse("$GPGGA,123519,4807.038,N,01131.000,E,1,08,0.9,545.4,M,46.9,M,,*48"),
+            Err(NmeaError::Checksum)
+        );
+        assert_eq!(parse("$GPGGA,123519"), Err(NmeaError::Checksum));
+        assert_eq!(parse("GPGGA*00"), Err(NmeaError::Format));
+        assert_eq!(
+            parse("$GPTXT,01,01,02,u-blox ag - www.u-blox.com*50"),
+            Err(NmeaError::Unsupported)
+        );
+        assert_eq!(parse("$GPGGA,1*4B"), Err(NmeaError::Format));
+    }
+}
@@ -0,0 +1,17 @@
+//! Commonly used types, for glob import.
+//!
+//! ```
+//! use ublox::prelude::*;
+//!
+//! let bytes = [0xb5, 0x62, 0x05, 0x01, 0x02, 0x00, 0x06, 0x01, 0x0f, 0x38];
+//! let mut deframer = Deframer::new();
+//! let frame: Frame = bytes.iter().find_map(|&b| deframer.push(b)).unwrap();
+//! match Msg::from_frame(&frame) {
+//!     Ok(Msg::AckNak(AckNak::Ack(ack))) => assert_eq!((ack.class, ack.id), (0x06, 0x01)),
+//!     other => panic!("unexpected {:?}", other),
+//! }
+//! ```
+
+pub use crate::framing::{Deframer, Frame};
+pub use crate::messages::primitive::{CH, I1, I2, I4, R4, R8, U1, U2, U4, X1, X2, X4};
+pub use crate::messages::{ack::AckNak, cfg::Cfg, nav::Nav, Message, Msg, ParseError, VarMessage};
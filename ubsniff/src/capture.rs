@@ -0,0 +1,39 @@
+use crate::error::Result;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// Optionally tees received bytes to a file.
+///
+/// I/O errors are logged and disable further capture rather than
+/// interrupting the decode loop.
+pub struct Capture(Option<BufWriter<File>>);
+
+impl Capture {
+    /// Returns a new `Capture` writing to `path`, or a no-op capture
+    /// if `path` is `None`.
+    pub fn create(path: Option<&Path>) -> Result<Self> {
+        Ok(Capture(match path {
+            Some(path) => Some(BufWriter::new(File::create(path)?)),
+            None => None,
+        }))
+    }
+
+    /// Appends `bytes` to the capture file.
+    pub fn write(&mut self, bytes: &[u8]) {
+        if let Some(Err(e)) = self.0.as_mut().map(|file| file.write_all(bytes)) {
+            log::error!("capture write failed, disabling capture: {}", e);
+            self.0 = None;
+        }
+    }
+
+    /// Flushes buffered bytes to the capture file.
+    pub fn flush(&mut self) {
+        if let Some(Err(e)) = self.0.as_mut().map(|file| file.flush()) {
+            log::error!("capture flush failed, disabling capture: {}", e);
+            self.0 = None;
+        }
+    }
+}
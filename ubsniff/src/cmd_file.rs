@@ -1,19 +1,48 @@
-use crate::error::Result;
-use std::{fs::File, io::Read, path::Path};
-use ublox::{framing::Deframer, messages::Msg};
+use crate::{capture::Capture, error::Result, hexdump::Hexdump};
+use std::{
+    fs::File,
+    io::{BufReader, Read},
+    path::Path,
+};
+use ublox::{
+    framing::{Deframer, StreamItem},
+    messages::Msg,
+    nmea,
+};
 
-pub fn file_loop(path: &Path) -> Result {
-    let file = File::open(path)?;
+pub fn file_loop(path: &Path, capture: &mut Capture) -> Result {
+    read_loop(File::open(path)?, capture)
+}
+
+/// Prints every message deframed from `src` until EOF.
+pub fn read_loop<R: Read>(src: R, capture: &mut Capture) -> Result {
+    let src = BufReader::new(src);
 
     let mut deframer = Deframer::new();
-    for b in file.bytes() {
-        match deframer.push(b?) {
-            None => (),
-            Some(frame) => match Msg::from_frame(&frame) {
-                Err(_) => eprintln!("unhandled frame: {:?}", frame),
-                Ok(msg) => println!("{:#?}", msg),
-            },
+    for b in src.bytes() {
+        let b = b?;
+        capture.write(&[b]);
+        if let Some(item) = deframer.push_item(b) {
+            print_item(item);
         }
     }
+    // Items may remain queued after a false sync near EOF.
+    while let Some(item) = deframer.poll_item() {
+        print_item(item);
+    }
+    capture.flush();
     Ok(())
 }
+
+fn print_item(item: StreamItem) {
+    match item {
+        StreamItem::Nmea(sentence) => match nmea::parse(&sentence) {
+            Ok(parsed) => println!("{:#?}", parsed),
+            Err(_) => println!("{}", sentence),
+        },
+        StreamItem::Ubx(frame) => match Msg::from_frame(&frame) {
+            Err(e) => eprintln!("{}: {}", e, Hexdump(&frame)),
+            Ok(msg) => println!("{:#?}", msg),
+        },
+    }
+}
@@ -1,15 +1,20 @@
-use crate::error::Result;
+use crate::{capture::Capture, error::Result, hexdump::Hexdump};
 use i2c_linux::{I2c, Message as I2cMessage, ReadFlags, WriteFlags};
-use std::thread;
+use std::{convert::TryFrom, thread};
 use std::{fmt::Debug, fs::File, path::Path, time::Duration};
 use sysfs_gpio as gpio;
-use ublox::{framing::Deframer, messages::Msg};
 use ublox::{
-    framing::{frame, Frame},
-    messages::{cfg, nav, Message},
+    framing::{frame, frame_var},
+    messages::{cfg, nav},
+    prelude::*,
 };
 
-pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Option<u64>) -> Result {
+pub fn i2c_loop<P: AsRef<Path> + Debug>(
+    path: &P,
+    addr: u16,
+    tx_ready_pin: Option<u64>,
+    capture: &mut Capture,
+) -> Result {
     let mut dev = I2c::from_path(path)?;
     let mut deframer = Deframer::new();
     let mut scratch = [0x00_u8; 128];
@@ -18,6 +23,7 @@ pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Optio
     {
         use cfg::prt;
         let msg = prt::Prt::Uart {
+            port_id: 1,
             tx_ready: prt::TxReady(0),
             in_proto_mask: {
                 let mut mask = prt::InProtoMask(0);
@@ -35,7 +41,7 @@ pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Optio
                 mode
             },
         };
-        let len = frame(&msg, &mut scratch).unwrap();
+        let len = frame_var(&msg, &mut scratch).unwrap();
         log::debug!("{:02x?}", &scratch[..len]);
         write(&mut dev, addr, &scratch[..len])?;
     }
@@ -46,14 +52,17 @@ pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Optio
         let msg = prt::Prt::I2c {
             tx_ready: {
                 let mut txr = prt::TxReady(0);
-                txr.set_thres(1);
+                txr.set_threshold_bytes(8).unwrap();
                 txr.set_pin(13);
                 txr.set_en(true);
                 txr
             },
             mode: {
                 let mut mode = prt::I2cMode(0);
-                mode.set_slave_addr(addr as u8);
+                u8::try_from(addr)
+                    .map_err(drop)
+                    .and_then(|addr| mode.set_address(addr))
+                    .map_err(|()| format!("invalid I2C address {:#04x}", addr))?;
                 mode
             },
             in_proto_mask: {
@@ -68,31 +77,20 @@ pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Optio
             },
             flags: prt::Flags(0),
         };
-        let len = frame(&msg, &mut scratch).unwrap();
+        let len = frame_var(&msg, &mut scratch).unwrap();
         log::debug!("{:02x?}", &scratch[..len]);
         write(&mut dev, addr, &scratch[..len])?;
     }
 
-    {
-        let frm = Frame {
-            class: 6,
-            id: 1,
-            message: vec![nav::Pvt::CLASS, nav::Pvt::ID, 1],
-        };
-        let en_msg = frm.into_framed_vec();
-        log::debug!("{:x?}", en_msg);
-        write(&mut dev, addr, &en_msg)?;
-    }
-
-    {
-        let frm = Frame {
-            class: 6,
-            id: 1,
-            message: vec![nav::TimeGps::CLASS, nav::TimeGps::ID, 1],
-        };
-        let en_msg = frm.into_framed_vec();
-        log::debug!("{:x?}", en_msg);
-        write(&mut dev, addr, &en_msg)?;
+    // Enable messages on the I2C port.
+    for &(class, id) in &[
+        (nav::Pvt::CLASS, nav::Pvt::ID),
+        (nav::TimeGps::CLASS, nav::TimeGps::ID),
+    ] {
+        let msg = cfg::SetMsgRate { class, id, rate: 1 };
+        let len = frame(&msg, &mut scratch).unwrap();
+        log::debug!("{:02x?}", &scratch[..len]);
+        write(&mut dev, addr, &scratch[..len])?;
     }
 
     let mut pin: Option<(gpio::Pin, gpio::PinPoller)> = tx_ready_pin.map(|pinnum| {
@@ -160,11 +158,14 @@ pub fn i2c_loop<P: AsRef<Path> + Debug>(path: &P, addr: u16, tx_ready_pin: Optio
             continue;
         }
 
+        capture.write(read_buf);
+        capture.flush();
+
         for &mut b in read_buf {
             match deframer.push(b) {
                 None => (),
                 Some(frame) => match Msg::from_frame(&frame) {
-                    Err(_) => log::warn!("unhandled frame: {:?}", frame),
+                    Err(e) => log::warn!("{}: {}", e, Hexdump(&frame)),
                     Ok(msg) => println!("\n{:?}\n", msg),
                 },
             }
@@ -226,7 +227,7 @@ fn write(dev: &mut I2c<File>, addr: u16, src: &[u8]) -> Result {
         },
         I2cMessage::Write {
             address: addr,
-            data: &src,
+            data: src,
             flags: WriteFlags::default(),
         },
     ];
@@ -0,0 +1,44 @@
+use crate::{cmd_uart, error::Result};
+use std::{
+    fs::File,
+    io::{BufReader, Read, Write},
+    path::Path,
+    thread,
+    time::{Duration, Instant},
+};
+use ublox::framing::{Deframer, Frame};
+
+/// Re-emits every frame captured in `in_path` to the serial port at
+/// `out_path`, paced at `rate_hz` frames per second.
+pub fn replay_loop(in_path: &Path, out_path: &Path, baud: u32, rate_hz: f64) -> Result {
+    if !(rate_hz > 0.0 && rate_hz.is_finite()) {
+        return Err(format!("invalid rate {} Hz", rate_hz).into());
+    }
+    let period = Duration::from_secs_f64(1.0 / rate_hz);
+    let src = BufReader::new(File::open(in_path)?);
+    let mut port = cmd_uart::open(&out_path, baud)?;
+
+    let mut deframer = Deframer::new();
+    let mut next = Instant::now();
+    let mut replay = |frame: Frame| -> Result {
+        let now = Instant::now();
+        if next > now {
+            thread::sleep(next - now);
+        }
+        next += period;
+        log::debug!("replaying class {:#04x} id {:#04x}", frame.class, frame.id);
+        port.write_all(&frame.into_framed_vec())?;
+        Ok(())
+    };
+    for b in src.bytes() {
+        if let Some(frame) = deframer.push(b?) {
+            replay(frame)?;
+        }
+    }
+    // Frames may remain queued after a false sync near EOF.
+    while let Some(frame) = deframer.poll() {
+        replay(frame)?;
+    }
+    port.flush()?;
+    Ok(())
+}
@@ -1,15 +1,23 @@
-use crate::error::Result;
+use crate::{
+    capture::Capture,
+    error::{Idle, Result},
+    hexdump::Hexdump,
+};
+use serialport::prelude::*;
 use std::{
     ffi::OsStr,
     io::{BufReader, ErrorKind, Read},
-    time::Duration,
+    time::{Duration, Instant},
+};
+use ublox::{
+    framing::{Deframer, StreamItem},
+    messages::Msg,
+    nmea,
 };
-use ublox::{framing::Deframer, messages::Msg};
-
-pub fn uart_loop<P: AsRef<OsStr>>(path: &P, baud: u32) -> Result {
-    use serialport::prelude::*;
 
-    let port = BufReader::new(serialport::open_with_settings(
+/// Opens the serial port at `path` with 8N1 framing.
+pub fn open<P: AsRef<OsStr>>(path: &P, baud: u32) -> Result<Box<dyn SerialPort>> {
+    let port = serialport::open_with_settings(
         path,
         &SerialPortSettings {
             baud_rate: baud,
@@ -19,21 +27,68 @@ pub fn uart_loop<P: AsRef<OsStr>>(path: &P, baud: u32) -> Result {
             stop_bits: StopBits::One,
             timeout: Duration::from_millis(50),
         },
-    )?);
+    )?;
+    Ok(port)
+}
+
+/// What to do when no complete frame is received for a while.
+pub struct IdlePolicy {
+    /// How long to wait for a complete frame.
+    pub timeout: Duration,
+    /// Return an [`Idle`] error instead of warning.
+    pub exit: bool,
+}
+
+pub fn uart_loop<P: AsRef<OsStr>>(
+    path: &P,
+    baud: u32,
+    idle: Option<IdlePolicy>,
+    capture: &mut Capture,
+) -> Result {
+    let port = BufReader::new(open(path, baud)?);
 
     let mut deframer = Deframer::new();
+    let mut last_frame = Instant::now();
 
     for b in port.bytes() {
         match b {
-            Err(ref e) if e.kind() == ErrorKind::TimedOut => (),
+            Err(ref e) if e.kind() == ErrorKind::TimedOut => capture.flush(),
             Err(e) => eprintln!("{:?}", e),
-            Ok(b) => match deframer.push(b) {
-                None => (),
-                Some(frame) => match Msg::from_frame(&frame) {
-                    Err(_) => eprintln!("unhandled frame: {:?}", frame),
-                    Ok(msg) => println!("{:#?}", msg),
-                },
-            },
+            Ok(b) => {
+                capture.write(&[b]);
+                match deframer.push_item(b) {
+                    None => (),
+                    Some(StreamItem::Nmea(sentence)) => {
+                        last_frame = Instant::now();
+                        match nmea::parse(&sentence) {
+                            Ok(parsed) => println!("{:#?}", parsed),
+                            Err(_) => println!("{}", sentence),
+                        }
+                    }
+                    Some(StreamItem::Ubx(frame)) => {
+                        last_frame = Instant::now();
+                        match Msg::from_frame(&frame) {
+                            Err(e) => eprintln!("{}: {}", e, Hexdump(&frame)),
+                            Ok(msg) => println!("{:#?}", msg),
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(IdlePolicy { timeout, exit }) = idle {
+            if last_frame.elapsed() >= timeout {
+                if exit {
+                    return Err(Idle(timeout).into());
+                }
+                log::warn!(
+                    "no frames received in {:?}, deframer in {} state with {} bytes of frame",
+                    timeout,
+                    deframer.state_name(),
+                    deframer.bytes_in_current_frame()
+                );
+                last_frame = Instant::now();
+            }
         }
     }
     Ok(())
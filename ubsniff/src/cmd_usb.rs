@@ -0,0 +1,11 @@
+use crate::{capture::Capture, cmd_file, error::Result};
+use std::{fs::File, path::Path};
+
+/// Reads from a USB CDC-ACM device.
+///
+/// Unlike a UART, CDC-ACM devices ignore baud rate and framing
+/// settings, so none are applied. The device is expected to already
+/// be in raw mode (e.g. `stty -F PATH raw`).
+pub fn usb_loop(path: &Path, capture: &mut Capture) -> Result {
+    cmd_file::read_loop(File::open(path)?, capture)
+}
@@ -2,7 +2,16 @@ use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
-pub enum Cmdline {
+pub struct Cmdline {
+    /// Save every received byte to a file.
+    #[structopt(name = "CAPTURE", long = "capture", global = true)]
+    pub capture: Option<PathBuf>,
+    #[structopt(subcommand)]
+    pub cmd: Cmd,
+}
+
+#[derive(StructOpt, Debug)]
+pub enum Cmd {
     /// Print u-blox messages from a file.
     File {
         /// Path to captured messages.
@@ -17,6 +26,33 @@ pub enum Cmdline {
         /// Baud rate.
         #[structopt(default_value = "9600")]
         baud: u32,
+        /// Warn if no complete frame is received for this many seconds.
+        #[structopt(name = "SECS", long = "idle-timeout")]
+        idle_timeout: Option<u64>,
+        /// Exit with status 2 instead of warning when idle.
+        #[structopt(long = "exit-on-idle", requires = "SECS")]
+        exit_on_idle: bool,
+    },
+    /// Replay captured u-blox messages to a serial port.
+    Replay {
+        /// Path to captured messages.
+        #[structopt(name = "IN_PATH")]
+        in_path: PathBuf,
+        /// Path to output TTY.
+        #[structopt(name = "OUT_PATH")]
+        out_path: PathBuf,
+        /// Frames per second.
+        #[structopt(short = "r", long = "rate", default_value = "1")]
+        rate_hz: f64,
+        /// Baud rate.
+        #[structopt(short = "b", long = "baud", default_value = "9600")]
+        baud: u32,
+    },
+    /// Print u-blox messages from a USB (CDC-ACM) device.
+    Usb {
+        /// Path to USB TTY
+        #[structopt(name = "PATH")]
+        path: PathBuf,
     },
     #[cfg(target_os = "linux")]
     I2c {
@@ -46,10 +82,10 @@ macro_rules! impl_from_hex_dec_bin {
                     match s.split_at(2) {
                         ("0x", rest) => $T::from_str_radix(rest, 16),
                         ("0b", rest) => $T::from_str_radix(rest, 2),
-                        _ => $T::from_str_radix(s, 10),
+                        _ => s.parse::<$T>(),
                     }
                 } else {
-                    $T::from_str_radix(s, 10)
+                    s.parse::<$T>()
                 }
             }
         }
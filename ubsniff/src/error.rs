@@ -1,3 +1,20 @@
-use std::error::Error;
+use std::{error::Error, fmt, time::Duration};
 
 pub type Result<T = ()> = ::std::result::Result<T, Box<dyn Error>>;
+
+/// No complete frame was received within the idle timeout.
+#[derive(Debug)]
+pub struct Idle(pub Duration);
+
+impl Idle {
+    /// Process exit status used when exiting due to an idle link.
+    pub const EXIT_STATUS: i32 = 2;
+}
+
+impl fmt::Display for Idle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no frames received in {:?}", self.0)
+    }
+}
+
+impl Error for Idle {}
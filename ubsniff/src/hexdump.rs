@@ -0,0 +1,47 @@
+//! Human readable dumps of raw frames.
+
+use std::fmt;
+use ublox::framing::Frame;
+
+/// Bytes shown per hexdump row.
+const ROW_LEN: usize = 16;
+
+/// Displays a frame's class and ID followed by a canonical offset,
+/// hex, and ASCII dump of its payload.
+pub struct Hexdump<'a>(pub &'a Frame);
+
+impl fmt::Display for Hexdump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Frame { class, id, message } = self.0;
+        write!(
+            f,
+            "class {:#04x} id {:#04x} len {}",
+            class,
+            id,
+            message.len()
+        )?;
+        for (row_idx, row) in message.chunks(ROW_LEN).enumerate() {
+            write!(f, "\n{:08x} ", row_idx * ROW_LEN)?;
+            for col in 0..ROW_LEN {
+                if col == ROW_LEN / 2 {
+                    f.write_str(" ")?;
+                }
+                match row.get(col) {
+                    Some(b) => write!(f, " {:02x}", b)?,
+                    None => f.write_str("   ")?,
+                }
+            }
+            f.write_str("  |")?;
+            for &b in row {
+                let c = if b.is_ascii_graphic() || b == b' ' {
+                    char::from(b)
+                } else {
+                    '.'
+                };
+                write!(f, "{}", c)?;
+            }
+            f.write_str("|")?;
+        }
+        Ok(())
+    }
+}
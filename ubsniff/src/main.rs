@@ -1,27 +1,54 @@
+mod capture;
 mod cmd_file;
 #[cfg(target_os = "linux")]
 mod cmd_i2c;
+mod cmd_replay;
 mod cmd_uart;
+mod cmd_usb;
 mod cmdline;
 mod error;
-use cmdline::Cmdline;
+mod hexdump;
+use capture::Capture;
+use cmdline::{Cmd, Cmdline};
+use error::Idle;
+use std::time::Duration;
 use structopt::StructOpt;
 
 fn main() {
     let cmdline = Cmdline::from_args();
     env_logger::init();
-    let res = match cmdline {
-        Cmdline::File { path } => cmd_file::file_loop(&path),
-        #[cfg(target_os = "linux")]
-        Cmdline::I2c {
-            path,
-            addr,
-            tx_ready_pin,
-        } => cmd_i2c::i2c_loop(&path, addr, tx_ready_pin),
-        Cmdline::Serial { path, baud } => cmd_uart::uart_loop(&path, baud),
-    };
+    let res =
+        Capture::create(cmdline.capture.as_deref()).and_then(|mut capture| match cmdline.cmd {
+            Cmd::File { path } => cmd_file::file_loop(&path, &mut capture),
+            #[cfg(target_os = "linux")]
+            Cmd::I2c {
+                path,
+                addr,
+                tx_ready_pin,
+            } => cmd_i2c::i2c_loop(&path, addr, tx_ready_pin, &mut capture),
+            Cmd::Replay {
+                in_path,
+                out_path,
+                rate_hz,
+                baud,
+            } => cmd_replay::replay_loop(&in_path, &out_path, baud, rate_hz),
+            Cmd::Serial {
+                path,
+                baud,
+                idle_timeout,
+                exit_on_idle,
+            } => {
+                let idle = idle_timeout.map(|secs| cmd_uart::IdlePolicy {
+                    timeout: Duration::from_secs(secs),
+                    exit: exit_on_idle,
+                });
+                cmd_uart::uart_loop(&path, baud, idle, &mut capture)
+            }
+            Cmd::Usb { path } => cmd_usb::usb_loop(&path, &mut capture),
+        });
     if let Err(e) = res {
         eprintln!("error: {}", e);
-        ::std::process::exit(1);
+        let status = if e.is::<Idle>() { Idle::EXIT_STATUS } else { 1 };
+        ::std::process::exit(status);
     }
 }